@@ -5,7 +5,7 @@ use crate::error::UsecaseError;
 use dao::HavePayrollDao;
 use payroll_domain::{Affiliation, Employee, EmployeeId};
 
-pub trait ChangeAffiliationTx<Ctx>: ChangeEmployeeTx<Ctx> {
+pub trait AddAffiliationTx<Ctx>: ChangeEmployeeTx<Ctx> {
     fn execute<'a, F>(
         &'a self,
         emp_id: EmployeeId,
@@ -18,10 +18,10 @@ pub trait ChangeAffiliationTx<Ctx>: ChangeEmployeeTx<Ctx> {
     {
         ChangeEmployeeTx::<Ctx>::execute(self, emp_id, |ctx, emp| {
             record_membership(ctx, emp)?;
-            emp.set_affiliation(affiliation);
+            emp.add_affiliation(affiliation);
             Ok(())
         })
     }
 }
 // blanket implementation
-impl<T, Ctx> ChangeAffiliationTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+impl<T, Ctx> AddAffiliationTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -1,10 +1,11 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 use tx_rs::Tx;
 
 use crate::error::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use payroll_domain::{Employee, EmployeeId, PaymentClassification, PaymentSchedule};
-use payroll_impl::{AffiliationImpl, PaymentMethodImpl};
+use payroll_impl::{PaymentMethodImpl, WithholdingImpl};
 
 pub trait AddEmployeeTx<Ctx>: HavePayrollDao<Ctx> {
     fn execute<'a>(
@@ -12,6 +13,7 @@ pub trait AddEmployeeTx<Ctx>: HavePayrollDao<Ctx> {
         emp_id: EmployeeId,
         name: &str,
         address: &str,
+        hire_date: Option<NaiveDate>,
         classification: Rc<RefCell<dyn PaymentClassification>>,
         schedule: Rc<RefCell<dyn PaymentSchedule>>,
     ) -> impl tx_rs::Tx<Ctx, Item = EmployeeId, Err = UsecaseError>
@@ -22,10 +24,11 @@ pub trait AddEmployeeTx<Ctx>: HavePayrollDao<Ctx> {
             emp_id,
             name,
             address,
+            hire_date,
             classification,
             schedule,
             Rc::new(RefCell::new(PaymentMethodImpl::Hold)),
-            Rc::new(RefCell::new(AffiliationImpl::Unaffiliated)),
+            Rc::new(RefCell::new(WithholdingImpl::Exempt)),
         );
         self.dao()
             .insert(emp)
@@ -0,0 +1,84 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use crate::error::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{Deduction, Earning, Employee, EmployeeId, LateEntryPolicy, Money, Paycheck};
+
+// record an entry dated `date`, which is late if a paycheck covering the date was issued
+pub trait AddEntryTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a, F>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: &'a str,
+        f: F,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+        F: FnOnce(&mut Ctx, &mut Employee) -> Result<(), UsecaseError>,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let mut emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            let paid = self
+                .dao()
+                .fetch_paychecks(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+                .into_iter()
+                .find(|pc| {
+                    !pc.is_voided() && !pc.is_off_cycle() && pc.get_period().contains(&date)
+                });
+            match paid {
+                None => f(ctx, &mut emp)?,
+                Some(paid) => match self
+                    .dao()
+                    .fetch_late_entry_policy()
+                    .run(ctx)
+                    .map_err(UsecaseError::GetLateEntryPolicyFailed)?
+                {
+                    LateEntryPolicy::Reject => {
+                        return Err(UsecaseError::PeriodLocked(format!(
+                            "emp_id: {}, date: {}",
+                            emp_id, date
+                        )));
+                    }
+                    LateEntryPolicy::CarryForward => {
+                        // what the paid period is worth with the entry, less what it was without;
+                        // a late service charge is carried as a negative adjustment
+                        let worth = |emp: &Employee| {
+                            let mut pc = Paycheck::new(paid.get_period());
+                            emp.calculate_pay(&mut pc);
+                            pc.get_gross_pay()
+                                - pc.get_deductions()
+                                    .iter()
+                                    .filter(|d| matches!(d, Deduction::ServiceCharge { .. }))
+                                    .map(Deduction::get_amount)
+                                    .sum::<Money>()
+                        };
+                        let before = worth(&emp);
+                        f(ctx, &mut emp)?;
+                        let amount = worth(&emp) - before;
+                        if !amount.is_zero() {
+                            emp.add_adjustment(Earning::Adjustment {
+                                date,
+                                description: description.to_string(),
+                                amount,
+                            });
+                        }
+                    }
+                },
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AddEntryTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
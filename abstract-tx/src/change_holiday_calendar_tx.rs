@@ -0,0 +1,22 @@
+use std::{cell::RefCell, rc::Rc};
+use tx_rs::Tx;
+
+use crate::error::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::HolidayCalendar;
+
+pub trait ChangeHolidayCalendarTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        calendar: Rc<RefCell<dyn HolidayCalendar>>,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .set_holiday_calendar(calendar)
+            .map_err(UsecaseError::SetHolidayCalendarFailed)
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeHolidayCalendarTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,40 @@
+use chrono::{Days, NaiveDate};
+use tx_rs::Tx;
+
+use crate::change_employee_tx::ChangeEmployeeTx;
+use crate::error::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{Employee, EmployeeId};
+
+// change the pay rates of an employee from a date on; an undated change takes effect
+// from the first unpaid period, so the paid periods keep the rates they were paid at
+pub trait ChangeEmployeeRateTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a, F>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: Option<NaiveDate>,
+        f: F,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+        // the date the rates change from, None when no period has been paid yet
+        F: FnOnce(&mut Employee, Option<NaiveDate>) -> Result<(), UsecaseError>,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let unpaid = self
+                .dao()
+                .fetch_paychecks(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+                .into_iter()
+                .filter(|pc| !pc.is_voided() && !pc.is_off_cycle())
+                .map(|pc| *pc.get_period().end())
+                .max()
+                .map(|end| end + Days::new(1));
+            let from = effective.or(unpaid);
+            ChangeEmployeeTx::execute(self, emp_id, move |_, emp| f(emp, from)).run(ctx)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeRateTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,23 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::change_employee_tx::ChangeEmployeeTx;
+use crate::error::UsecaseError;
+use payroll_domain::{EmployeeId, Withholding};
+
+pub trait ChangeEmployeeWithholdingTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        withholding: Rc<RefCell<dyn Withholding>>,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, |_, emp| {
+            emp.set_withholding(withholding);
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeWithholdingTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
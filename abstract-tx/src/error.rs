@@ -22,4 +22,66 @@ pub enum UsecaseError {
     AddUnionMemberFailed(DaoError),
     #[error("remove union member failed: {0}")]
     RemoveUnionMemberFailed(DaoError),
+    #[error("invalid employment date: {0}")]
+    InvalidEmploymentDate(String),
+    #[error("invalid payment schedule: {0}")]
+    InvalidPaymentSchedule(String),
+    #[error("can't get holiday calendar: {0}")]
+    GetHolidayCalendarFailed(DaoError),
+    #[error("set holiday calendar failed: {0}")]
+    SetHolidayCalendarFailed(DaoError),
+    #[error("load holiday calendar failed: {0}")]
+    LoadHolidayCalendarFailed(String),
+    #[error("load tax table failed: {0}")]
+    LoadTaxTableFailed(String),
+    #[error("schedule change failed: {0}")]
+    ScheduleChangeFailed(DaoError),
+    #[error("can't get scheduled changes: {0}")]
+    GetScheduledChangesFailed(DaoError),
+    #[error("cancel scheduled change failed: {0}")]
+    CancelScheduledChangeFailed(DaoError),
+    #[error("scheduled change not found: {0}")]
+    ScheduledChangeNotFound(String),
+    #[error("can't get paychecks: {0}")]
+    GetPaychecksFailed(DaoError),
+    #[error("already paid: {0}")]
+    AlreadyPaid(String),
+    #[error("save payroll run failed: {0}")]
+    SavePayrollRunFailed(DaoError),
+    #[error("payroll run not found: {0}")]
+    PayrollRunNotFound(DaoError),
+    #[error("discard payroll run failed: {0}")]
+    DiscardPayrollRunFailed(DaoError),
+    #[error("void paycheck failed: {0}")]
+    VoidPaycheckFailed(DaoError),
+    #[error("record reversal failed: {0}")]
+    RecordReversalFailed(DaoError),
+    #[error("paycheck not found: {0}")]
+    PaycheckNotFound(String),
+    #[error("invalid pay period: {0}")]
+    InvalidPayPeriod(String),
+    #[error("unexpected garnishment: {0}")]
+    UnexpectedGarnishment(String),
+    #[error("unexpected loan: {0}")]
+    UnexpectedLoan(String),
+    #[error("insufficient leave balance: {0}")]
+    InsufficientLeaveBalance(String),
+    #[error("entry not found: {0}")]
+    EntryNotFound(String),
+    #[error("ambiguous entry: {0}")]
+    AmbiguousEntry(String),
+    #[error("clocked entry: {0}")]
+    ClockedEntry(String),
+    #[error("invalid shift: {0}")]
+    InvalidShift(String),
+    #[error("can't get late entry policy: {0}")]
+    GetLateEntryPolicyFailed(DaoError),
+    #[error("set late entry policy failed: {0}")]
+    SetLateEntryPolicyFailed(DaoError),
+    #[error("period locked: {0}")]
+    PeriodLocked(String),
+    #[error("save commission plan failed: {0}")]
+    SaveCommissionPlanFailed(DaoError),
+    #[error("commission plan not found: {0}")]
+    CommissionPlanNotFound(DaoError),
 }
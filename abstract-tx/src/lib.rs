@@ -1,13 +1,25 @@
+mod add_affiliation_tx;
 mod add_employee_tx;
-mod change_affiliation_tx;
-mod change_classification_tx;
+mod add_entry_tx;
 mod change_employee_tx;
+mod change_holiday_calendar_tx;
 mod change_method_tx;
+mod change_rate_tx;
+mod change_schedule_tx;
+mod change_withholding_tx;
 mod error;
+mod remove_affiliation_tx;
+mod schedule_change_tx;
 
+pub use add_affiliation_tx::AddAffiliationTx;
 pub use add_employee_tx::AddEmployeeTx;
-pub use change_affiliation_tx::ChangeAffiliationTx;
-pub use change_classification_tx::ChangeEmployeePaymentClassificationTx;
+pub use add_entry_tx::AddEntryTx;
 pub use change_employee_tx::ChangeEmployeeTx;
+pub use change_holiday_calendar_tx::ChangeHolidayCalendarTx;
 pub use change_method_tx::ChangeEmployeePaymentMethodTx;
+pub use change_rate_tx::ChangeEmployeeRateTx;
+pub use change_schedule_tx::ChangeEmployeePaymentScheduleTx;
+pub use change_withholding_tx::ChangeEmployeeWithholdingTx;
 pub use error::UsecaseError;
+pub use remove_affiliation_tx::RemoveAffiliationTx;
+pub use schedule_change_tx::ScheduleChangeTx;
@@ -0,0 +1,33 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::change_employee_tx::ChangeEmployeeTx;
+use crate::error::UsecaseError;
+use dao::HavePayrollDao;
+use payroll_domain::{Affiliation, EmployeeId};
+
+pub trait RemoveAffiliationTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a, P, F>(
+        &'a self,
+        emp_id: EmployeeId,
+        is_target: P,
+        release_membership: F,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        P: Fn(&dyn Affiliation) -> bool,
+        F: FnOnce(&mut Ctx, Vec<Rc<RefCell<dyn Affiliation>>>) -> Result<(), UsecaseError>,
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::<Ctx>::execute(self, emp_id, move |ctx, emp| {
+            let removed = emp.remove_affiliations(is_target);
+            if removed.is_empty() {
+                return Err(UsecaseError::UnexpectedAffiliation(format!(
+                    "no such affiliation emp_id: {}",
+                    emp_id
+                )));
+            }
+            release_membership(ctx, removed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> RemoveAffiliationTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
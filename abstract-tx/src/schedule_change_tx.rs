@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use crate::error::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{EmployeeChange, EmployeeId, ScheduledChange};
+
+pub trait ScheduleChangeTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        change: EmployeeChange,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            self.dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            self.dao()
+                .add_scheduled_change(ScheduledChange::new(emp_id, effective, change))
+                .run(ctx)
+                .map_err(UsecaseError::ScheduleChangeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ScheduleChangeTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -1,7 +1,13 @@
 mod error;
 pub use error::DaoError;
 
-use payroll_domain::{Employee, EmployeeId, MemberId, Paycheck};
+use chrono::NaiveDate;
+use std::{cell::RefCell, ops::RangeInclusive, rc::Rc};
+
+use payroll_domain::{
+    CommissionPlan, Employee, EmployeeId, HolidayCalendar, LateEntryPolicy, MemberId, Paycheck,
+    PayrollRun, Reversal, ScheduledChange,
+};
 
 pub trait PayrollDao<Ctx> {
     fn insert(&self, emp: Employee) -> impl tx_rs::Tx<Ctx, Item = EmployeeId, Err = DaoError>;
@@ -27,6 +33,81 @@ pub trait PayrollDao<Ctx> {
         emp_id: EmployeeId,
         pc: Paycheck,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    // whether a regular paycheck for the period is recorded and not voided
+    fn is_paid(
+        &self,
+        emp_id: EmployeeId,
+        period: RangeInclusive<NaiveDate>,
+    ) -> impl tx_rs::Tx<Ctx, Item = bool, Err = DaoError>;
+    // whether a regular paycheck covering the date is recorded and not voided
+    fn is_paid_on(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = bool, Err = DaoError>;
+    fn fetch_paychecks(
+        &self,
+        emp_id: EmployeeId,
+    ) -> impl tx_rs::Tx<Ctx, Item = Vec<Paycheck>, Err = DaoError>;
+    fn fetch_all_paychecks(
+        &self,
+    ) -> impl tx_rs::Tx<Ctx, Item = Vec<(EmployeeId, Paycheck)>, Err = DaoError>;
+    // mark the regular or the off-cycle paycheck issued on `pay_date` as voided and return it
+    fn void_paycheck(
+        &self,
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        off_cycle: bool,
+    ) -> impl tx_rs::Tx<Ctx, Item = Paycheck, Err = DaoError>;
+    fn record_reversal(&self, reversal: Reversal)
+        -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    fn fetch_reversals(&self) -> impl tx_rs::Tx<Ctx, Item = Vec<Reversal>, Err = DaoError>;
+    // a draft run replaces the one already saved for the same pay date
+    fn save_payroll_run(&self, run: PayrollRun) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    fn fetch_payroll_run(
+        &self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = PayrollRun, Err = DaoError>;
+    fn remove_payroll_run(
+        &self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    fn set_holiday_calendar(
+        &self,
+        calendar: Rc<RefCell<dyn HolidayCalendar>>,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    fn fetch_holiday_calendar(
+        &self,
+    ) -> impl tx_rs::Tx<Ctx, Item = Option<Rc<RefCell<dyn HolidayCalendar>>>, Err = DaoError>;
+    fn set_late_entry_policy(
+        &self,
+        policy: LateEntryPolicy,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    fn fetch_late_entry_policy(
+        &self,
+    ) -> impl tx_rs::Tx<Ctx, Item = LateEntryPolicy, Err = DaoError>;
+    // replaces a plan of the same name
+    fn save_commission_plan(
+        &self,
+        plan: CommissionPlan,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    fn fetch_commission_plan(
+        &self,
+        name: &str,
+    ) -> impl tx_rs::Tx<Ctx, Item = CommissionPlan, Err = DaoError>;
+    fn add_scheduled_change(
+        &self,
+        change: ScheduledChange,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = DaoError>;
+    // in order of effective date, then of scheduling
+    fn fetch_scheduled_changes(
+        &self,
+    ) -> impl tx_rs::Tx<Ctx, Item = Vec<ScheduledChange>, Err = DaoError>;
+    fn remove_scheduled_changes(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = Vec<ScheduledChange>, Err = DaoError>;
 }
 
 pub trait HavePayrollDao<Ctx> {
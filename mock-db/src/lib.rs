@@ -1,13 +1,25 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use chrono::NaiveDate;
+use std::{cell::RefCell, collections::HashMap, ops::RangeInclusive, rc::Rc};
 
 use dao::{DaoError, PayrollDao};
-use payroll_domain::{Employee, EmployeeId, MemberId, Paycheck};
+use payroll_domain::{
+    CommissionPlan, Employee, EmployeeId, HolidayCalendar, LateEntryPolicy, MemberId, Paycheck,
+    PayrollRun, Reversal, ScheduledChange,
+};
+
+type SharedHolidayCalendar = Rc<RefCell<dyn HolidayCalendar>>;
 
 #[derive(Debug, Clone)]
 pub struct MockDb {
     employees: Rc<RefCell<HashMap<EmployeeId, Employee>>>,
     union_members: Rc<RefCell<HashMap<MemberId, EmployeeId>>>,
     paychecks: Rc<RefCell<HashMap<EmployeeId, Vec<Paycheck>>>>,
+    payroll_runs: Rc<RefCell<HashMap<NaiveDate, PayrollRun>>>,
+    reversals: Rc<RefCell<Vec<Reversal>>>,
+    holiday_calendar: Rc<RefCell<Option<SharedHolidayCalendar>>>,
+    late_entry_policy: Rc<RefCell<LateEntryPolicy>>,
+    commission_plans: Rc<RefCell<HashMap<String, CommissionPlan>>>,
+    scheduled_changes: Rc<RefCell<Vec<ScheduledChange>>>,
 }
 impl MockDb {
     pub fn new() -> Self {
@@ -15,6 +27,12 @@ impl MockDb {
             employees: Rc::new(RefCell::new(HashMap::new())),
             union_members: Rc::new(RefCell::new(HashMap::new())),
             paychecks: Rc::new(RefCell::new(HashMap::new())),
+            payroll_runs: Rc::new(RefCell::new(HashMap::new())),
+            reversals: Rc::new(RefCell::new(vec![])),
+            holiday_calendar: Rc::new(RefCell::new(None)),
+            late_entry_policy: Rc::new(RefCell::new(LateEntryPolicy::default())),
+            commission_plans: Rc::new(RefCell::new(HashMap::new())),
+            scheduled_changes: Rc::new(RefCell::new(vec![])),
         }
     }
 }
@@ -130,4 +148,217 @@ impl PayrollDao<()> for MockDb {
             Ok(())
         })
     }
+    fn is_paid(
+        &self,
+        emp_id: EmployeeId,
+        period: RangeInclusive<NaiveDate>,
+    ) -> impl tx_rs::Tx<(), Item = bool, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            Ok(self.paychecks.borrow().get(&emp_id).is_some_and(|pcs| {
+                pcs.iter()
+                    .any(|pc| !pc.is_voided() && !pc.is_off_cycle() && pc.get_period() == period)
+            }))
+        })
+    }
+    fn is_paid_on(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+    ) -> impl tx_rs::Tx<(), Item = bool, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            Ok(self.paychecks.borrow().get(&emp_id).is_some_and(|pcs| {
+                pcs.iter().any(|pc| {
+                    !pc.is_voided() && !pc.is_off_cycle() && pc.get_period().contains(&date)
+                })
+            }))
+        })
+    }
+    fn fetch_paychecks(
+        &self,
+        emp_id: EmployeeId,
+    ) -> impl tx_rs::Tx<(), Item = Vec<Paycheck>, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            Ok(self
+                .paychecks
+                .borrow()
+                .get(&emp_id)
+                .cloned()
+                .unwrap_or_default())
+        })
+    }
+    fn fetch_all_paychecks(
+        &self,
+    ) -> impl tx_rs::Tx<(), Item = Vec<(EmployeeId, Paycheck)>, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            Ok(self
+                .paychecks
+                .borrow()
+                .iter()
+                .flat_map(|(emp_id, pcs)| pcs.iter().map(|pc| (*emp_id, pc.clone())))
+                .collect())
+        })
+    }
+    fn void_paycheck(
+        &self,
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        off_cycle: bool,
+    ) -> impl tx_rs::Tx<(), Item = Paycheck, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            let mut paychecks = self.paychecks.borrow_mut();
+            let mut matched: Vec<_> = paychecks
+                .get_mut(&emp_id)
+                .into_iter()
+                .flatten()
+                .filter(|pc| {
+                    !pc.is_voided()
+                        && pc.is_off_cycle() == off_cycle
+                        && pc.get_pay_date() == Some(pay_date)
+                })
+                .collect();
+            match matched.as_mut_slice() {
+                [pc] => {
+                    pc.void();
+                    Ok(pc.clone())
+                }
+                [] => Err(DaoError::UpdateError(format!(
+                    "paycheck for emp_id={} on {} not found",
+                    emp_id, pay_date
+                ))),
+                pcs => Err(DaoError::UpdateError(format!(
+                    "{} paychecks for emp_id={} on {}",
+                    pcs.len(),
+                    emp_id,
+                    pay_date
+                ))),
+            }
+        })
+    }
+    fn record_reversal(&self, reversal: Reversal) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            self.reversals.borrow_mut().push(reversal);
+            Ok(())
+        })
+    }
+    fn fetch_reversals(&self) -> impl tx_rs::Tx<(), Item = Vec<Reversal>, Err = DaoError> {
+        tx_rs::with_tx(move |_| Ok(self.reversals.borrow().clone()))
+    }
+    fn save_payroll_run(&self, run: PayrollRun) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            self.payroll_runs
+                .borrow_mut()
+                .insert(run.get_pay_date(), run);
+            Ok(())
+        })
+    }
+    fn fetch_payroll_run(
+        &self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<(), Item = PayrollRun, Err = DaoError> {
+        tx_rs::with_tx(move |_| match self.payroll_runs.borrow().get(&pay_date) {
+            Some(run) => Ok(run.clone()),
+            None => Err(DaoError::FetchError(format!(
+                "payroll run for {} not found",
+                pay_date
+            ))),
+        })
+    }
+    fn remove_payroll_run(
+        &self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            if self.payroll_runs.borrow_mut().remove(&pay_date).is_none() {
+                return Err(DaoError::DeleteError(format!(
+                    "payroll run for {} not found",
+                    pay_date
+                )));
+            }
+            Ok(())
+        })
+    }
+    fn set_holiday_calendar(
+        &self,
+        calendar: Rc<RefCell<dyn HolidayCalendar>>,
+    ) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            *self.holiday_calendar.borrow_mut() = Some(calendar);
+            Ok(())
+        })
+    }
+    fn fetch_holiday_calendar(
+        &self,
+    ) -> impl tx_rs::Tx<(), Item = Option<Rc<RefCell<dyn HolidayCalendar>>>, Err = DaoError> {
+        tx_rs::with_tx(move |_| Ok(self.holiday_calendar.borrow().clone()))
+    }
+    fn set_late_entry_policy(
+        &self,
+        policy: LateEntryPolicy,
+    ) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            *self.late_entry_policy.borrow_mut() = policy;
+            Ok(())
+        })
+    }
+    fn fetch_late_entry_policy(
+        &self,
+    ) -> impl tx_rs::Tx<(), Item = LateEntryPolicy, Err = DaoError> {
+        tx_rs::with_tx(move |_| Ok(*self.late_entry_policy.borrow()))
+    }
+    fn save_commission_plan(
+        &self,
+        plan: CommissionPlan,
+    ) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            self.commission_plans
+                .borrow_mut()
+                .insert(plan.get_name().to_string(), plan);
+            Ok(())
+        })
+    }
+    fn fetch_commission_plan(
+        &self,
+        name: &str,
+    ) -> impl tx_rs::Tx<(), Item = CommissionPlan, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            self.commission_plans
+                .borrow()
+                .get(name)
+                .cloned()
+                .ok_or(DaoError::FetchError(format!(
+                    "commission plan {} not found",
+                    name
+                )))
+        })
+    }
+    fn add_scheduled_change(
+        &self,
+        change: ScheduledChange,
+    ) -> impl tx_rs::Tx<(), Item = (), Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            let mut changes = self.scheduled_changes.borrow_mut();
+            let pos = changes.partition_point(|c| c.get_effective() <= change.get_effective());
+            changes.insert(pos, change);
+            Ok(())
+        })
+    }
+    fn fetch_scheduled_changes(
+        &self,
+    ) -> impl tx_rs::Tx<(), Item = Vec<ScheduledChange>, Err = DaoError> {
+        tx_rs::with_tx(move |_| Ok(self.scheduled_changes.borrow().clone()))
+    }
+    fn remove_scheduled_changes(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    ) -> impl tx_rs::Tx<(), Item = Vec<ScheduledChange>, Err = DaoError> {
+        tx_rs::with_tx(move |_| {
+            let mut changes = self.scheduled_changes.borrow_mut();
+            let (removed, kept): (Vec<_>, Vec<_>) = changes
+                .drain(..)
+                .partition(|c| c.get_emp_id() == emp_id && c.get_effective() == effective);
+            *changes = kept;
+            Ok(removed)
+        })
+    }
 }
@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Money};
+use tx_app::Transaction;
+use tx_impl::general::AddAllowanceTx;
+
+pub struct AddAllowanceTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+    pub description: String,
+    pub amount: Money,
+}
+impl HavePayrollDao<()> for AddAllowanceTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AddAllowanceTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AddAllowanceTx::execute(self, self.emp_id, self.date, &self.description, self.amount)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -1,9 +1,10 @@
+use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money, Rate};
 use tx_app::Transaction;
 use tx_impl::general::AddCommissionedEmployeeTx;
 
@@ -13,8 +14,9 @@ pub struct AddCommissionedEmployeeTxImpl {
     pub emp_id: EmployeeId,
     pub name: String,
     pub address: String,
-    pub salary: f32,
-    pub commission_rate: f32,
+    pub salary: Money,
+    pub commission_rate: Rate,
+    pub hire_date: Option<NaiveDate>,
 }
 impl HavePayrollDao<()> for AddCommissionedEmployeeTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -30,6 +32,7 @@ impl Transaction<()> for AddCommissionedEmployeeTxImpl {
             &self.address,
             self.salary.clone(),
             self.commission_rate.clone(),
+            self.hire_date,
         )
         .map(|_| ())
         .run(ctx)
@@ -0,0 +1,39 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, GarnishmentAmount, Money};
+use tx_app::Transaction;
+use tx_impl::garnishment::AddGarnishmentTx;
+
+pub struct AddGarnishmentTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub case: String,
+    pub amount: GarnishmentAmount,
+    pub max_per_period: Option<Money>,
+    pub balance: Money,
+    pub ahead_of_union_dues: bool,
+}
+impl HavePayrollDao<()> for AddGarnishmentTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AddGarnishmentTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AddGarnishmentTx::execute(
+            self,
+            self.emp_id,
+            &self.case,
+            self.amount.clone(),
+            self.max_per_period,
+            self.balance,
+            self.ahead_of_union_dues,
+        )
+        .map(|_| ())
+        .run(ctx)
+    }
+}
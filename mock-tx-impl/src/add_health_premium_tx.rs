@@ -0,0 +1,27 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Money};
+use tx_app::Transaction;
+use tx_impl::affiliation::AddHealthPremiumTx;
+
+pub struct AddHealthPremiumTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub premium: Money,
+}
+impl HavePayrollDao<()> for AddHealthPremiumTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AddHealthPremiumTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AddHealthPremiumTx::execute(self, self.emp_id, self.premium)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -1,9 +1,10 @@
+use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money};
 use tx_app::Transaction;
 use tx_impl::general::AddHourlyEmployeeTx;
 
@@ -13,7 +14,8 @@ pub struct AddHourlyEmployeeTxImpl {
     pub emp_id: EmployeeId,
     pub name: String,
     pub address: String,
-    pub hourly_rate: f32,
+    pub hourly_rate: Money,
+    pub hire_date: Option<NaiveDate>,
 }
 impl HavePayrollDao<()> for AddHourlyEmployeeTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -28,6 +30,7 @@ impl Transaction<()> for AddHourlyEmployeeTxImpl {
             &self.name,
             &self.address,
             self.hourly_rate.clone(),
+            self.hire_date,
         )
         .map(|_| ())
         .run(ctx)
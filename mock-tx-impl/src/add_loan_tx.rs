@@ -0,0 +1,35 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, LoanRepayment, Money};
+use tx_app::Transaction;
+use tx_impl::loan::AddLoanTx;
+
+pub struct AddLoanTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub name: String,
+    pub principal: Money,
+    pub repayment: LoanRepayment,
+}
+impl HavePayrollDao<()> for AddLoanTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AddLoanTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AddLoanTx::execute(
+            self,
+            self.emp_id,
+            &self.name,
+            self.principal,
+            self.repayment.clone(),
+        )
+        .map(|_| ())
+        .run(ctx)
+    }
+}
@@ -1,9 +1,10 @@
+use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money};
 use tx_app::Transaction;
 use tx_impl::general::AddSalaryEmployeeTx;
 
@@ -13,7 +14,8 @@ pub struct AddSalaryEmployeeTxImpl {
     pub emp_id: EmployeeId,
     pub name: String,
     pub address: String,
-    pub salary: f32,
+    pub salary: Money,
+    pub hire_date: Option<NaiveDate>,
 }
 impl HavePayrollDao<()> for AddSalaryEmployeeTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -28,6 +30,7 @@ impl Transaction<()> for AddSalaryEmployeeTxImpl {
             &self.name,
             &self.address,
             self.salary.clone(),
+            self.hire_date,
         )
         .map(|_| ())
         .run(ctx)
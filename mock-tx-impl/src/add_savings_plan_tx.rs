@@ -0,0 +1,27 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Rate};
+use tx_app::Transaction;
+use tx_impl::affiliation::AddSavingsPlanTx;
+
+pub struct AddSavingsPlanTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub rate: Rate,
+}
+impl HavePayrollDao<()> for AddSavingsPlanTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AddSavingsPlanTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AddSavingsPlanTx::execute(self, self.emp_id, self.rate)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Money};
+use tx_app::Transaction;
+use tx_impl::general::AmendSalesReceiptTx;
+
+pub struct AmendSalesReceiptTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+    pub amount: Money,
+}
+impl HavePayrollDao<()> for AmendSalesReceiptTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AmendSalesReceiptTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AmendSalesReceiptTx::execute(self, self.emp_id, self.date, self.amount)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{MemberId, Money};
+use tx_app::Transaction;
+use tx_impl::affiliation::AmendServiceChargeTx;
+
+pub struct AmendServiceChargeTxImpl {
+    pub db: MockDb,
+
+    pub member_id: MemberId,
+    pub date: NaiveDate,
+    pub amount: Money,
+}
+impl HavePayrollDao<()> for AmendServiceChargeTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AmendServiceChargeTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AmendServiceChargeTx::execute(self, self.member_id, self.date, self.amount)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
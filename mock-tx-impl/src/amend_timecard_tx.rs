@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Hours};
+use tx_app::Transaction;
+use tx_impl::general::AmendTimeCardTx;
+
+pub struct AmendTimeCardTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+    pub hours: Hours,
+}
+impl HavePayrollDao<()> for AmendTimeCardTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for AmendTimeCardTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        AmendTimeCardTx::execute(self, self.emp_id, self.date, self.hours)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::scheduled::ApplyScheduledChangesTx;
+
+pub struct ApplyScheduledChangesTxImpl {
+    pub db: MockDb,
+
+    pub date: NaiveDate,
+}
+impl HavePayrollDao<()> for ApplyScheduledChangesTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ApplyScheduledChangesTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ApplyScheduledChangesTx::execute(self, self.date)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
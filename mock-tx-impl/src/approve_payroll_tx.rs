@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::payroll_run::ApprovePayrollTx;
+
+pub struct ApprovePayrollTxImpl {
+    pub db: MockDb,
+
+    pub pay_date: NaiveDate,
+}
+impl HavePayrollDao<()> for ApprovePayrollTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ApprovePayrollTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ApprovePayrollTx::execute(self, self.pay_date)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
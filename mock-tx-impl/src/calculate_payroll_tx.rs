@@ -0,0 +1,27 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::payroll_run::CalculatePayrollTx;
+
+pub struct CalculatePayrollTxImpl {
+    pub db: MockDb,
+
+    pub pay_date: NaiveDate,
+    pub rerun: bool,
+}
+impl HavePayrollDao<()> for CalculatePayrollTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for CalculatePayrollTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        CalculatePayrollTx::execute(self, self.pay_date, self.rerun)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::scheduled::CancelScheduledChangesTx;
+
+pub struct CancelScheduledChangesTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub effective: NaiveDate,
+}
+impl HavePayrollDao<()> for CancelScheduledChangesTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for CancelScheduledChangesTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        CancelScheduledChangesTx::execute(self, self.emp_id, self.effective)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
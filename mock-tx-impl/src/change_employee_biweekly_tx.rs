@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::schedule::ChangeEmployeeBiweeklyTx;
+
+pub struct ChangeEmployeeBiweeklyTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub anchor: NaiveDate,
+}
+impl HavePayrollDao<()> for ChangeEmployeeBiweeklyTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeeBiweeklyTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeeBiweeklyTx::execute(self, self.emp_id, self.anchor)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -1,9 +1,10 @@
+use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money, Rate};
 use tx_app::Transaction;
 use tx_impl::classification::ChangeEmployeeCommissionedTx;
 
@@ -11,8 +12,9 @@ pub struct ChangeEmployeeCommissionedTxImpl {
     pub db: MockDb,
 
     pub emp_id: EmployeeId,
-    pub salary: f32,
-    pub commission_rate: f32,
+    pub salary: Money,
+    pub commission_rate: Rate,
+    pub effective: Option<NaiveDate>,
 }
 impl HavePayrollDao<()> for ChangeEmployeeCommissionedTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -21,8 +23,14 @@ impl HavePayrollDao<()> for ChangeEmployeeCommissionedTxImpl {
 }
 impl Transaction<()> for ChangeEmployeeCommissionedTxImpl {
     fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
-        ChangeEmployeeCommissionedTx::execute(self, self.emp_id, self.salary, self.commission_rate)
-            .map(|_| ())
-            .run(ctx)
+        ChangeEmployeeCommissionedTx::execute(
+            self,
+            self.emp_id,
+            self.salary,
+            self.commission_rate,
+            self.effective,
+        )
+        .map(|_| ())
+        .run(ctx)
     }
 }
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::schedule::ChangeEmployeeEveryNWeeksTx;
+
+pub struct ChangeEmployeeEveryNWeeksTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub weeks: u32,
+    pub anchor: NaiveDate,
+}
+impl HavePayrollDao<()> for ChangeEmployeeEveryNWeeksTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeeEveryNWeeksTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeeEveryNWeeksTx::execute(self, self.emp_id, self.weeks, self.anchor)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -1,9 +1,10 @@
+use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money};
 use tx_app::Transaction;
 use tx_impl::classification::ChangeEmployeeHourlyTx;
 
@@ -11,7 +12,8 @@ pub struct ChangeEmployeeHourlyTxImpl {
     pub db: MockDb,
 
     pub emp_id: EmployeeId,
-    pub hourly_rate: f32,
+    pub hourly_rate: Money,
+    pub effective: Option<NaiveDate>,
 }
 impl HavePayrollDao<()> for ChangeEmployeeHourlyTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -20,7 +22,7 @@ impl HavePayrollDao<()> for ChangeEmployeeHourlyTxImpl {
 }
 impl Transaction<()> for ChangeEmployeeHourlyTxImpl {
     fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
-        ChangeEmployeeHourlyTx::execute(self, self.emp_id, self.hourly_rate)
+        ChangeEmployeeHourlyTx::execute(self, self.emp_id, self.hourly_rate, self.effective)
             .map(|_| ())
             .run(ctx)
     }
@@ -0,0 +1,27 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, OvertimePolicy};
+use tx_app::Transaction;
+use tx_impl::classification::ChangeEmployeeOvertimeTx;
+
+pub struct ChangeEmployeeOvertimeTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub policy: OvertimePolicy,
+}
+impl HavePayrollDao<()> for ChangeEmployeeOvertimeTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeeOvertimeTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeeOvertimeTx::execute(self, self.emp_id, self.policy.clone())
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,28 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Hours, PtoAccrual};
+use tx_app::Transaction;
+use tx_impl::pto::ChangeEmployeePtoTx;
+
+pub struct ChangeEmployeePtoTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub accrual: PtoAccrual,
+    pub balance: Option<Hours>,
+}
+impl HavePayrollDao<()> for ChangeEmployeePtoTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeePtoTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeePtoTx::execute(self, self.emp_id, self.accrual.clone(), self.balance)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -1,9 +1,10 @@
+use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money};
 use tx_app::Transaction;
 use tx_impl::classification::ChangeEmployeeSalariedTx;
 
@@ -11,7 +12,8 @@ pub struct ChangeEmployeeSalariedTxImpl {
     pub db: MockDb,
 
     pub emp_id: EmployeeId,
-    pub salary: f32,
+    pub salary: Money,
+    pub effective: Option<NaiveDate>,
 }
 impl HavePayrollDao<()> for ChangeEmployeeSalariedTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -20,7 +22,7 @@ impl HavePayrollDao<()> for ChangeEmployeeSalariedTxImpl {
 }
 impl Transaction<()> for ChangeEmployeeSalariedTxImpl {
     fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
-        ChangeEmployeeSalariedTx::execute(self, self.emp_id, self.salary)
+        ChangeEmployeeSalariedTx::execute(self, self.emp_id, self.salary, self.effective)
             .map(|_| ())
             .run(ctx)
     }
@@ -0,0 +1,26 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::schedule::ChangeEmployeeSemiMonthlyTx;
+
+pub struct ChangeEmployeeSemiMonthlyTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+}
+impl HavePayrollDao<()> for ChangeEmployeeSemiMonthlyTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeeSemiMonthlyTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeeSemiMonthlyTx::execute(self, self.emp_id)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,27 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, ShiftDifferential};
+use tx_app::Transaction;
+use tx_impl::classification::ChangeEmployeeShiftDifferentialTx;
+
+pub struct ChangeEmployeeShiftDifferentialTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub differential: ShiftDifferential,
+}
+impl HavePayrollDao<()> for ChangeEmployeeShiftDifferentialTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeeShiftDifferentialTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeeShiftDifferentialTx::execute(self, self.emp_id, self.differential.clone())
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,27 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::withholding::ChangeEmployeeTaxTableTx;
+
+pub struct ChangeEmployeeTaxTableTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub path: String,
+}
+impl HavePayrollDao<()> for ChangeEmployeeTaxTableTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeEmployeeTaxTableTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeEmployeeTaxTableTx::execute(self, self.emp_id, &self.path)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
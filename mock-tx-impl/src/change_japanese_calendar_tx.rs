@@ -0,0 +1,21 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::calendar::ChangeJapaneseCalendarTx;
+
+pub struct ChangeJapaneseCalendarTxImpl {
+    pub db: MockDb,
+}
+impl HavePayrollDao<()> for ChangeJapaneseCalendarTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeJapaneseCalendarTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeJapaneseCalendarTx::execute(self).map(|_| ()).run(ctx)
+    }
+}
@@ -0,0 +1,26 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::LateEntryPolicy;
+use tx_app::Transaction;
+use tx_impl::payroll_run::ChangeLateEntryPolicyTx;
+
+pub struct ChangeLateEntryPolicyTxImpl {
+    pub db: MockDb,
+
+    pub policy: LateEntryPolicy,
+}
+impl HavePayrollDao<()> for ChangeLateEntryPolicyTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ChangeLateEntryPolicyTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ChangeLateEntryPolicyTx::execute(self, self.policy)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
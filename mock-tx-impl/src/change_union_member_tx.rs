@@ -3,7 +3,7 @@ use tx_rs::Tx;
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::{EmployeeId, MemberId};
+use payroll_domain::{EmployeeId, MemberId, Money};
 use tx_app::Transaction;
 use tx_impl::affiliation::ChangeUnionMemberTx;
 
@@ -12,7 +12,7 @@ pub struct ChangeUnionMemberTxImpl {
 
     pub emp_id: EmployeeId,
     pub member_id: MemberId,
-    pub dues: f32,
+    pub dues: Money,
 }
 impl HavePayrollDao<()> for ChangeUnionMemberTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
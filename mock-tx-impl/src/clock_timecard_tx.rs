@@ -0,0 +1,30 @@
+use chrono::{NaiveDate, NaiveTime};
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::general::ClockTimeCardTx;
+
+pub struct ClockTimeCardTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+    pub clock_in: NaiveTime,
+    pub clock_out: NaiveTime,
+}
+impl HavePayrollDao<()> for ClockTimeCardTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ClockTimeCardTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ClockTimeCardTx::execute(self, self.emp_id, self.date, self.clock_in, self.clock_out)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,26 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::CommissionPlan;
+use tx_app::Transaction;
+use tx_impl::commission::DefineCommissionPlanTx;
+
+pub struct DefineCommissionPlanTxImpl {
+    pub db: MockDb,
+
+    pub plan: CommissionPlan,
+}
+impl HavePayrollDao<()> for DefineCommissionPlanTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for DefineCommissionPlanTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        DefineCommissionPlanTx::execute(self, self.plan.clone())
+            .map(|_| ())
+            .run(ctx)
+    }
+}
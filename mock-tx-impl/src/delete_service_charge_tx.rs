@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::MemberId;
+use tx_app::Transaction;
+use tx_impl::affiliation::DeleteServiceChargeTx;
+
+pub struct DeleteServiceChargeTxImpl {
+    pub db: MockDb,
+
+    pub member_id: MemberId,
+    pub date: NaiveDate,
+}
+impl HavePayrollDao<()> for DeleteServiceChargeTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for DeleteServiceChargeTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        DeleteServiceChargeTx::execute(self, self.member_id, self.date)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::general::DeleteTimeCardTx;
+
+pub struct DeleteTimeCardTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+}
+impl HavePayrollDao<()> for DeleteTimeCardTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for DeleteTimeCardTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        DeleteTimeCardTx::execute(self, self.emp_id, self.date)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Hours};
+use tx_app::Transaction;
+use tx_impl::pto::LeaveTx;
+
+pub struct LeaveTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+    pub hours: Hours,
+}
+impl HavePayrollDao<()> for LeaveTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for LeaveTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        LeaveTx::execute(self, self.emp_id, self.date, self.hours)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
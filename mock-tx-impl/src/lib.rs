@@ -1,39 +1,139 @@
+mod add_allowance_tx;
+mod add_bonus_tx;
 mod add_commissioned_employee_tx;
+mod add_garnishment_tx;
+mod add_health_premium_tx;
 mod add_hourly_employee_tx;
+mod add_loan_tx;
 mod add_salary_employee_tx;
+mod add_savings_plan_tx;
+mod amend_sales_receipt_tx;
+mod amend_service_charge_tx;
+mod amend_timecard_tx;
+mod apply_scheduled_changes_tx;
+mod approve_payroll_tx;
+mod assign_commission_plan_tx;
+mod calculate_payroll_tx;
+mod cancel_scheduled_changes_tx;
 mod change_employee_address_tx;
+mod change_employee_biweekly_tx;
 mod change_employee_commissioned_tx;
 mod change_employee_direct_tx;
+mod change_employee_every_n_weeks_tx;
 mod change_employee_hold_tx;
 mod change_employee_hourly_tx;
 mod change_employee_mail_tx;
 mod change_employee_name_tx;
+mod change_employee_overtime_tx;
+mod change_employee_pto_tx;
 mod change_employee_salaried_tx;
+mod change_employee_semi_monthly_tx;
+mod change_employee_shift_differential_tx;
+mod change_employee_tax_table_tx;
+mod change_japanese_calendar_tx;
+mod change_late_entry_policy_tx;
 mod change_unaffiliated_tx;
 mod change_union_member_tx;
+mod clock_timecard_tx;
+mod define_commission_plan_tx;
 mod delete_employee_tx;
+mod delete_sales_receipt_tx;
+mod delete_service_charge_tx;
+mod delete_timecard_tx;
+mod discard_payroll_tx;
+mod leave_tx;
+mod list_loans_tx;
+mod list_scheduled_changes_tx;
+mod load_holiday_calendar_tx;
+mod off_cycle_paycheck_tx;
 mod payday_tx;
+mod reimbursement_tx;
+mod remove_garnishment_tx;
+mod remove_health_premium_tx;
+mod remove_savings_plan_tx;
+mod retro_pay_tx;
+mod review_payroll_tx;
 mod sales_receipt_tx;
+mod schedule_employee_address_tx;
+mod schedule_employee_direct_tx;
+mod schedule_employee_hold_tx;
+mod schedule_employee_mail_tx;
+mod schedule_employee_name_tx;
+mod schedule_union_member_tx;
 mod service_charge_tx;
+mod terminate_employee_tx;
 mod timecard_tx;
 mod tx_factory_impl;
+mod unassign_commission_plan_tx;
+mod void_paycheck_tx;
+mod void_payroll_run_tx;
 
+pub use add_allowance_tx::AddAllowanceTxImpl;
+pub use add_bonus_tx::AddBonusTxImpl;
 pub use add_commissioned_employee_tx::AddCommissionedEmployeeTxImpl;
+pub use add_garnishment_tx::AddGarnishmentTxImpl;
+pub use add_health_premium_tx::AddHealthPremiumTxImpl;
 pub use add_hourly_employee_tx::AddHourlyEmployeeTxImpl;
+pub use add_loan_tx::AddLoanTxImpl;
 pub use add_salary_employee_tx::AddSalaryEmployeeTxImpl;
+pub use add_savings_plan_tx::AddSavingsPlanTxImpl;
+pub use amend_sales_receipt_tx::AmendSalesReceiptTxImpl;
+pub use amend_service_charge_tx::AmendServiceChargeTxImpl;
+pub use amend_timecard_tx::AmendTimeCardTxImpl;
+pub use apply_scheduled_changes_tx::ApplyScheduledChangesTxImpl;
+pub use approve_payroll_tx::ApprovePayrollTxImpl;
+pub use assign_commission_plan_tx::AssignCommissionPlanTxImpl;
+pub use calculate_payroll_tx::CalculatePayrollTxImpl;
+pub use cancel_scheduled_changes_tx::CancelScheduledChangesTxImpl;
 pub use change_employee_address_tx::ChangeEmployeeAddressTxImpl;
+pub use change_employee_biweekly_tx::ChangeEmployeeBiweeklyTxImpl;
 pub use change_employee_commissioned_tx::ChangeEmployeeCommissionedTxImpl;
 pub use change_employee_direct_tx::ChangeEmployeeDirectTxImpl;
+pub use change_employee_every_n_weeks_tx::ChangeEmployeeEveryNWeeksTxImpl;
 pub use change_employee_hold_tx::ChangeEmployeeHoldTxImpl;
 pub use change_employee_hourly_tx::ChangeEmployeeHourlyTxImpl;
 pub use change_employee_mail_tx::ChangeEmployeeMailTxImpl;
 pub use change_employee_name_tx::ChangeEmployeeNameTxImpl;
+pub use change_employee_overtime_tx::ChangeEmployeeOvertimeTxImpl;
+pub use change_employee_pto_tx::ChangeEmployeePtoTxImpl;
 pub use change_employee_salaried_tx::ChangeEmployeeSalariedTxImpl;
+pub use change_employee_semi_monthly_tx::ChangeEmployeeSemiMonthlyTxImpl;
+pub use change_employee_shift_differential_tx::ChangeEmployeeShiftDifferentialTxImpl;
+pub use change_employee_tax_table_tx::ChangeEmployeeTaxTableTxImpl;
+pub use change_japanese_calendar_tx::ChangeJapaneseCalendarTxImpl;
+pub use change_late_entry_policy_tx::ChangeLateEntryPolicyTxImpl;
 pub use change_unaffiliated_tx::ChangeUnaffiliatedTxImpl;
 pub use change_union_member_tx::ChangeUnionMemberTxImpl;
+pub use clock_timecard_tx::ClockTimeCardTxImpl;
+pub use define_commission_plan_tx::DefineCommissionPlanTxImpl;
 pub use delete_employee_tx::DeleteEmployeeTxImpl;
+pub use delete_sales_receipt_tx::DeleteSalesReceiptTxImpl;
+pub use delete_service_charge_tx::DeleteServiceChargeTxImpl;
+pub use delete_timecard_tx::DeleteTimeCardTxImpl;
+pub use discard_payroll_tx::DiscardPayrollTxImpl;
+pub use leave_tx::LeaveTxImpl;
+pub use list_loans_tx::ListLoansTxImpl;
+pub use list_scheduled_changes_tx::ListScheduledChangesTxImpl;
+pub use load_holiday_calendar_tx::LoadHolidayCalendarTxImpl;
+pub use off_cycle_paycheck_tx::OffCyclePaycheckTxImpl;
 pub use payday_tx::PaydayTxImpl;
+pub use reimbursement_tx::ReimbursementTxImpl;
+pub use remove_garnishment_tx::RemoveGarnishmentTxImpl;
+pub use remove_health_premium_tx::RemoveHealthPremiumTxImpl;
+pub use remove_savings_plan_tx::RemoveSavingsPlanTxImpl;
+pub use retro_pay_tx::RetroPayTxImpl;
+pub use review_payroll_tx::ReviewPayrollTxImpl;
 pub use sales_receipt_tx::SalesReceiptTxImpl;
+pub use schedule_employee_address_tx::ScheduleEmployeeAddressTxImpl;
+pub use schedule_employee_direct_tx::ScheduleEmployeeDirectTxImpl;
+pub use schedule_employee_hold_tx::ScheduleEmployeeHoldTxImpl;
+pub use schedule_employee_mail_tx::ScheduleEmployeeMailTxImpl;
+pub use schedule_employee_name_tx::ScheduleEmployeeNameTxImpl;
+pub use schedule_union_member_tx::ScheduleUnionMemberTxImpl;
 pub use service_charge_tx::ServiceChargeTxImpl;
+pub use terminate_employee_tx::TerminateEmployeeTxImpl;
 pub use timecard_tx::TimeCardTxImpl;
 pub use tx_factory_impl::TransactionFactoryImpl;
+pub use unassign_commission_plan_tx::UnassignCommissionPlanTxImpl;
+pub use void_paycheck_tx::VoidPaycheckTxImpl;
+pub use void_payroll_run_tx::VoidPayrollRunTxImpl;
@@ -0,0 +1,37 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::loan::ListLoansTx;
+
+pub struct ListLoansTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+}
+impl HavePayrollDao<()> for ListLoansTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ListLoansTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ListLoansTx::execute(self, self.emp_id)
+            .map(|loans| {
+                for loan in loans {
+                    println!(
+                        "Loan {} for emp_id {}: balance {} of {}, installment {}",
+                        loan.get_name(),
+                        self.emp_id,
+                        loan.get_balance(),
+                        loan.get_principal(),
+                        loan.get_installment()
+                    );
+                }
+            })
+            .run(ctx)
+    }
+}
@@ -0,0 +1,32 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::scheduled::ListScheduledChangesTx;
+
+pub struct ListScheduledChangesTxImpl {
+    pub db: MockDb,
+}
+impl HavePayrollDao<()> for ListScheduledChangesTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ListScheduledChangesTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ListScheduledChangesTx::execute(self)
+            .map(|changes| {
+                for change in changes {
+                    println!(
+                        "Scheduled on {} for emp_id {}: {:?}",
+                        change.get_effective(),
+                        change.get_emp_id(),
+                        change.get_change()
+                    );
+                }
+            })
+            .run(ctx)
+    }
+}
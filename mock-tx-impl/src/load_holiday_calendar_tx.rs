@@ -0,0 +1,25 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::calendar::LoadHolidayCalendarTx;
+
+pub struct LoadHolidayCalendarTxImpl {
+    pub db: MockDb,
+
+    pub path: String,
+}
+impl HavePayrollDao<()> for LoadHolidayCalendarTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for LoadHolidayCalendarTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        LoadHolidayCalendarTx::execute(self, &self.path)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,45 @@
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{Earning, EmployeeId, Money};
+use tx_app::Transaction;
+use tx_impl::payroll_run::OffCyclePaycheckTx;
+
+pub struct OffCyclePaycheckTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub pay_date: NaiveDate,
+    pub period: RangeInclusive<NaiveDate>,
+    pub extras: Vec<(String, Money)>,
+}
+impl HavePayrollDao<()> for OffCyclePaycheckTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for OffCyclePaycheckTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        let extras = self
+            .extras
+            .iter()
+            .map(|(description, amount)| Earning::Extra {
+                description: description.clone(),
+                amount: *amount,
+            })
+            .collect();
+        OffCyclePaycheckTx::execute(
+            self,
+            self.emp_id,
+            self.pay_date,
+            self.period.clone(),
+            extras,
+        )
+        .map(|_| ())
+        .run(ctx)
+    }
+}
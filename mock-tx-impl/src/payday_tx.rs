@@ -11,6 +11,7 @@ pub struct PaydayTxImpl {
     pub db: MockDb,
 
     pub pay_date: NaiveDate,
+    pub rerun: bool,
 }
 impl HavePayrollDao<()> for PaydayTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -19,6 +20,8 @@ impl HavePayrollDao<()> for PaydayTxImpl {
 }
 impl Transaction<()> for PaydayTxImpl {
     fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
-        PaydayTx::execute(self, self.pay_date).map(|_| ()).run(ctx)
+        PaydayTx::execute(self, self.pay_date, self.rerun)
+            .map(|_| ())
+            .run(ctx)
     }
 }
@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Money};
+use tx_app::Transaction;
+use tx_impl::general::ReimbursementTx;
+
+pub struct ReimbursementTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub date: NaiveDate,
+    pub description: String,
+    pub amount: Money,
+}
+impl HavePayrollDao<()> for ReimbursementTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ReimbursementTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ReimbursementTx::execute(self, self.emp_id, self.date, &self.description, self.amount)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,27 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::garnishment::RemoveGarnishmentTx;
+
+pub struct RemoveGarnishmentTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub case: String,
+}
+impl HavePayrollDao<()> for RemoveGarnishmentTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for RemoveGarnishmentTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        RemoveGarnishmentTx::execute(self, self.emp_id, &self.case)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
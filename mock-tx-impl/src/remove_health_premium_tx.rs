@@ -0,0 +1,26 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::affiliation::RemoveHealthPremiumTx;
+
+pub struct RemoveHealthPremiumTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+}
+impl HavePayrollDao<()> for RemoveHealthPremiumTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for RemoveHealthPremiumTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        RemoveHealthPremiumTx::execute(self, self.emp_id)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{Earning, EmployeeId};
+use tx_app::Transaction;
+use tx_impl::payroll_run::RetroPayTx;
+
+pub struct RetroPayTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub range: RangeInclusive<NaiveDate>,
+}
+impl HavePayrollDao<()> for RetroPayTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for RetroPayTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        RetroPayTx::execute(self, self.emp_id, self.range.clone())
+            .map(|adjustments| {
+                for adjustment in adjustments {
+                    if let Earning::Adjustment {
+                        description,
+                        amount,
+                        ..
+                    } = adjustment
+                    {
+                        println!(
+                            "Adjustment for emp_id {}: {} {}",
+                            self.emp_id, description, amount
+                        );
+                    }
+                }
+            })
+            .run(ctx)
+    }
+}
@@ -0,0 +1,43 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use tx_app::Transaction;
+use tx_impl::payroll_run::ReviewPayrollTx;
+
+pub struct ReviewPayrollTxImpl {
+    pub db: MockDb,
+
+    pub pay_date: NaiveDate,
+}
+impl HavePayrollDao<()> for ReviewPayrollTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ReviewPayrollTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ReviewPayrollTx::execute(self, self.pay_date)
+            .map(|run| {
+                println!("Payroll run for {}:", run.get_pay_date());
+                for (emp_id, pc) in run.get_paychecks() {
+                    println!(
+                        "  emp_id {}: gross {}, deductions {}, net {}",
+                        emp_id,
+                        pc.get_gross_pay(),
+                        pc.get_total_deductions(),
+                        pc.get_net_pay()
+                    );
+                }
+                println!(
+                    "  total: gross {}, deductions {}, net {}",
+                    run.get_gross_pay(),
+                    run.get_total_deductions(),
+                    run.get_net_pay()
+                );
+            })
+            .run(ctx)
+    }
+}
@@ -4,7 +4,7 @@ use tx_rs::Tx;
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money};
 use tx_app::Transaction;
 use tx_impl::general::SalesReceiptTx;
 
@@ -13,7 +13,8 @@ pub struct SalesReceiptTxImpl {
 
     pub emp_id: EmployeeId,
     pub date: NaiveDate,
-    pub amount: f32,
+    pub amount: Money,
+    pub product: Option<String>,
 }
 impl HavePayrollDao<()> for SalesReceiptTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -22,8 +23,14 @@ impl HavePayrollDao<()> for SalesReceiptTxImpl {
 }
 impl Transaction<()> for SalesReceiptTxImpl {
     fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
-        SalesReceiptTx::execute(self, self.emp_id, self.date, self.amount)
-            .map(|_| ())
-            .run(ctx)
+        SalesReceiptTx::execute(
+            self,
+            self.emp_id,
+            self.date,
+            self.amount,
+            self.product.as_deref(),
+        )
+        .map(|_| ())
+        .run(ctx)
     }
 }
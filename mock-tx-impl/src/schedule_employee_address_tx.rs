@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::scheduled::ScheduleEmployeeAddressTx;
+
+pub struct ScheduleEmployeeAddressTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub effective: NaiveDate,
+    pub address: String,
+}
+impl HavePayrollDao<()> for ScheduleEmployeeAddressTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ScheduleEmployeeAddressTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ScheduleEmployeeAddressTx::execute(self, self.emp_id, self.effective, &self.address)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::scheduled::ScheduleEmployeeDirectTx;
+
+pub struct ScheduleEmployeeDirectTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub effective: NaiveDate,
+    pub bank: String,
+    pub account: String,
+}
+impl HavePayrollDao<()> for ScheduleEmployeeDirectTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ScheduleEmployeeDirectTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ScheduleEmployeeDirectTx::execute(
+            self,
+            self.emp_id,
+            self.effective,
+            &self.bank,
+            &self.account,
+        )
+        .map(|_| ())
+        .run(ctx)
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::scheduled::ScheduleEmployeeHoldTx;
+
+pub struct ScheduleEmployeeHoldTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub effective: NaiveDate,
+}
+impl HavePayrollDao<()> for ScheduleEmployeeHoldTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ScheduleEmployeeHoldTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ScheduleEmployeeHoldTx::execute(self, self.emp_id, self.effective)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
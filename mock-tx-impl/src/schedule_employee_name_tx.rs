@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::scheduled::ScheduleEmployeeNameTx;
+
+pub struct ScheduleEmployeeNameTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub effective: NaiveDate,
+    pub name: String,
+}
+impl HavePayrollDao<()> for ScheduleEmployeeNameTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ScheduleEmployeeNameTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ScheduleEmployeeNameTx::execute(self, self.emp_id, self.effective, &self.name)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
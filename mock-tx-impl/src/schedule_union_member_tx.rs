@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, MemberId, Money};
+use tx_app::Transaction;
+use tx_impl::scheduled::ScheduleUnionMemberTx;
+
+pub struct ScheduleUnionMemberTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub effective: NaiveDate,
+    pub member_id: MemberId,
+    pub dues: Money,
+}
+impl HavePayrollDao<()> for ScheduleUnionMemberTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for ScheduleUnionMemberTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        ScheduleUnionMemberTx::execute(self, self.emp_id, self.effective, self.member_id, self.dues)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
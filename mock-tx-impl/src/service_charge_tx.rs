@@ -4,7 +4,7 @@ use tx_rs::Tx;
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::MemberId;
+use payroll_domain::{MemberId, Money};
 use tx_app::Transaction;
 use tx_impl::affiliation::ServiceChargeTx;
 
@@ -13,7 +13,7 @@ pub struct ServiceChargeTxImpl {
 
     pub member_id: MemberId,
     pub date: NaiveDate,
-    pub amount: f32,
+    pub amount: Money,
 }
 impl HavePayrollDao<()> for ServiceChargeTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
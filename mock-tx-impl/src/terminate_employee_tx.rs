@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::general::TerminateEmployeeTx;
+
+pub struct TerminateEmployeeTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub last_day: NaiveDate,
+}
+impl HavePayrollDao<()> for TerminateEmployeeTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for TerminateEmployeeTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        TerminateEmployeeTx::execute(self, self.emp_id, self.last_day)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
@@ -4,7 +4,7 @@ use tx_rs::Tx;
 use abstract_tx::UsecaseError;
 use dao::{HavePayrollDao, PayrollDao};
 use mock_db::MockDb;
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Hours};
 use tx_app::Transaction;
 use tx_impl::general::TimeCardTx;
 
@@ -13,7 +13,7 @@ pub struct TimeCardTxImpl {
 
     pub emp_id: EmployeeId,
     pub date: NaiveDate,
-    pub hours: f32,
+    pub hours: Hours,
 }
 impl HavePayrollDao<()> for TimeCardTxImpl {
     fn dao(&self) -> &impl PayrollDao<()> {
@@ -1,5 +1,8 @@
 use mock_db::MockDb;
-use payroll_domain::{EmployeeId, MemberId};
+use payroll_domain::{
+    CommissionPlan, EmployeeId, GarnishmentAmount, Hours, LateEntryPolicy, LoanRepayment, MemberId,
+    Money, OvertimePolicy, PtoAccrual, Rate, ShiftDifferential,
+};
 use tx_app::Transaction;
 use tx_factory::TransactionFactory;
 
@@ -18,7 +21,8 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        salary: f32,
+        salary: Money,
+        hire_date: Option<chrono::NaiveDate>,
     ) -> Box<dyn Transaction<()>> {
         Box::new(crate::add_salary_employee_tx::AddSalaryEmployeeTxImpl {
             db: self.db.clone(),
@@ -26,6 +30,7 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             name,
             address,
             salary,
+            hire_date,
         })
     }
     fn mk_add_hourly_employee_tx(
@@ -33,7 +38,8 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        hire_date: Option<chrono::NaiveDate>,
     ) -> Box<dyn Transaction<()>> {
         Box::new(crate::add_hourly_employee_tx::AddHourlyEmployeeTxImpl {
             db: self.db.clone(),
@@ -41,6 +47,7 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             name,
             address,
             hourly_rate,
+            hire_date,
         })
     }
     fn mk_add_commissioned_employee_tx(
@@ -48,8 +55,9 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        hire_date: Option<chrono::NaiveDate>,
     ) -> Box<dyn Transaction<()>> {
         Box::new(
             crate::add_commissioned_employee_tx::AddCommissionedEmployeeTxImpl {
@@ -59,6 +67,7 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
                 address,
                 salary,
                 commission_rate,
+                hire_date,
             },
         )
     }
@@ -68,11 +77,22 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             emp_id,
         })
     }
+    fn mk_terminate_employee_tx(
+        &self,
+        emp_id: EmployeeId,
+        last_day: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::terminate_employee_tx::TerminateEmployeeTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            last_day,
+        })
+    }
     fn mk_timecard_tx(
         &self,
         emp_id: EmployeeId,
         date: chrono::NaiveDate,
-        hours: f32,
+        hours: Hours,
     ) -> Box<dyn Transaction<()>> {
         Box::new(crate::timecard_tx::TimeCardTxImpl {
             db: self.db.clone(),
@@ -81,17 +101,140 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             hours,
         })
     }
+    fn mk_clock_timecard_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        clock_in: chrono::NaiveTime,
+        clock_out: chrono::NaiveTime,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::clock_timecard_tx::ClockTimeCardTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            clock_in,
+            clock_out,
+        })
+    }
+    fn mk_amend_timecard_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        hours: Hours,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::amend_timecard_tx::AmendTimeCardTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            hours,
+        })
+    }
+    fn mk_delete_timecard_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::delete_timecard_tx::DeleteTimeCardTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+        })
+    }
     fn mk_sales_receipt_tx(
         &self,
         emp_id: EmployeeId,
         date: chrono::NaiveDate,
-        amount: f32,
+        amount: Money,
+        product: Option<String>,
     ) -> Box<dyn Transaction<()>> {
         Box::new(crate::sales_receipt_tx::SalesReceiptTxImpl {
             db: self.db.clone(),
             emp_id,
             date,
             amount,
+            product,
+        })
+    }
+    fn mk_amend_sales_receipt_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        amount: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::amend_sales_receipt_tx::AmendSalesReceiptTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            amount,
+        })
+    }
+    fn mk_delete_sales_receipt_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::delete_sales_receipt_tx::DeleteSalesReceiptTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+        })
+    }
+    fn mk_add_bonus_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        description: String,
+        amount: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::add_bonus_tx::AddBonusTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            description,
+            amount,
+        })
+    }
+    fn mk_add_allowance_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        description: String,
+        amount: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::add_allowance_tx::AddAllowanceTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            description,
+            amount,
+        })
+    }
+    fn mk_reimbursement_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        description: String,
+        amount: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::reimbursement_tx::ReimbursementTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            description,
+            amount,
+        })
+    }
+    fn mk_leave_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: chrono::NaiveDate,
+        hours: Hours,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::leave_tx::LeaveTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            date,
+            hours,
         })
     }
     fn mk_change_name_tx(&self, emp_id: EmployeeId, name: String) -> Box<dyn Transaction<()>> {
@@ -114,33 +257,42 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             },
         )
     }
-    fn mk_change_salaried_tx(&self, emp_id: EmployeeId, salary: f32) -> Box<dyn Transaction<()>> {
+    fn mk_change_salaried_tx(
+        &self,
+        emp_id: EmployeeId,
+        salary: Money,
+        effective: Option<chrono::NaiveDate>,
+    ) -> Box<dyn Transaction<()>> {
         Box::new(
             crate::change_employee_salaried_tx::ChangeEmployeeSalariedTxImpl {
                 db: self.db.clone(),
                 emp_id,
                 salary,
+                effective,
             },
         )
     }
     fn mk_change_hourly_tx(
         &self,
         emp_id: EmployeeId,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        effective: Option<chrono::NaiveDate>,
     ) -> Box<dyn Transaction<()>> {
         Box::new(
             crate::change_employee_hourly_tx::ChangeEmployeeHourlyTxImpl {
                 db: self.db.clone(),
                 emp_id,
                 hourly_rate,
+                effective,
             },
         )
     }
     fn mk_change_commissioned_tx(
         &self,
         emp_id: EmployeeId,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        effective: Option<chrono::NaiveDate>,
     ) -> Box<dyn Transaction<()>> {
         Box::new(
             crate::change_employee_commissioned_tx::ChangeEmployeeCommissionedTxImpl {
@@ -148,6 +300,62 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
                 emp_id,
                 salary,
                 commission_rate,
+                effective,
+            },
+        )
+    }
+    fn mk_define_commission_plan_tx(&self, plan: CommissionPlan) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::define_commission_plan_tx::DefineCommissionPlanTxImpl {
+                db: self.db.clone(),
+                plan,
+            },
+        )
+    }
+    fn mk_assign_commission_plan_tx(
+        &self,
+        emp_id: EmployeeId,
+        name: String,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::assign_commission_plan_tx::AssignCommissionPlanTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                name,
+            },
+        )
+    }
+    fn mk_unassign_commission_plan_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::unassign_commission_plan_tx::UnassignCommissionPlanTxImpl {
+                db: self.db.clone(),
+                emp_id,
+            },
+        )
+    }
+    fn mk_change_overtime_tx(
+        &self,
+        emp_id: EmployeeId,
+        policy: OvertimePolicy,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_employee_overtime_tx::ChangeEmployeeOvertimeTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                policy,
+            },
+        )
+    }
+    fn mk_change_shift_differential_tx(
+        &self,
+        emp_id: EmployeeId,
+        differential: ShiftDifferential,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_employee_shift_differential_tx::ChangeEmployeeShiftDifferentialTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                differential,
             },
         )
     }
@@ -179,11 +387,47 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             emp_id,
         })
     }
+    fn mk_change_biweekly_tx(
+        &self,
+        emp_id: EmployeeId,
+        anchor: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_employee_biweekly_tx::ChangeEmployeeBiweeklyTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                anchor,
+            },
+        )
+    }
+    fn mk_change_semi_monthly_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_employee_semi_monthly_tx::ChangeEmployeeSemiMonthlyTxImpl {
+                db: self.db.clone(),
+                emp_id,
+            },
+        )
+    }
+    fn mk_change_every_n_weeks_tx(
+        &self,
+        emp_id: EmployeeId,
+        weeks: u32,
+        anchor: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_employee_every_n_weeks_tx::ChangeEmployeeEveryNWeeksTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                weeks,
+                anchor,
+            },
+        )
+    }
     fn mk_change_union_member_tx(
         &self,
         emp_id: EmployeeId,
         member_id: MemberId,
-        dues: f32,
+        dues: Money,
     ) -> Box<dyn Transaction<()>> {
         Box::new(crate::change_union_member_tx::ChangeUnionMemberTxImpl {
             db: self.db.clone(),
@@ -198,11 +442,114 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             emp_id,
         })
     }
+    fn mk_add_health_premium_tx(
+        &self,
+        emp_id: EmployeeId,
+        premium: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::add_health_premium_tx::AddHealthPremiumTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            premium,
+        })
+    }
+    fn mk_remove_health_premium_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<()>> {
+        Box::new(crate::remove_health_premium_tx::RemoveHealthPremiumTxImpl {
+            db: self.db.clone(),
+            emp_id,
+        })
+    }
+    fn mk_add_savings_plan_tx(&self, emp_id: EmployeeId, rate: Rate) -> Box<dyn Transaction<()>> {
+        Box::new(crate::add_savings_plan_tx::AddSavingsPlanTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            rate,
+        })
+    }
+    fn mk_remove_savings_plan_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<()>> {
+        Box::new(crate::remove_savings_plan_tx::RemoveSavingsPlanTxImpl {
+            db: self.db.clone(),
+            emp_id,
+        })
+    }
+    fn mk_add_garnishment_tx(
+        &self,
+        emp_id: EmployeeId,
+        case: String,
+        amount: GarnishmentAmount,
+        max_per_period: Option<Money>,
+        balance: Money,
+        ahead_of_union_dues: bool,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::add_garnishment_tx::AddGarnishmentTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            case,
+            amount,
+            max_per_period,
+            balance,
+            ahead_of_union_dues,
+        })
+    }
+    fn mk_remove_garnishment_tx(
+        &self,
+        emp_id: EmployeeId,
+        case: String,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::remove_garnishment_tx::RemoveGarnishmentTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            case,
+        })
+    }
+    fn mk_add_loan_tx(
+        &self,
+        emp_id: EmployeeId,
+        name: String,
+        principal: Money,
+        repayment: LoanRepayment,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::add_loan_tx::AddLoanTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            name,
+            principal,
+            repayment,
+        })
+    }
+    fn mk_list_loans_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<()>> {
+        Box::new(crate::list_loans_tx::ListLoansTxImpl {
+            db: self.db.clone(),
+            emp_id,
+        })
+    }
+    fn mk_change_pto_tx(
+        &self,
+        emp_id: EmployeeId,
+        accrual: PtoAccrual,
+        balance: Option<Hours>,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::change_employee_pto_tx::ChangeEmployeePtoTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            accrual,
+            balance,
+        })
+    }
+    fn mk_change_tax_table_tx(&self, emp_id: EmployeeId, path: String) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_employee_tax_table_tx::ChangeEmployeeTaxTableTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                path,
+            },
+        )
+    }
     fn mk_service_charge_tx(
         &self,
         member_id: MemberId,
         date: chrono::prelude::NaiveDate,
-        amount: f32,
+        amount: Money,
     ) -> Box<dyn Transaction<()>> {
         Box::new(crate::service_charge_tx::ServiceChargeTxImpl {
             db: self.db.clone(),
@@ -211,10 +558,248 @@ impl TransactionFactory<()> for TransactionFactoryImpl {
             amount,
         })
     }
-    fn mk_payday_tx(&self, pay_date: chrono::NaiveDate) -> Box<dyn Transaction<()>> {
+    fn mk_amend_service_charge_tx(
+        &self,
+        member_id: MemberId,
+        date: chrono::NaiveDate,
+        amount: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::amend_service_charge_tx::AmendServiceChargeTxImpl {
+            db: self.db.clone(),
+            member_id,
+            date,
+            amount,
+        })
+    }
+    fn mk_delete_service_charge_tx(
+        &self,
+        member_id: MemberId,
+        date: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::delete_service_charge_tx::DeleteServiceChargeTxImpl {
+            db: self.db.clone(),
+            member_id,
+            date,
+        })
+    }
+    fn mk_change_late_entry_policy_tx(&self, policy: LateEntryPolicy) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_late_entry_policy_tx::ChangeLateEntryPolicyTxImpl {
+                db: self.db.clone(),
+                policy,
+            },
+        )
+    }
+    fn mk_change_japanese_calendar_tx(&self) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::change_japanese_calendar_tx::ChangeJapaneseCalendarTxImpl {
+                db: self.db.clone(),
+            },
+        )
+    }
+    fn mk_load_holiday_calendar_tx(&self, path: String) -> Box<dyn Transaction<()>> {
+        Box::new(crate::load_holiday_calendar_tx::LoadHolidayCalendarTxImpl {
+            db: self.db.clone(),
+            path,
+        })
+    }
+    fn mk_schedule_name_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+        name: String,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::schedule_employee_name_tx::ScheduleEmployeeNameTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                effective,
+                name,
+            },
+        )
+    }
+    fn mk_schedule_address_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+        address: String,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::schedule_employee_address_tx::ScheduleEmployeeAddressTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                effective,
+                address,
+            },
+        )
+    }
+    fn mk_schedule_hold_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::schedule_employee_hold_tx::ScheduleEmployeeHoldTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                effective,
+            },
+        )
+    }
+    fn mk_schedule_direct_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+        bank: String,
+        account: String,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::schedule_employee_direct_tx::ScheduleEmployeeDirectTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                effective,
+                bank,
+                account,
+            },
+        )
+    }
+    fn mk_schedule_mail_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+        address: String,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::schedule_employee_mail_tx::ScheduleEmployeeMailTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                effective,
+                address,
+            },
+        )
+    }
+    fn mk_schedule_union_member_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+        member_id: MemberId,
+        dues: Money,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::schedule_union_member_tx::ScheduleUnionMemberTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            effective,
+            member_id,
+            dues,
+        })
+    }
+    fn mk_cancel_scheduled_changes_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: chrono::NaiveDate,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::cancel_scheduled_changes_tx::CancelScheduledChangesTxImpl {
+                db: self.db.clone(),
+                emp_id,
+                effective,
+            },
+        )
+    }
+    fn mk_list_scheduled_changes_tx(&self) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::list_scheduled_changes_tx::ListScheduledChangesTxImpl {
+                db: self.db.clone(),
+            },
+        )
+    }
+    fn mk_advance_date_tx(&self, date: chrono::NaiveDate) -> Box<dyn Transaction<()>> {
+        Box::new(
+            crate::apply_scheduled_changes_tx::ApplyScheduledChangesTxImpl {
+                db: self.db.clone(),
+                date,
+            },
+        )
+    }
+    fn mk_calculate_payroll_tx(
+        &self,
+        pay_date: chrono::NaiveDate,
+        rerun: bool,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::calculate_payroll_tx::CalculatePayrollTxImpl {
+            db: self.db.clone(),
+            pay_date,
+            rerun,
+        })
+    }
+    fn mk_review_payroll_tx(&self, pay_date: chrono::NaiveDate) -> Box<dyn Transaction<()>> {
+        Box::new(crate::review_payroll_tx::ReviewPayrollTxImpl {
+            db: self.db.clone(),
+            pay_date,
+        })
+    }
+    fn mk_approve_payroll_tx(&self, pay_date: chrono::NaiveDate) -> Box<dyn Transaction<()>> {
+        Box::new(crate::approve_payroll_tx::ApprovePayrollTxImpl {
+            db: self.db.clone(),
+            pay_date,
+        })
+    }
+    fn mk_discard_payroll_tx(&self, pay_date: chrono::NaiveDate) -> Box<dyn Transaction<()>> {
+        Box::new(crate::discard_payroll_tx::DiscardPayrollTxImpl {
+            db: self.db.clone(),
+            pay_date,
+        })
+    }
+    fn mk_void_paycheck_tx(
+        &self,
+        emp_id: EmployeeId,
+        pay_date: chrono::NaiveDate,
+        off_cycle: bool,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::void_paycheck_tx::VoidPaycheckTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            pay_date,
+            off_cycle,
+        })
+    }
+    fn mk_void_payroll_run_tx(&self, pay_date: chrono::NaiveDate) -> Box<dyn Transaction<()>> {
+        Box::new(crate::void_payroll_run_tx::VoidPayrollRunTxImpl {
+            db: self.db.clone(),
+            pay_date,
+        })
+    }
+    fn mk_off_cycle_paycheck_tx(
+        &self,
+        emp_id: EmployeeId,
+        pay_date: chrono::NaiveDate,
+        period: std::ops::RangeInclusive<chrono::NaiveDate>,
+        extras: Vec<(String, Money)>,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::off_cycle_paycheck_tx::OffCyclePaycheckTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            pay_date,
+            period,
+            extras,
+        })
+    }
+    fn mk_retro_pay_tx(
+        &self,
+        emp_id: EmployeeId,
+        range: std::ops::RangeInclusive<chrono::NaiveDate>,
+    ) -> Box<dyn Transaction<()>> {
+        Box::new(crate::retro_pay_tx::RetroPayTxImpl {
+            db: self.db.clone(),
+            emp_id,
+            range,
+        })
+    }
+    fn mk_payday_tx(&self, pay_date: chrono::NaiveDate, rerun: bool) -> Box<dyn Transaction<()>> {
         Box::new(crate::payday_tx::PaydayTxImpl {
             db: self.db.clone(),
             pay_date,
+            rerun,
         })
     }
 }
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::EmployeeId;
+use tx_app::Transaction;
+use tx_impl::payroll_run::VoidPaycheckTx;
+
+pub struct VoidPaycheckTxImpl {
+    pub db: MockDb,
+
+    pub emp_id: EmployeeId,
+    pub pay_date: NaiveDate,
+    pub off_cycle: bool,
+}
+impl HavePayrollDao<()> for VoidPaycheckTxImpl {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+impl Transaction<()> for VoidPaycheckTxImpl {
+    fn execute<'a>(&'a self, ctx: &mut ()) -> Result<(), UsecaseError> {
+        VoidPaycheckTx::execute(self, self.emp_id, self.pay_date, self.off_cycle)
+            .map(|_| ())
+            .run(ctx)
+    }
+}
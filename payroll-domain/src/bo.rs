@@ -1,5 +1,33 @@
+mod commission_plan;
+mod deduction;
+mod earning;
 mod employee;
+mod garnishment;
+mod late_entry_policy;
+mod loan;
+mod overtime_policy;
+mod paid_entry;
+mod paid_time_off;
 mod paycheck;
+mod payroll_run;
+mod reimbursement;
+mod reversal;
+mod scheduled_change;
+mod shift_differential;
 
+pub use commission_plan::{CommissionPlan, CommissionTier};
+pub use deduction::Deduction;
+pub use earning::Earning;
 pub use employee::Employee;
+pub use garnishment::{Garnishment, GarnishmentAmount};
+pub use late_entry_policy::LateEntryPolicy;
+pub use loan::{Loan, LoanRepayment};
+pub use overtime_policy::{OvertimePolicy, OvertimeRule};
+pub use paid_entry::PaidEntry;
+pub use paid_time_off::{PaidTimeOff, PtoAccrual};
 pub use paycheck::Paycheck;
+pub use payroll_run::PayrollRun;
+pub use reimbursement::Reimbursement;
+pub use reversal::Reversal;
+pub use scheduled_change::{EmployeeChange, ScheduledChange};
+pub use shift_differential::{ShiftDifferential, ShiftWindow};
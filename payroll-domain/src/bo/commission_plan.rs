@@ -0,0 +1,151 @@
+use crate::types::{Money, Rate};
+
+// the rate on the part of a period's sales from `threshold` up to the next tier
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommissionTier {
+    threshold: Money,
+    rate: Rate,
+}
+impl CommissionTier {
+    pub fn new(threshold: Money, rate: Rate) -> Self {
+        Self { threshold, rate }
+    }
+    pub fn get_threshold(&self) -> Money {
+        self.threshold
+    }
+    pub fn get_rate(&self) -> Rate {
+        self.rate
+    }
+}
+
+// tiered rates on the sales of each period, flat rates for some products, and a cap
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommissionPlan {
+    name: String,
+    tiers: Vec<CommissionTier>,
+    // sales of these products are paid at their own rate and don't count towards the tiers
+    product_rates: Vec<(String, Rate)>,
+    // the most commission paid on one paycheck
+    cap: Option<Money>,
+}
+impl CommissionPlan {
+    pub fn new(
+        name: &str,
+        mut tiers: Vec<CommissionTier>,
+        product_rates: Vec<(String, Rate)>,
+        cap: Option<Money>,
+    ) -> Self {
+        tiers.sort_by_key(|t| t.threshold);
+        Self {
+            name: name.to_string(),
+            tiers,
+            product_rates,
+            cap,
+        }
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_tiers(&self) -> &[CommissionTier] {
+        &self.tiers
+    }
+    pub fn get_product_rates(&self) -> &[(String, Rate)] {
+        &self.product_rates
+    }
+    pub fn get_cap(&self) -> Option<Money> {
+        self.cap
+    }
+    // the commission on each of the period's sales, taken in the order given
+    pub fn calculate(&self, sales: &[(Option<&str>, Money)]) -> Vec<Money> {
+        let mut tiered = Money::ZERO;
+        let mut paid = Money::ZERO;
+        sales
+            .iter()
+            .map(|(product, amount)| {
+                let product_rate = product.and_then(|p| {
+                    self.product_rates
+                        .iter()
+                        .find(|(name, _)| name == p)
+                        .map(|(_, rate)| *rate)
+                });
+                let commission = match product_rate {
+                    Some(rate) => *amount * rate,
+                    None => {
+                        let (from, to) = (tiered, tiered + *amount);
+                        tiered = to;
+                        self.tiered_commission(from, to)
+                    }
+                };
+                let commission = match self.cap {
+                    Some(cap) => commission.min(cap - paid).max(Money::ZERO),
+                    None => commission,
+                };
+                paid += commission;
+                commission
+            })
+            .collect()
+    }
+    // the commission on the sales between `from` and `to` of the period's running total
+    fn tiered_commission(&self, from: Money, to: Money) -> Money {
+        self.tiers
+            .iter()
+            .enumerate()
+            .map(|(i, tier)| {
+                let lower = from.max(tier.threshold);
+                let upper = self
+                    .tiers
+                    .get(i + 1)
+                    .map_or(to, |next| to.min(next.threshold));
+                if lower < upper {
+                    (upper - lower) * tier.rate
+                } else {
+                    Money::ZERO
+                }
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test_commission_plan {
+    use super::*;
+
+    fn plan(cap: Option<Money>) -> CommissionPlan {
+        CommissionPlan::new(
+            "Tiered",
+            vec![
+                CommissionTier::new(Money::from_cents(1000000), Rate::from_basis_points(800)),
+                CommissionTier::new(Money::ZERO, Rate::from_basis_points(500)),
+            ],
+            vec![("Service".to_string(), Rate::from_basis_points(1200))],
+            cap,
+        )
+    }
+
+    #[test]
+    fn test_tiers() {
+        // 6k at 5%, then 4k at 5% and 2k at 8%
+        assert_eq!(
+            plan(None).calculate(&[
+                (None, Money::from_cents(600000)),
+                (None, Money::from_cents(600000)),
+            ]),
+            vec![Money::from_cents(30000), Money::from_cents(36000)]
+        );
+    }
+    #[test]
+    fn test_product_rate_and_cap() {
+        assert_eq!(
+            plan(Some(Money::from_cents(50000))).calculate(&[
+                (Some("Service"), Money::from_cents(100000)),
+                (Some("Widget"), Money::from_cents(600000)),
+                (None, Money::from_cents(600000)),
+            ]),
+            vec![
+                Money::from_cents(12000),
+                Money::from_cents(30000),
+                Money::from_cents(8000),
+            ]
+        );
+    }
+}
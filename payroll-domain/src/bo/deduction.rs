@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use std::fmt::Debug;
+
+use crate::types::Money;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Deduction {
+    Withholding { amount: Money },
+    UnionDues { date: NaiveDate, amount: Money },
+    ServiceCharge { date: NaiveDate, amount: Money },
+    HealthPremium { amount: Money },
+    SavingsPlan { amount: Money },
+    LoanRepayment { loan: String, amount: Money },
+    Garnishment { case: String, amount: Money },
+}
+impl Deduction {
+    pub fn get_amount(&self) -> Money {
+        match self {
+            Deduction::Withholding { amount }
+            | Deduction::UnionDues { amount, .. }
+            | Deduction::ServiceCharge { amount, .. }
+            | Deduction::HealthPremium { amount }
+            | Deduction::SavingsPlan { amount }
+            | Deduction::LoanRepayment { amount, .. }
+            | Deduction::Garnishment { amount, .. } => *amount,
+        }
+    }
+}
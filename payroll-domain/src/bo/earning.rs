@@ -0,0 +1,102 @@
+use chrono::NaiveDate;
+use std::fmt::Debug;
+
+use crate::types::{Hours, Money, Rate};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Earning {
+    Salary {
+        amount: Money,
+    },
+    RegularHours {
+        date: NaiveDate,
+        hours: Hours,
+        amount: Money,
+    },
+    OvertimeHours {
+        date: NaiveDate,
+        hours: Hours,
+        multiplier: Rate,
+        amount: Money,
+    },
+    Commission {
+        date: NaiveDate,
+        sales: Money,
+        amount: Money,
+    },
+    // the premium for hours worked inside a shift differential window
+    ShiftDifferential {
+        date: NaiveDate,
+        hours: Hours,
+        amount: Money,
+    },
+    // paid time off at the regular rate
+    Leave {
+        date: NaiveDate,
+        hours: Hours,
+        amount: Money,
+    },
+    Bonus {
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    },
+    Allowance {
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    },
+    // carried into a later paycheck for an entry dated in a period already paid
+    Adjustment {
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    },
+    // entered by hand on an off-cycle paycheck
+    Extra {
+        description: String,
+        amount: Money,
+    },
+}
+impl Earning {
+    pub fn get_amount(&self) -> Money {
+        match self {
+            Earning::Salary { amount }
+            | Earning::RegularHours { amount, .. }
+            | Earning::OvertimeHours { amount, .. }
+            | Earning::Commission { amount, .. }
+            | Earning::ShiftDifferential { amount, .. }
+            | Earning::Leave { amount, .. }
+            | Earning::Bonus { amount, .. }
+            | Earning::Allowance { amount, .. }
+            | Earning::Adjustment { amount, .. }
+            | Earning::Extra { amount, .. } => *amount,
+        }
+    }
+    // made by the payment classification rather than entered for the employee
+    pub fn is_wage(&self) -> bool {
+        matches!(
+            self,
+            Earning::Salary { .. }
+                | Earning::RegularHours { .. }
+                | Earning::OvertimeHours { .. }
+                | Earning::Commission { .. }
+                | Earning::ShiftDifferential { .. }
+                | Earning::Leave { .. }
+        )
+    }
+    // the day the earning was made, if it is tied to one
+    pub fn get_date(&self) -> Option<NaiveDate> {
+        match self {
+            Earning::RegularHours { date, .. }
+            | Earning::OvertimeHours { date, .. }
+            | Earning::Commission { date, .. }
+            | Earning::ShiftDifferential { date, .. }
+            | Earning::Leave { date, .. }
+            | Earning::Bonus { date, .. }
+            | Earning::Allowance { date, .. }
+            | Earning::Adjustment { date, .. } => Some(*date),
+            Earning::Salary { .. } | Earning::Extra { .. } => None,
+        }
+    }
+}
@@ -1,39 +1,70 @@
-use chrono::NaiveDate;
+use chrono::{Days, NaiveDate};
 use std::{cell::RefCell, fmt::Debug, ops::RangeInclusive, rc::Rc};
 
-use crate::bo::Paycheck;
-use crate::interface::{Affiliation, PaymentClassification, PaymentMethod, PaymentSchedule};
-use crate::types::EmployeeId;
+use crate::bo::{
+    Deduction, Earning, Garnishment, Loan, PaidEntry, PaidTimeOff, Paycheck, Reimbursement,
+};
+use crate::interface::{
+    Affiliation, HolidayCalendar, PaymentClassification, PaymentMethod, PaymentSchedule,
+    Withholding,
+};
+use crate::types::{EmployeeId, Hours, Money};
 
 #[derive(Debug, Clone)]
 pub struct Employee {
     emp_id: EmployeeId,
     name: String,
     address: String,
+    // None for employees hired before the payroll records
+    hire_date: Option<NaiveDate>,
+    // the last working day
+    termination_date: Option<NaiveDate>,
 
     classification: Rc<RefCell<dyn PaymentClassification>>,
     schedule: Rc<RefCell<dyn PaymentSchedule>>,
     method: Rc<RefCell<dyn PaymentMethod>>,
-    affiliation: Rc<RefCell<dyn Affiliation>>,
+    affiliations: Vec<Rc<RefCell<dyn Affiliation>>>,
+    withholding: Rc<RefCell<dyn Withholding>>,
+    // bonuses and allowances paid whatever the classification
+    additional_earnings: Vec<Earning>,
+    // carried into the next paycheck whatever its period
+    adjustments: Vec<Earning>,
+    // outstanding until a paycheck repays them
+    reimbursements: Vec<Reimbursement>,
+    loans: Vec<Loan>,
+    pto: Option<PaidTimeOff>,
+    // in order of priority, withheld ahead of the loans
+    garnishments: Vec<Garnishment>,
 }
 impl Employee {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         emp_id: EmployeeId,
         name: &str,
         address: &str,
+        hire_date: Option<NaiveDate>,
         classification: Rc<RefCell<dyn PaymentClassification>>,
         schedule: Rc<RefCell<dyn PaymentSchedule>>,
         method: Rc<RefCell<dyn PaymentMethod>>,
-        affiliation: Rc<RefCell<dyn Affiliation>>,
+        withholding: Rc<RefCell<dyn Withholding>>,
     ) -> Self {
         Self {
             emp_id,
             name: name.to_string(),
             address: address.to_string(),
+            hire_date,
+            termination_date: None,
             classification,
             schedule,
             method,
-            affiliation,
+            affiliations: vec![],
+            withholding,
+            additional_earnings: vec![],
+            adjustments: vec![],
+            reimbursements: vec![],
+            loans: vec![],
+            pto: None,
+            garnishments: vec![],
         }
     }
     pub fn get_emp_id(&self) -> EmployeeId {
@@ -45,6 +76,28 @@ impl Employee {
     pub fn set_address(&mut self, address: &str) {
         self.address = address.to_string();
     }
+    pub fn get_hire_date(&self) -> Option<NaiveDate> {
+        self.hire_date
+    }
+    pub fn get_termination_date(&self) -> Option<NaiveDate> {
+        self.termination_date
+    }
+    pub fn set_termination_date(&mut self, last_day: NaiveDate) {
+        self.termination_date = Some(last_day);
+    }
+    // the part of `period` between hire and termination, if any
+    pub fn get_employed_period(
+        &self,
+        period: &RangeInclusive<NaiveDate>,
+    ) -> Option<RangeInclusive<NaiveDate>> {
+        let start = self
+            .hire_date
+            .map_or(*period.start(), |d| d.max(*period.start()));
+        let end = self
+            .termination_date
+            .map_or(*period.end(), |d| d.min(*period.end()));
+        (start <= end).then_some(start..=end)
+    }
     pub fn get_classification(&self) -> Rc<RefCell<dyn PaymentClassification>> {
         self.classification.clone()
     }
@@ -57,25 +110,469 @@ impl Employee {
     pub fn set_method(&mut self, method: Rc<RefCell<dyn PaymentMethod>>) {
         self.method = method;
     }
-    pub fn get_affiliation(&self) -> Rc<RefCell<dyn Affiliation>> {
-        self.affiliation.clone()
+    pub fn get_affiliations(&self) -> &[Rc<RefCell<dyn Affiliation>>] {
+        &self.affiliations
+    }
+    pub fn add_affiliation(&mut self, affiliation: Rc<RefCell<dyn Affiliation>>) {
+        self.affiliations.push(affiliation);
+    }
+    // remove every affiliation matching `pred`, keeping the order of the rest
+    pub fn remove_affiliations<P>(&mut self, pred: P) -> Vec<Rc<RefCell<dyn Affiliation>>>
+    where
+        P: Fn(&dyn Affiliation) -> bool,
+    {
+        let (removed, kept): (Vec<_>, Vec<_>) = self
+            .affiliations
+            .drain(..)
+            .partition(|a| pred(&*a.borrow()));
+        self.affiliations = kept;
+        removed
+    }
+    pub fn set_withholding(&mut self, withholding: Rc<RefCell<dyn Withholding>>) {
+        self.withholding = withholding;
+    }
+    pub fn get_additional_earnings(&self) -> &[Earning] {
+        &self.additional_earnings
+    }
+    pub fn add_additional_earning(&mut self, earning: Earning) {
+        self.additional_earnings.push(earning);
+    }
+    pub fn get_adjustments(&self) -> &[Earning] {
+        &self.adjustments
+    }
+    pub fn add_adjustment(&mut self, adjustment: Earning) {
+        self.adjustments.push(adjustment);
+    }
+    pub fn get_reimbursements(&self) -> &[Reimbursement] {
+        &self.reimbursements
+    }
+    pub fn add_reimbursement(&mut self, reimbursement: Reimbursement) {
+        self.reimbursements.push(reimbursement);
+    }
+    pub fn get_loans(&self) -> &[Loan] {
+        &self.loans
+    }
+    pub fn add_loan(&mut self, loan: Loan) {
+        self.loans.push(loan);
+    }
+    pub fn get_pto(&self) -> Option<&PaidTimeOff> {
+        self.pto.as_ref()
+    }
+    pub fn get_pto_mut(&mut self) -> Option<&mut PaidTimeOff> {
+        self.pto.as_mut()
+    }
+    pub fn set_pto(&mut self, pto: PaidTimeOff) {
+        self.pto = Some(pto);
+    }
+    pub fn get_garnishments(&self) -> &[Garnishment] {
+        &self.garnishments
+    }
+    pub fn add_garnishment(&mut self, garnishment: Garnishment) {
+        self.garnishments.push(garnishment);
+    }
+    pub fn remove_garnishment(&mut self, case: &str) -> Option<Garnishment> {
+        let i = self
+            .garnishments
+            .iter()
+            .position(|g| g.get_case() == case)?;
+        Some(self.garnishments.remove(i))
     }
-    pub fn set_affiliation(&mut self, affiliation: Rc<RefCell<dyn Affiliation>>) {
-        self.affiliation = affiliation;
+    pub fn is_pay_date(&self, date: NaiveDate, calendar: &dyn HolidayCalendar) -> bool {
+        self.schedule.borrow().is_pay_date(date, calendar)
     }
-    pub fn is_pay_date(&self, date: NaiveDate) -> bool {
-        self.schedule.borrow().is_pay_date(date)
+    pub fn get_nominal_pay_date(
+        &self,
+        date: NaiveDate,
+        calendar: &dyn HolidayCalendar,
+    ) -> Option<NaiveDate> {
+        self.schedule.borrow().nominal_pay_date(date, calendar)
     }
     pub fn get_pay_period(&self, payday: NaiveDate) -> RangeInclusive<NaiveDate> {
         self.schedule.borrow().calculate_period(payday)
     }
     pub fn payday(&self, pc: &mut Paycheck) {
-        let gross_pay = self.classification.borrow().calculate_pay(pc);
-        let deductions = self.affiliation.borrow().calculate_deductions(pc);
-        let net_pay = gross_pay - deductions;
-        pc.set_gross_pay(gross_pay);
-        pc.set_deductions(deductions);
-        pc.set_net_pay(net_pay);
+        self.calculate_pay(pc);
+        self.pay(pc);
+    }
+    // fill in the paycheck without paying it
+    pub fn calculate_pay(&self, pc: &mut Paycheck) {
+        if let Some(employed) = self.get_employed_period(&pc.get_period()) {
+            pc.set_employed_period(employed);
+        }
+        let parts = if pc.is_off_cycle() {
+            self.scheduled_parts(pc)
+        } else {
+            vec![pc.clone()]
+        };
+        for part in parts {
+            let classification = self.classification.borrow();
+            pc.add_earnings(classification.calculate_pay(&part));
+            pc.add_paid_entries(classification.paid_entries(&part));
+        }
+        let period = pc.get_period();
+        let additional = self
+            .additional_earnings
+            .iter()
+            .filter(|e| e.get_date().is_some_and(|d| period.contains(&d)))
+            .filter(|e| !pc.is_paid_elsewhere(&Self::additional_entry(e)))
+            .cloned()
+            .collect::<Vec<_>>();
+        pc.add_paid_entries(additional.iter().map(Self::additional_entry).collect());
+        pc.add_earnings(additional);
+        pc.add_earnings(self.adjustments.clone());
+        let withholding = self
+            .withholding
+            .borrow()
+            .calculate_withholding(pc, &*self.schedule.borrow());
+        pc.add_deductions(withholding);
+        let (ahead, behind): (Vec<_>, Vec<_>) = self
+            .garnishments
+            .iter()
+            .partition(|g| g.is_ahead_of_union_dues());
+        Self::garnish(pc, &ahead);
+        for affiliation in &self.affiliations {
+            let deductions = affiliation.borrow().calculate_deductions(pc);
+            pc.add_deductions(deductions);
+        }
+        Self::garnish(pc, &behind);
+        // installments fall due on the scheduled paydays only
+        let off_cycle = pc.is_off_cycle();
+        for loan in self.loans.iter().filter(|_| !off_cycle) {
+            let amount = loan.calculate_deduction(pc);
+            if !amount.is_zero() {
+                pc.add_deductions(vec![Deduction::LoanRepayment {
+                    loan: loan.get_name().to_string(),
+                    amount,
+                }]);
+            }
+        }
+        // repaid on the first paycheck that ends on or after the filing date
+        let reimbursements = self
+            .reimbursements
+            .iter()
+            .filter(|r| r.get_date() <= *period.end())
+            .cloned()
+            .collect();
+        pc.add_reimbursements(reimbursements);
+    }
+    // An off-cycle period is paid as the parts of the scheduled periods it falls in, so
+    // that the base pay prorates and the workweeks run as they do on the scheduled paydays.
+    fn scheduled_parts(&self, pc: &Paycheck) -> Vec<Paycheck> {
+        let schedule = self.schedule.borrow();
+        let employed = pc.get_employed_period();
+        let mut parts = vec![];
+        let mut start = *employed.start();
+        while start <= *employed.end() {
+            let Some(payday) = start
+                .iter_days()
+                .take(366)
+                .find(|d| schedule.is_nominal_pay_date(*d))
+            else {
+                break;
+            };
+            let period = schedule.calculate_period(payday);
+            let end = (*period.end()).min(*employed.end());
+            parts.push(pc.part(period, start..=end));
+            start = end + Days::new(1);
+        }
+        parts
+    }
+    fn additional_entry(earning: &Earning) -> PaidEntry {
+        PaidEntry::Additional {
+            earning: earning.clone(),
+        }
+    }
+    fn garnish(pc: &mut Paycheck, garnishments: &[&Garnishment]) {
+        for garnishment in garnishments {
+            let amount = garnishment.calculate_deduction(pc);
+            if !amount.is_zero() {
+                pc.add_deductions(vec![Deduction::Garnishment {
+                    case: garnishment.get_case().to_string(),
+                    amount,
+                }]);
+            }
+        }
+    }
+    pub fn pay(&self, pc: &Paycheck) {
         self.method.borrow().pay(pc);
     }
+    // run down the balances by what an issued paycheck withheld, and accrue leave
+    pub fn settle(&mut self, pc: &Paycheck) {
+        self.pay_down_balances(pc, |amount| amount);
+        self.adjustments.retain(|a| !pc.get_earnings().contains(a));
+        self.reimbursements
+            .retain(|r| !pc.get_reimbursements().contains(r));
+        if let Some(pto) = &mut self.pto {
+            let hours = pto.accrued(pc);
+            pto.accrue(hours);
+        }
+    }
+    // give back what a voided paycheck withheld or accrued
+    pub fn unsettle(&mut self, pc: &Paycheck) {
+        self.pay_down_balances(pc, |amount| -amount);
+        let adjustments = pc
+            .get_earnings()
+            .iter()
+            .filter(|e| matches!(e, Earning::Adjustment { .. }))
+            .cloned();
+        self.adjustments.extend(adjustments);
+        self.reimbursements
+            .extend(pc.get_reimbursements().iter().cloned());
+        if let Some(pto) = &mut self.pto {
+            let hours = pto.accrued(pc);
+            pto.accrue(Hours::ZERO - hours);
+        }
+    }
+    fn pay_down_balances<F>(&mut self, pc: &Paycheck, f: F)
+    where
+        F: Fn(Money) -> Money,
+    {
+        for d in pc.get_deductions() {
+            match d {
+                Deduction::LoanRepayment { loan, amount } => {
+                    if let Some(l) = self.loans.iter_mut().find(|l| l.get_name() == loan) {
+                        l.pay_down(f(*amount));
+                    }
+                }
+                Deduction::Garnishment { case, amount } => {
+                    if let Some(g) = self.garnishments.iter_mut().find(|g| g.get_case() == case) {
+                        g.pay_down(f(*amount));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_employee {
+    use super::*;
+    use chrono::Datelike;
+    use std::any::Any;
+
+    use crate::bo::{GarnishmentAmount, LoanRepayment, Reimbursement};
+    use crate::test_util::ymd;
+    use crate::types::Rate;
+
+    #[derive(Debug, Clone)]
+    struct Salaried(Money);
+    impl PaymentClassification for Salaried {
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+        fn calculate_pay(&self, _pc: &Paycheck) -> Vec<Earning> {
+            vec![Earning::Salary { amount: self.0 }]
+        }
+        fn paid_entries(&self, _pc: &Paycheck) -> Vec<PaidEntry> {
+            vec![]
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Monthly;
+    impl PaymentSchedule for Monthly {
+        fn is_nominal_pay_date(&self, date: NaiveDate) -> bool {
+            date.succ_opt().is_some_and(|d| d.day() == 1)
+        }
+        fn calculate_period(&self, payday: NaiveDate) -> RangeInclusive<NaiveDate> {
+            payday.with_day(1).unwrap()..=payday
+        }
+        fn pay_periods_per_year(&self) -> u32 {
+            12
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Hold;
+    impl PaymentMethod for Hold {
+        fn pay(&self, _pc: &Paycheck) {}
+    }
+
+    #[derive(Debug, Clone)]
+    struct NoWithholding;
+    impl Withholding for NoWithholding {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn calculate_withholding(
+            &self,
+            _pc: &Paycheck,
+            _schedule: &dyn PaymentSchedule,
+        ) -> Vec<Deduction> {
+            vec![]
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Dues(Money);
+    impl Affiliation for Dues {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+        fn calculate_deductions(&self, pc: &Paycheck) -> Vec<Deduction> {
+            vec![Deduction::UnionDues {
+                date: *pc.get_period().end(),
+                amount: self.0,
+            }]
+        }
+    }
+
+    fn employee(salary: Money) -> Employee {
+        Employee::new(
+            1,
+            "Bob",
+            "Home",
+            None,
+            Rc::new(RefCell::new(Salaried(salary))),
+            Rc::new(RefCell::new(Monthly)),
+            Rc::new(RefCell::new(Hold)),
+            Rc::new(RefCell::new(NoWithholding)),
+        )
+    }
+    // calculate, issue and settle the paycheck for `period`
+    fn issue(emp: &mut Employee, period: RangeInclusive<NaiveDate>, off_cycle: bool) -> Paycheck {
+        let mut pc = Paycheck::new(period.clone());
+        pc.set_pay_date(*period.end());
+        if off_cycle {
+            pc.set_off_cycle();
+        }
+        emp.payday(&mut pc);
+        emp.settle(&pc);
+        pc
+    }
+
+    #[test]
+    fn test_reimbursement_filed_late() {
+        let mut emp = employee(Money::from_cents(100000));
+        issue(&mut emp, ymd(2024, 7, 1)..=ymd(2024, 7, 31), false);
+        // filed for a day the July paycheck already covered
+        emp.add_reimbursement(Reimbursement::new(
+            ymd(2024, 7, 15),
+            "Taxi",
+            Money::from_cents(3000),
+        ));
+        let pc = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 31), false);
+        assert_eq!(pc.get_total_reimbursements(), Money::from_cents(3000));
+        assert!(emp.get_reimbursements().is_empty());
+    }
+
+    #[test]
+    fn test_reimbursement_paid_once() {
+        let mut emp = employee(Money::from_cents(100000));
+        emp.add_reimbursement(Reimbursement::new(
+            ymd(2024, 8, 5),
+            "Hotel",
+            Money::from_cents(12000),
+        ));
+        let off_cycle = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 10), true);
+        assert_eq!(
+            off_cycle.get_total_reimbursements(),
+            Money::from_cents(12000)
+        );
+        let regular = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 31), false);
+        assert_eq!(regular.get_total_reimbursements(), Money::ZERO);
+
+        // voiding the off-cycle paycheck makes it outstanding again
+        emp.unsettle(&off_cycle);
+        assert_eq!(emp.get_reimbursements().len(), 1);
+    }
+
+    #[test]
+    fn test_reimbursement_not_yet_filed() {
+        let mut emp = employee(Money::from_cents(100000));
+        emp.add_reimbursement(Reimbursement::new(
+            ymd(2024, 9, 2),
+            "Taxi",
+            Money::from_cents(3000),
+        ));
+        let pc = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 31), false);
+        assert_eq!(pc.get_total_reimbursements(), Money::ZERO);
+        assert_eq!(emp.get_reimbursements().len(), 1);
+    }
+
+    fn garnished(pc: &Paycheck) -> Vec<(&str, Money)> {
+        pc.get_deductions()
+            .iter()
+            .filter_map(|d| match d {
+                Deduction::Garnishment { case, amount } => Some((case.as_str(), *amount)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_garnishment_ahead_of_union_dues() {
+        let mut emp = employee(Money::from_cents(100000));
+        emp.add_affiliation(Rc::new(RefCell::new(Dues(Money::from_cents(20000)))));
+        let rate = Rate::from_basis_points(1000);
+        emp.add_garnishment(Garnishment::new(
+            "DR-77",
+            GarnishmentAmount::Percent { rate },
+            None,
+            Money::from_cents(100000),
+            false,
+        ));
+        emp.add_garnishment(Garnishment::new(
+            "CS-1234",
+            GarnishmentAmount::Percent { rate },
+            None,
+            Money::from_cents(100000),
+            true,
+        ));
+        let pc = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 31), false);
+        // 10% of 1000.00, then 10% of 1000.00 - 100.00 - 200.00
+        assert_eq!(
+            garnished(&pc),
+            vec![
+                ("CS-1234", Money::from_cents(10000)),
+                ("DR-77", Money::from_cents(7000))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_garnishment_ahead_of_loans() {
+        let mut emp = employee(Money::from_cents(100000));
+        emp.add_loan(Loan::new(
+            "Advance",
+            Money::from_cents(70000),
+            LoanRepayment::Installment {
+                amount: Money::from_cents(70000),
+            },
+        ));
+        emp.add_garnishment(Garnishment::new(
+            "CS-1234",
+            GarnishmentAmount::Fixed {
+                amount: Money::from_cents(50000),
+            },
+            None,
+            Money::from_cents(100000),
+            false,
+        ));
+        let pc = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 31), false);
+        assert_eq!(garnished(&pc), vec![("CS-1234", Money::from_cents(50000))]);
+        // the loan gets what the garnishment left
+        assert_eq!(emp.get_loans()[0].get_balance(), Money::from_cents(20000));
+        assert_eq!(pc.get_net_pay(), Money::ZERO);
+    }
+
+    #[test]
+    fn test_no_installment_off_cycle() {
+        let mut emp = employee(Money::from_cents(100000));
+        emp.add_loan(Loan::new(
+            "Advance",
+            Money::from_cents(30000),
+            LoanRepayment::Count { count: 3 },
+        ));
+        let pc = issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 10), true);
+        assert_eq!(pc.get_total_deductions(), Money::ZERO);
+        assert_eq!(emp.get_loans()[0].get_balance(), Money::from_cents(30000));
+
+        issue(&mut emp, ymd(2024, 8, 1)..=ymd(2024, 8, 31), false);
+        assert_eq!(emp.get_loans()[0].get_balance(), Money::from_cents(20000));
+    }
 }
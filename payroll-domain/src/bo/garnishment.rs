@@ -0,0 +1,123 @@
+use crate::bo::Paycheck;
+use crate::types::{Money, Rate};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GarnishmentAmount {
+    Fixed { amount: Money },
+    // a share of the disposable pay
+    Percent { rate: Rate },
+}
+
+// a court order to withhold pay until the ordered total is paid
+#[derive(Debug, Clone, PartialEq)]
+pub struct Garnishment {
+    case: String,
+    amount: GarnishmentAmount,
+    max_per_period: Option<Money>,
+    balance: Money,
+    // withheld before the union dues rather than after them
+    ahead_of_union_dues: bool,
+}
+impl Garnishment {
+    pub fn new(
+        case: &str,
+        amount: GarnishmentAmount,
+        max_per_period: Option<Money>,
+        balance: Money,
+        ahead_of_union_dues: bool,
+    ) -> Self {
+        Self {
+            case: case.to_string(),
+            amount,
+            max_per_period,
+            balance,
+            ahead_of_union_dues,
+        }
+    }
+    pub fn get_case(&self) -> &str {
+        &self.case
+    }
+    pub fn get_balance(&self) -> Money {
+        self.balance
+    }
+    pub fn is_ahead_of_union_dues(&self) -> bool {
+        self.ahead_of_union_dues
+    }
+    // what to withhold from `pc`, given the deductions already on it
+    pub fn calculate_deduction(&self, pc: &Paycheck) -> Money {
+        let remaining = pc.get_gross_pay() - pc.get_total_deductions();
+        let amount = match &self.amount {
+            GarnishmentAmount::Fixed { amount } => *amount,
+            GarnishmentAmount::Percent { rate } => remaining.max(Money::ZERO) * *rate,
+        };
+        let cap = self.max_per_period.unwrap_or(amount);
+        // never more than is left on the paycheck
+        amount
+            .min(cap)
+            .min(self.balance)
+            .min(remaining.max(Money::ZERO))
+    }
+    pub fn pay_down(&mut self, amount: Money) {
+        self.balance -= amount;
+    }
+}
+
+#[cfg(test)]
+mod test_garnishment {
+    use super::*;
+    use crate::bo::{Deduction, Earning};
+    use chrono::NaiveDate;
+
+    fn paycheck() -> Paycheck {
+        let mut pc = Paycheck::new(
+            NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                ..=NaiveDate::from_ymd_opt(2024, 8, 31).unwrap(),
+        );
+        pc.add_earnings(vec![Earning::Salary {
+            amount: Money::from_cents(100000),
+        }]);
+        pc.add_deductions(vec![
+            Deduction::Withholding {
+                amount: Money::from_cents(10000),
+            },
+            Deduction::UnionDues {
+                date: NaiveDate::from_ymd_opt(2024, 8, 2).unwrap(),
+                amount: Money::from_cents(20000),
+            },
+        ]);
+        pc
+    }
+
+    #[test]
+    fn test_percent_of_disposable_pay() {
+        let g = Garnishment::new(
+            "DR-77",
+            GarnishmentAmount::Percent {
+                rate: Rate::from_basis_points(1000),
+            },
+            None,
+            Money::from_cents(100000),
+            false,
+        );
+        // 10% of 1000.00 - 100.00 - 200.00
+        assert_eq!(g.calculate_deduction(&paycheck()), Money::from_cents(7000));
+    }
+
+    #[test]
+    fn test_capped() {
+        let mut g = Garnishment::new(
+            "CS-1234",
+            GarnishmentAmount::Fixed {
+                amount: Money::from_cents(80000),
+            },
+            Some(Money::from_cents(50000)),
+            Money::from_cents(60000),
+            false,
+        );
+        // limited by what is left on the paycheck
+        assert_eq!(g.calculate_deduction(&paycheck()), Money::from_cents(50000));
+        g.pay_down(Money::from_cents(50000));
+        // limited by the balance
+        assert_eq!(g.calculate_deduction(&paycheck()), Money::from_cents(10000));
+    }
+}
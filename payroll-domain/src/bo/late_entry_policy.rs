@@ -0,0 +1,8 @@
+// what to do with an entry dated in a period that is already paid
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LateEntryPolicy {
+    #[default]
+    Reject,
+    // pay the difference on the next paycheck
+    CarryForward,
+}
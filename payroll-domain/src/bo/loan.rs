@@ -0,0 +1,75 @@
+use crate::bo::Paycheck;
+use crate::types::Money;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoanRepayment {
+    Installment { amount: Money },
+    // the installment that pays the principal back within `count` paydays
+    Count { count: u32 },
+}
+
+// money lent to the employee, recovered one installment each payday
+#[derive(Debug, Clone, PartialEq)]
+pub struct Loan {
+    name: String,
+    principal: Money,
+    installment: Money,
+    balance: Money,
+}
+impl Loan {
+    // `count` must be positive, AddLoanTx refuses a loan that is never repaid
+    pub fn new(name: &str, principal: Money, repayment: LoanRepayment) -> Self {
+        let installment = match repayment {
+            LoanRepayment::Installment { amount } => amount,
+            LoanRepayment::Count { count } => {
+                let count = count as i64;
+                // round up so that the last installment is the smaller one
+                Money::from_cents((principal.as_cents() + count - 1) / count)
+            }
+        };
+        Self {
+            name: name.to_string(),
+            principal,
+            installment,
+            balance: principal,
+        }
+    }
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn get_principal(&self) -> Money {
+        self.principal
+    }
+    pub fn get_installment(&self) -> Money {
+        self.installment
+    }
+    pub fn get_balance(&self) -> Money {
+        self.balance
+    }
+    // the installment due on `pc`, given the deductions already on it
+    pub fn calculate_deduction(&self, pc: &Paycheck) -> Money {
+        let remaining = pc.get_gross_pay() - pc.get_total_deductions();
+        self.installment
+            .min(self.balance)
+            .min(remaining.max(Money::ZERO))
+    }
+    pub fn pay_down(&mut self, amount: Money) {
+        self.balance -= amount;
+    }
+}
+
+#[cfg(test)]
+mod test_loan {
+    use super::*;
+
+    #[test]
+    fn test_installment_count() {
+        let loan = Loan::new(
+            "Advance",
+            Money::from_cents(10000),
+            LoanRepayment::Count { count: 3 },
+        );
+        // 33.34, 33.34, 33.32
+        assert_eq!(loan.get_installment(), Money::from_cents(3334));
+    }
+}
@@ -0,0 +1,274 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+use crate::types::{Hours, Rate};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OvertimeRule {
+    // hours beyond threshold on a single day
+    Daily { threshold: Hours, multiplier: Rate },
+    // straight hours beyond threshold within a 7-day workweek
+    Weekly { threshold: Hours, multiplier: Rate },
+    // every hour worked on Saturday or Sunday
+    Weekend { multiplier: Rate },
+    // every hour worked on the given date
+    Holiday { date: NaiveDate, multiplier: Rate },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OvertimePolicy {
+    rules: Vec<OvertimeRule>,
+}
+impl Default for OvertimePolicy {
+    fn default() -> Self {
+        Self::new(vec![OvertimeRule::Daily {
+            threshold: Hours::from_hours(8),
+            multiplier: Rate::from_basis_points(15000),
+        }])
+    }
+}
+impl OvertimePolicy {
+    pub fn new(rules: Vec<OvertimeRule>) -> Self {
+        Self { rules }
+    }
+    pub fn get_rules(&self) -> &[OvertimeRule] {
+        &self.rules
+    }
+
+    // Split the hours worked on each day into (hours, multiplier) parts.
+    // Workweeks are the consecutive 7-day blocks starting at `period_start`.
+    // The hours another paycheck paid count towards the weekly threshold first.
+    pub fn split_hours(
+        &self,
+        period_start: NaiveDate,
+        paid: &[(NaiveDate, Hours)],
+        days: &[(NaiveDate, Hours)],
+    ) -> Vec<(NaiveDate, Hours, Rate)> {
+        let mut days = days.to_vec();
+        days.sort_by_key(|(date, _)| *date);
+
+        let mut straight_in_week: BTreeMap<i64, Hours> = BTreeMap::new();
+        for (date, hours) in paid {
+            if self.premium_day_multiplier(*date).is_none() {
+                let week = (*date - period_start).num_days().div_euclid(7);
+                let straight = self.split_daily(*hours)[0].0;
+                *straight_in_week.entry(week).or_insert(Hours::ZERO) += straight;
+            }
+        }
+        let mut result = vec![];
+        for (date, hours) in days {
+            if let Some(multiplier) = self.premium_day_multiplier(date) {
+                result.push((date, hours, multiplier));
+                continue;
+            }
+            let mut parts = self.split_daily(hours);
+
+            let week = (date - period_start).num_days().div_euclid(7);
+            let worked = straight_in_week.entry(week).or_insert(Hours::ZERO);
+            let straight = parts[0].0;
+            if let Some((threshold, multiplier)) = self.weekly() {
+                let allowed = (threshold - *worked).max(Hours::ZERO).min(straight);
+                if allowed < straight {
+                    parts[0].0 = allowed;
+                    parts.push((straight - allowed, multiplier));
+                }
+            }
+            *worked += straight;
+
+            for (hours, multiplier) in parts {
+                if hours > Hours::ZERO {
+                    result.push((date, hours, multiplier));
+                }
+            }
+        }
+        result
+    }
+
+    fn premium_day_multiplier(&self, date: NaiveDate) -> Option<Rate> {
+        let is_weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        self.rules
+            .iter()
+            .filter_map(|r| match r {
+                OvertimeRule::Weekend { multiplier } if is_weekend => Some(*multiplier),
+                OvertimeRule::Holiday {
+                    date: holiday,
+                    multiplier,
+                } if *holiday == date => Some(*multiplier),
+                _ => None,
+            })
+            .max()
+    }
+
+    // the first part is always the straight time at 1.0
+    fn split_daily(&self, hours: Hours) -> Vec<(Hours, Rate)> {
+        let mut tiers: Vec<(Hours, Rate)> = self
+            .rules
+            .iter()
+            .filter_map(|r| match r {
+                OvertimeRule::Daily {
+                    threshold,
+                    multiplier,
+                } => Some((*threshold, *multiplier)),
+                _ => None,
+            })
+            .collect();
+        tiers.sort();
+
+        let mut parts = vec![];
+        let mut from = Hours::ZERO;
+        let mut multiplier = Rate::ONE;
+        for (threshold, tier_multiplier) in tiers {
+            let upto = threshold.min(hours).max(from);
+            parts.push((upto - from, multiplier));
+            from = upto;
+            multiplier = multiplier.max(tier_multiplier);
+        }
+        parts.push((hours - from, multiplier));
+
+        // fold the parts below the first threshold back into straight time
+        let mut straight = Hours::ZERO;
+        let mut rest = vec![];
+        for (h, m) in parts {
+            if m == Rate::ONE {
+                straight += h;
+            } else {
+                rest.push((h, m));
+            }
+        }
+        let mut result = vec![(straight, Rate::ONE)];
+        result.extend(rest);
+        result
+    }
+
+    fn weekly(&self) -> Option<(Hours, Rate)> {
+        self.rules.iter().find_map(|r| match r {
+            OvertimeRule::Weekly {
+                threshold,
+                multiplier,
+            } => Some((*threshold, *multiplier)),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_overtime_policy {
+    use super::*;
+    use crate::test_util::ymd;
+
+    fn bp(bp: i64) -> Rate {
+        Rate::from_basis_points(bp)
+    }
+
+    #[test]
+    fn test_default() {
+        let policy = OvertimePolicy::default();
+        let result = policy.split_hours(
+            ymd(2024, 8, 3),
+            &[],
+            &[(ymd(2024, 8, 5), Hours::from_hours(10))],
+        );
+        assert_eq!(
+            result,
+            vec![
+                (ymd(2024, 8, 5), Hours::from_hours(8), Rate::ONE),
+                (ymd(2024, 8, 5), Hours::from_hours(2), bp(15000)),
+            ]
+        );
+    }
+    #[test]
+    fn test_double_time() {
+        let policy = OvertimePolicy::new(vec![
+            OvertimeRule::Daily {
+                threshold: Hours::from_hours(8),
+                multiplier: bp(15000),
+            },
+            OvertimeRule::Daily {
+                threshold: Hours::from_hours(12),
+                multiplier: bp(20000),
+            },
+        ]);
+        let result = policy.split_hours(
+            ymd(2024, 8, 3),
+            &[],
+            &[(ymd(2024, 8, 5), Hours::from_hours(13))],
+        );
+        assert_eq!(
+            result,
+            vec![
+                (ymd(2024, 8, 5), Hours::from_hours(8), Rate::ONE),
+                (ymd(2024, 8, 5), Hours::from_hours(4), bp(15000)),
+                (ymd(2024, 8, 5), Hours::from_hours(1), bp(20000)),
+            ]
+        );
+    }
+    #[test]
+    fn test_weekly() {
+        let policy = OvertimePolicy::new(vec![OvertimeRule::Weekly {
+            threshold: Hours::from_hours(40),
+            multiplier: bp(15000),
+        }]);
+        // Sat 2024-08-03 starts the workweek; 5 days x 9h = 45h
+        let days: Vec<_> = (5..=9)
+            .map(|d| (ymd(2024, 8, d), Hours::from_hours(9)))
+            .collect();
+        let result = policy.split_hours(ymd(2024, 8, 3), &[], &days);
+        assert_eq!(
+            result[4..],
+            [
+                (ymd(2024, 8, 9), Hours::from_hours(4), Rate::ONE),
+                (ymd(2024, 8, 9), Hours::from_hours(5), bp(15000)),
+            ]
+        );
+    }
+    #[test]
+    fn test_weekly_after_paid_hours() {
+        let policy = OvertimePolicy::new(vec![OvertimeRule::Weekly {
+            threshold: Hours::from_hours(40),
+            multiplier: bp(15000),
+        }]);
+        // 27h of the week went out on an off-cycle paycheck
+        let paid: Vec<_> = (7..=9)
+            .map(|d| (ymd(2024, 8, d), Hours::from_hours(9)))
+            .collect();
+        let days: Vec<_> = (5..=6)
+            .map(|d| (ymd(2024, 8, d), Hours::from_hours(9)))
+            .collect();
+        let result = policy.split_hours(ymd(2024, 8, 3), &paid, &days);
+        assert_eq!(
+            result,
+            [
+                (ymd(2024, 8, 5), Hours::from_hours(9), Rate::ONE),
+                (ymd(2024, 8, 6), Hours::from_hours(4), Rate::ONE),
+                (ymd(2024, 8, 6), Hours::from_hours(5), bp(15000)),
+            ]
+        );
+    }
+    #[test]
+    fn test_weekend_and_holiday() {
+        let policy = OvertimePolicy::new(vec![
+            OvertimeRule::Weekend {
+                multiplier: bp(15000),
+            },
+            OvertimeRule::Holiday {
+                date: ymd(2024, 8, 12),
+                multiplier: bp(20000),
+            },
+        ]);
+        let result = policy.split_hours(
+            ymd(2024, 8, 10),
+            &[],
+            &[
+                (ymd(2024, 8, 10), Hours::from_hours(4)),
+                (ymd(2024, 8, 12), Hours::from_hours(6)),
+            ],
+        );
+        assert_eq!(
+            result,
+            vec![
+                (ymd(2024, 8, 10), Hours::from_hours(4), bp(15000)),
+                (ymd(2024, 8, 12), Hours::from_hours(6), bp(20000)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+
+use crate::bo::Earning;
+use crate::types::EntryId;
+
+// what a paycheck paid for, so that no other paycheck pays for it again
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaidEntry {
+    TimeCard { date: NaiveDate, id: EntryId },
+    SalesReceipt { date: NaiveDate, id: EntryId },
+    // the base pay for these days
+    BasePay { days: RangeInclusive<NaiveDate> },
+    // a bonus or an allowance
+    Additional { earning: Earning },
+}
@@ -0,0 +1,56 @@
+use crate::bo::{Earning, Paycheck};
+use crate::types::{Hours, Rate};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PtoAccrual {
+    PerPeriod { hours: Hours },
+    // hours of leave earned for each hour worked
+    PerHourWorked { rate: Rate },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaidTimeOff {
+    accrual: PtoAccrual,
+    balance: Hours,
+}
+impl PaidTimeOff {
+    pub fn new(accrual: PtoAccrual, balance: Hours) -> Self {
+        Self { accrual, balance }
+    }
+    pub fn get_accrual(&self) -> &PtoAccrual {
+        &self.accrual
+    }
+    pub fn get_balance(&self) -> Hours {
+        self.balance
+    }
+    // the leave earned by an issued paycheck; off-cycle paychecks don't count as a period
+    pub fn accrued(&self, pc: &Paycheck) -> Hours {
+        match &self.accrual {
+            PtoAccrual::PerPeriod { .. } if pc.is_off_cycle() => Hours::ZERO,
+            PtoAccrual::PerPeriod { hours } => *hours,
+            PtoAccrual::PerHourWorked { rate } => {
+                let worked: Hours = pc
+                    .get_earnings()
+                    .iter()
+                    .map(|e| match e {
+                        Earning::RegularHours { hours, .. }
+                        | Earning::OvertimeHours { hours, .. } => *hours,
+                        _ => Hours::ZERO,
+                    })
+                    .sum();
+                worked * *rate
+            }
+        }
+    }
+    pub fn accrue(&mut self, hours: Hours) {
+        self.balance += hours;
+    }
+    // use up `hours` of the balance, if there is enough
+    pub fn take(&mut self, hours: Hours) -> bool {
+        if hours > self.balance {
+            return false;
+        }
+        self.balance -= hours;
+        true
+    }
+}
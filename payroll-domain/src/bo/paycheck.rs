@@ -1,35 +1,204 @@
 use chrono::NaiveDate;
-use std::{fmt::Debug, ops::RangeInclusive};
+use std::{fmt, ops::RangeInclusive};
 
-#[derive(Debug, Clone, PartialEq)]
+use crate::bo::{Deduction, Earning, PaidEntry, Reimbursement};
+use crate::types::Money;
+
+#[derive(Clone, PartialEq)]
 pub struct Paycheck {
+    // set when the paycheck is issued
+    pay_date: Option<NaiveDate>,
     period: RangeInclusive<NaiveDate>,
-    gross_pay: f32,
-    deductions: f32,
-    net_pay: f32,
+    // the days within the period the employee was on the payroll
+    employed_period: RangeInclusive<NaiveDate>,
+    earnings: Vec<Earning>,
+    deductions: Vec<Deduction>,
+    // added to the net pay only
+    reimbursements: Vec<Reimbursement>,
+    voided: bool,
+    // paid outside the employee's payment schedule
+    off_cycle: bool,
+    // what this paycheck paid for
+    paid_entries: Vec<PaidEntry>,
+    // what other paychecks paid for, left out of this one
+    paid_elsewhere: Vec<PaidEntry>,
 }
 impl Paycheck {
     pub fn new(period: RangeInclusive<NaiveDate>) -> Self {
         Self {
+            pay_date: None,
+            employed_period: period.clone(),
             period,
-            gross_pay: 0.0,
-            deductions: 0.0,
-            net_pay: 0.0,
+            earnings: vec![],
+            deductions: vec![],
+            reimbursements: vec![],
+            voided: false,
+            off_cycle: false,
+            paid_entries: vec![],
+            paid_elsewhere: vec![],
         }
     }
+    // a paycheck for the `employed` days of another period, leaving out the same entries
+    pub fn part(
+        &self,
+        period: RangeInclusive<NaiveDate>,
+        employed_period: RangeInclusive<NaiveDate>,
+    ) -> Self {
+        let mut part = Paycheck::new(period);
+        part.set_employed_period(employed_period);
+        part.paid_elsewhere = self.paid_elsewhere.clone();
+        part
+    }
+    pub fn get_pay_date(&self) -> Option<NaiveDate> {
+        self.pay_date
+    }
+    pub fn set_pay_date(&mut self, pay_date: NaiveDate) {
+        self.pay_date = Some(pay_date);
+    }
+    pub fn is_voided(&self) -> bool {
+        self.voided
+    }
+    pub fn void(&mut self) {
+        self.voided = true;
+    }
+    pub fn is_off_cycle(&self) -> bool {
+        self.off_cycle
+    }
+    pub fn set_off_cycle(&mut self) {
+        self.off_cycle = true;
+    }
+    pub fn get_paid_entries(&self) -> &[PaidEntry] {
+        &self.paid_entries
+    }
+    pub fn add_paid_entries(&mut self, entries: Vec<PaidEntry>) {
+        self.paid_entries.extend(entries);
+    }
+    pub fn leave_out(&mut self, paid: &Paycheck) {
+        self.paid_elsewhere
+            .extend(paid.paid_entries.iter().cloned());
+    }
+    pub fn is_paid_elsewhere(&self, entry: &PaidEntry) -> bool {
+        self.paid_elsewhere.contains(entry)
+    }
+    // whether another paycheck paid the base pay for `date`
+    pub fn is_base_pay_paid_elsewhere(&self, date: NaiveDate) -> bool {
+        self.paid_elsewhere
+            .iter()
+            .any(|e| matches!(e, PaidEntry::BasePay { days } if days.contains(&date)))
+    }
     pub fn get_period(&self) -> RangeInclusive<NaiveDate> {
         self.period.clone()
     }
-    pub fn set_gross_pay(&mut self, gross_pay: f32) {
-        self.gross_pay = gross_pay;
+    pub fn get_employed_period(&self) -> RangeInclusive<NaiveDate> {
+        self.employed_period.clone()
+    }
+    pub fn set_employed_period(&mut self, employed_period: RangeInclusive<NaiveDate>) {
+        self.employed_period = employed_period;
+    }
+    // whether the employee was on the payroll for only part of the period
+    pub fn is_partial_period(&self) -> bool {
+        self.employed_period != self.period
+    }
+    pub fn get_earnings(&self) -> &[Earning] {
+        &self.earnings
+    }
+    pub fn add_earnings(&mut self, earnings: Vec<Earning>) {
+        self.earnings.extend(earnings);
     }
-    pub fn set_deductions(&mut self, deductions: f32) {
-        self.deductions = deductions;
+    pub fn get_deductions(&self) -> &[Deduction] {
+        &self.deductions
     }
-    pub fn get_net_pay(&self) -> f32 {
-        self.net_pay
+    pub fn add_deductions(&mut self, deductions: Vec<Deduction>) {
+        self.deductions.extend(deductions);
     }
-    pub fn set_net_pay(&mut self, net_pay: f32) {
-        self.net_pay = net_pay;
+    pub fn get_reimbursements(&self) -> &[Reimbursement] {
+        &self.reimbursements
+    }
+    pub fn add_reimbursements(&mut self, reimbursements: Vec<Reimbursement>) {
+        self.reimbursements.extend(reimbursements);
+    }
+    pub fn get_gross_pay(&self) -> Money {
+        self.earnings.iter().map(Earning::get_amount).sum()
+    }
+    pub fn get_total_deductions(&self) -> Money {
+        self.deductions.iter().map(Deduction::get_amount).sum()
+    }
+    pub fn get_total_reimbursements(&self) -> Money {
+        self.reimbursements
+            .iter()
+            .map(Reimbursement::get_amount)
+            .sum()
+    }
+    pub fn get_net_pay(&self) -> Money {
+        self.get_gross_pay() - self.get_total_deductions() + self.get_total_reimbursements()
+    }
+}
+impl fmt::Debug for Paycheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("Paycheck");
+        if let Some(pay_date) = &self.pay_date {
+            d.field("pay_date", pay_date);
+        }
+        if self.off_cycle {
+            d.field("off_cycle", &self.off_cycle);
+        }
+        d.field("period", &self.period);
+        if self.is_partial_period() {
+            d.field("employed_period", &self.employed_period);
+        }
+        d.field("earnings", &self.earnings)
+            .field("gross_pay", &self.get_gross_pay())
+            .field("deductions", &self.deductions)
+            .field("total_deductions", &self.get_total_deductions());
+        if !self.reimbursements.is_empty() {
+            d.field("reimbursements", &self.reimbursements)
+                .field("total_reimbursements", &self.get_total_reimbursements());
+        }
+        d.field("net_pay", &self.get_net_pay());
+        if self.voided {
+            d.field("voided", &self.voided);
+        }
+        d.finish()
+    }
+}
+
+#[cfg(test)]
+mod test_paycheck {
+    use super::*;
+    use crate::test_util::ymd;
+
+    #[test]
+    fn test_totals_from_lines() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        pc.add_earnings(vec![
+            Earning::Salary {
+                amount: Money::from_cents(100000),
+            },
+            Earning::Bonus {
+                date: ymd(2024, 8, 5),
+                description: "Q2".to_string(),
+                amount: Money::from_cents(25000),
+            },
+        ]);
+        pc.add_deductions(vec![
+            Deduction::UnionDues {
+                date: ymd(2024, 8, 9),
+                amount: Money::from_cents(945),
+            },
+            Deduction::ServiceCharge {
+                date: ymd(2024, 8, 8),
+                amount: Money::from_cents(350),
+            },
+        ]);
+        pc.add_reimbursements(vec![Reimbursement::new(
+            ymd(2024, 8, 6),
+            "Taxi",
+            Money::from_cents(2000),
+        )]);
+
+        assert_eq!(pc.get_gross_pay(), Money::from_cents(125000));
+        assert_eq!(pc.get_total_deductions(), Money::from_cents(1295));
+        assert_eq!(pc.get_total_reimbursements(), Money::from_cents(2000));
+        assert_eq!(pc.get_net_pay(), Money::from_cents(125705));
     }
 }
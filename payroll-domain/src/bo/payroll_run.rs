@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+
+use crate::bo::Paycheck;
+use crate::types::{EmployeeId, Money};
+
+// the paychecks calculated for a pay date, waiting to be approved
+#[derive(Debug, Clone)]
+pub struct PayrollRun {
+    pay_date: NaiveDate,
+    // pays the periods that were already paid once more
+    rerun: bool,
+    paychecks: Vec<(EmployeeId, Paycheck)>,
+}
+impl PayrollRun {
+    pub fn new(pay_date: NaiveDate, rerun: bool) -> Self {
+        Self {
+            pay_date,
+            rerun,
+            paychecks: vec![],
+        }
+    }
+    pub fn get_pay_date(&self) -> NaiveDate {
+        self.pay_date
+    }
+    pub fn is_rerun(&self) -> bool {
+        self.rerun
+    }
+    pub fn get_paychecks(&self) -> &[(EmployeeId, Paycheck)] {
+        &self.paychecks
+    }
+    pub fn add_paycheck(&mut self, emp_id: EmployeeId, pc: Paycheck) {
+        self.paychecks.push((emp_id, pc));
+    }
+    pub fn into_paychecks(self) -> Vec<(EmployeeId, Paycheck)> {
+        self.paychecks
+    }
+    pub fn get_gross_pay(&self) -> Money {
+        self.paychecks
+            .iter()
+            .map(|(_, pc)| pc.get_gross_pay())
+            .sum()
+    }
+    pub fn get_total_deductions(&self) -> Money {
+        self.paychecks
+            .iter()
+            .map(|(_, pc)| pc.get_total_deductions())
+            .sum()
+    }
+    pub fn get_net_pay(&self) -> Money {
+        self.paychecks.iter().map(|(_, pc)| pc.get_net_pay()).sum()
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+
+use crate::types::Money;
+
+// an expense repaid with the paycheck, which is not a wage
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reimbursement {
+    date: NaiveDate,
+    description: String,
+    amount: Money,
+}
+impl Reimbursement {
+    pub fn new(date: NaiveDate, description: &str, amount: Money) -> Self {
+        Self {
+            date,
+            description: description.to_string(),
+            amount,
+        }
+    }
+    pub fn get_date(&self) -> NaiveDate {
+        self.date
+    }
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+    pub fn get_amount(&self) -> Money {
+        self.amount
+    }
+}
@@ -0,0 +1,40 @@
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+
+use crate::bo::Paycheck;
+use crate::types::{EmployeeId, Money};
+
+// the record of a voided paycheck, with its amounts negated
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reversal {
+    emp_id: EmployeeId,
+    pay_date: NaiveDate,
+    period: RangeInclusive<NaiveDate>,
+    gross_pay: Money,
+    total_deductions: Money,
+    net_pay: Money,
+}
+impl Reversal {
+    pub fn new(emp_id: EmployeeId, pay_date: NaiveDate, pc: &Paycheck) -> Self {
+        Self {
+            emp_id,
+            pay_date,
+            period: pc.get_period(),
+            gross_pay: -pc.get_gross_pay(),
+            total_deductions: -pc.get_total_deductions(),
+            net_pay: -pc.get_net_pay(),
+        }
+    }
+    pub fn get_emp_id(&self) -> EmployeeId {
+        self.emp_id
+    }
+    pub fn get_pay_date(&self) -> NaiveDate {
+        self.pay_date
+    }
+    pub fn get_period(&self) -> RangeInclusive<NaiveDate> {
+        self.period.clone()
+    }
+    pub fn get_net_pay(&self) -> Money {
+        self.net_pay
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+
+use crate::bo::Employee;
+use crate::interface::{Affiliation, PaymentMethod};
+use crate::types::{EmployeeId, MemberId};
+
+#[derive(Debug, Clone)]
+pub enum EmployeeChange {
+    Name(String),
+    Address(String),
+    Method(Rc<RefCell<dyn PaymentMethod>>),
+    // member_id is recorded as a union membership when the change is applied
+    Affiliation {
+        member_id: Option<MemberId>,
+        affiliation: Rc<RefCell<dyn Affiliation>>,
+    },
+}
+impl EmployeeChange {
+    pub fn apply(self, emp: &mut Employee) {
+        match self {
+            EmployeeChange::Name(name) => emp.set_name(&name),
+            EmployeeChange::Address(address) => emp.set_address(&address),
+            EmployeeChange::Method(method) => emp.set_method(method),
+            EmployeeChange::Affiliation { affiliation, .. } => emp.add_affiliation(affiliation),
+        }
+    }
+}
+
+// a change to an employee that takes effect on a future date
+#[derive(Debug, Clone)]
+pub struct ScheduledChange {
+    emp_id: EmployeeId,
+    effective: NaiveDate,
+    change: EmployeeChange,
+}
+impl ScheduledChange {
+    pub fn new(emp_id: EmployeeId, effective: NaiveDate, change: EmployeeChange) -> Self {
+        Self {
+            emp_id,
+            effective,
+            change,
+        }
+    }
+    pub fn get_emp_id(&self) -> EmployeeId {
+        self.emp_id
+    }
+    pub fn get_effective(&self) -> NaiveDate {
+        self.effective
+    }
+    pub fn get_change(&self) -> &EmployeeChange {
+        &self.change
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::{Days, NaiveDateTime, NaiveTime};
+
+use crate::types::{Hours, Money};
+
+// a premium for every hour worked between `start` and `end`, wrapping past midnight
+// when `end` isn't after `start`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShiftWindow {
+    start: NaiveTime,
+    end: NaiveTime,
+    premium: Money,
+}
+impl ShiftWindow {
+    pub fn new(start: NaiveTime, end: NaiveTime, premium: Money) -> Self {
+        Self {
+            start,
+            end,
+            premium,
+        }
+    }
+    pub fn get_premium(&self) -> Money {
+        self.premium
+    }
+    // the part of the shift from `clock_in` to `clock_out` inside the window
+    pub fn hours_within(&self, clock_in: NaiveDateTime, clock_out: NaiveDateTime) -> Hours {
+        // a window opened on the day before may still be open at clock-in
+        let first = clock_in.date() - Days::new(1);
+        let minutes = first
+            .iter_days()
+            .take_while(|d| *d <= clock_out.date())
+            .map(|d| {
+                let from = d.and_time(self.start);
+                let to = if self.start < self.end {
+                    d.and_time(self.end)
+                } else {
+                    (d + Days::new(1)).and_time(self.end)
+                };
+                (to.min(clock_out) - from.max(clock_in))
+                    .num_minutes()
+                    .max(0)
+            })
+            .sum();
+        Hours::from_minutes(minutes)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShiftDifferential {
+    windows: Vec<ShiftWindow>,
+}
+impl ShiftDifferential {
+    pub fn new(windows: Vec<ShiftWindow>) -> Self {
+        Self { windows }
+    }
+    pub fn get_windows(&self) -> &[ShiftWindow] {
+        &self.windows
+    }
+    // (hours, premium amount) for each window the shift touches
+    pub fn premiums(
+        &self,
+        clock_in: NaiveDateTime,
+        clock_out: NaiveDateTime,
+    ) -> Vec<(Hours, Money)> {
+        self.windows
+            .iter()
+            .map(|w| {
+                let hours = w.hours_within(clock_in, clock_out);
+                (hours, w.premium * hours)
+            })
+            .filter(|(hours, _)| *hours > Hours::ZERO)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test_shift_differential {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(d: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2024, 8, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+    fn hm(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_overnight_window() {
+        let night = ShiftWindow::new(hm(22, 0), hm(6, 0), Money::from_cents(150));
+        // 20:00 to 04:30 the next day
+        assert_eq!(
+            night.hours_within(at(1, 20, 0), at(2, 4, 30)),
+            Hours::from_minutes(390)
+        );
+        // 05:00 to 13:00, still inside the window opened the night before
+        assert_eq!(
+            night.hours_within(at(2, 5, 0), at(2, 13, 0)),
+            Hours::from_hours(1)
+        );
+        assert_eq!(night.hours_within(at(2, 9, 0), at(2, 17, 0)), Hours::ZERO);
+    }
+
+    #[test]
+    fn test_premiums() {
+        let differential = ShiftDifferential::new(vec![
+            ShiftWindow::new(hm(18, 0), hm(22, 0), Money::from_cents(75)),
+            ShiftWindow::new(hm(22, 0), hm(6, 0), Money::from_cents(150)),
+        ]);
+        assert_eq!(
+            differential.premiums(at(1, 20, 0), at(2, 2, 0)),
+            vec![
+                (Hours::from_hours(2), Money::from_cents(150)),
+                (Hours::from_hours(4), Money::from_cents(600)),
+            ]
+        );
+    }
+}
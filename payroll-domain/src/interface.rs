@@ -1,9 +1,13 @@
 mod affiliation;
+mod holiday_calendar;
 mod payment_classification;
 mod payment_method;
 mod payment_schedule;
+mod withholding;
 
 pub use affiliation::Affiliation;
+pub use holiday_calendar::HolidayCalendar;
 pub use payment_classification::PaymentClassification;
 pub use payment_method::PaymentMethod;
 pub use payment_schedule::PaymentSchedule;
+pub use withholding::Withholding;
@@ -0,0 +1,8 @@
+use chrono::NaiveDate;
+use dyn_clone::DynClone;
+use std::fmt::Debug;
+
+pub trait HolidayCalendar: DynClone + Debug {
+    fn is_business_day(&self, date: NaiveDate) -> bool;
+}
+dyn_clone::clone_trait_object!(HolidayCalendar);
@@ -1,10 +1,13 @@
 use dyn_clone::DynClone;
 use std::{any::Any, fmt::Debug};
 
-use crate::bo::Paycheck;
+use crate::bo::{Earning, PaidEntry, Paycheck};
 
 pub trait PaymentClassification: DynClone + Debug {
     fn as_any_mut(&mut self) -> &mut dyn Any;
-    fn calculate_pay(&self, pc: &Paycheck) -> f32;
+    // leaves out what `pc` says other paychecks paid for
+    fn calculate_pay(&self, pc: &Paycheck) -> Vec<Earning>;
+    // what `calculate_pay` pays for
+    fn paid_entries(&self, pc: &Paycheck) -> Vec<PaidEntry>;
 }
 dyn_clone::clone_trait_object!(PaymentClassification);
@@ -2,8 +2,36 @@ use chrono::NaiveDate;
 use dyn_clone::DynClone;
 use std::{fmt::Debug, ops::RangeInclusive};
 
+use crate::interface::HolidayCalendar;
+
 pub trait PaymentSchedule: DynClone + Debug {
-    fn is_pay_date(&self, date: NaiveDate) -> bool;
+    fn is_nominal_pay_date(&self, date: NaiveDate) -> bool;
     fn calculate_period(&self, payday: NaiveDate) -> RangeInclusive<NaiveDate>;
+    fn pay_periods_per_year(&self) -> u32;
+
+    // A nominal payday that isn't a business day is paid on the previous business day,
+    // so `date` pays the nominal payday on itself or on the non-business days following it.
+    fn nominal_pay_date(
+        &self,
+        date: NaiveDate,
+        calendar: &dyn HolidayCalendar,
+    ) -> Option<NaiveDate> {
+        if !calendar.is_business_day(date) {
+            return None;
+        }
+        let mut d = date;
+        loop {
+            if self.is_nominal_pay_date(d) {
+                return Some(d);
+            }
+            d = d.succ_opt()?;
+            if calendar.is_business_day(d) {
+                return None;
+            }
+        }
+    }
+    fn is_pay_date(&self, date: NaiveDate, calendar: &dyn HolidayCalendar) -> bool {
+        self.nominal_pay_date(date, calendar).is_some()
+    }
 }
 dyn_clone::clone_trait_object!(PaymentSchedule);
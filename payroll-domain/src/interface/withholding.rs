@@ -0,0 +1,15 @@
+use dyn_clone::DynClone;
+use std::{any::Any, fmt::Debug};
+
+use crate::bo::{Deduction, Paycheck};
+use crate::interface::PaymentSchedule;
+
+pub trait Withholding: DynClone + Debug {
+    fn as_any(&self) -> &dyn Any;
+    fn calculate_withholding(
+        &self,
+        pc: &Paycheck,
+        schedule: &dyn PaymentSchedule,
+    ) -> Vec<Deduction>;
+}
+dyn_clone::clone_trait_object!(Withholding);
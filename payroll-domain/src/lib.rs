@@ -2,6 +2,17 @@ mod bo;
 mod interface;
 mod types;
 
-pub use bo::{Employee, Paycheck};
-pub use interface::{Affiliation, PaymentClassification, PaymentMethod, PaymentSchedule};
-pub use types::{EmployeeId, MemberId};
+pub use bo::{
+    CommissionPlan, CommissionTier, Deduction, Earning, Employee, EmployeeChange, Garnishment,
+    GarnishmentAmount, LateEntryPolicy, Loan, LoanRepayment, OvertimePolicy, OvertimeRule,
+    PaidEntry, PaidTimeOff, Paycheck, PayrollRun, PtoAccrual, Reimbursement, Reversal,
+    ScheduledChange, ShiftDifferential, ShiftWindow,
+};
+pub use interface::{
+    Affiliation, HolidayCalendar, PaymentClassification, PaymentMethod, PaymentSchedule,
+    Withholding,
+};
+pub use types::{EmployeeId, EntryId, Hours, MemberId, Money, ParseDecimalError, Rate};
+
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,5 @@
+use chrono::NaiveDate;
+
+pub fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
@@ -1,2 +1,14 @@
+mod decimal;
+mod hours;
+mod money;
+mod rate;
+
+pub use decimal::ParseDecimalError;
+pub use hours::Hours;
+pub use money::Money;
+pub use rate::Rate;
+
 pub type EmployeeId = u32;
+// identifies a timecard or a sales receipt within an employee's classification
+pub type EntryId = u32;
 pub type MemberId = u32;
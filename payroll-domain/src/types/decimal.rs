@@ -0,0 +1,76 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDecimalError(String);
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid decimal: {:?}", self.0)
+    }
+}
+impl std::error::Error for ParseDecimalError {}
+
+// n / d rounded to the nearest integer, ties away from zero
+pub(crate) fn div_round(n: i128, d: i128) -> i64 {
+    let q = n / d;
+    let r = n % d;
+    let q = if 2 * r.abs() >= d.abs() {
+        if (n < 0) != (d < 0) {
+            q - 1
+        } else {
+            q + 1
+        }
+    } else {
+        q
+    };
+    q as i64
+}
+
+// parse a plain decimal literal such as "1020.85" into units of 1/scale
+pub(crate) fn parse_scaled(s: &str, scale: i128) -> Result<i64, ParseDecimalError> {
+    let err = || ParseDecimalError(s.to_string());
+    let (negative, body) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (int_part, frac_part) = body.split_once('.').unwrap_or((body, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return Err(err());
+    }
+    if !int_part
+        .chars()
+        .chain(frac_part.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return Err(err());
+    }
+    let digits = format!("{}{}", int_part, frac_part);
+    let n: i128 = digits.parse().map_err(|_| err())?;
+    let d = 10i128.checked_pow(frac_part.len() as u32).ok_or_else(err)?;
+    let n = n.checked_mul(scale).ok_or_else(err)?;
+    if n / d >= i64::MAX as i128 {
+        return Err(err());
+    }
+    let v = div_round(n, d);
+    Ok(if negative { -v } else { v })
+}
+
+// write units of 1/scale as a decimal with the given number of fraction digits
+pub(crate) fn fmt_scaled(
+    f: &mut fmt::Formatter<'_>,
+    units: i64,
+    scale: i64,
+    digits: u32,
+) -> fmt::Result {
+    let pow = 10i64.pow(digits);
+    let v = div_round(units as i128 * pow as i128, scale as i128);
+    let sign = if v < 0 { "-" } else { "" };
+    let v = v.unsigned_abs();
+    write!(
+        f,
+        "{}{}.{:0width$}",
+        sign,
+        v / pow as u64,
+        v % pow as u64,
+        width = digits as usize
+    )
+}
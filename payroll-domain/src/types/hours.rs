@@ -0,0 +1,86 @@
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::decimal::{div_round, fmt_scaled, parse_scaled, ParseDecimalError};
+use super::rate::Rate;
+
+/// A quantity of time worked, kept to the minute.
+///
+/// Literals such as "7.25" are converted to whole minutes, rounding to the
+/// nearest minute, ties away from zero.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hours(i64);
+impl Hours {
+    pub const ZERO: Hours = Hours(0);
+    pub const MINUTES_PER_HOUR: i64 = 60;
+
+    pub const fn from_hours(hours: i64) -> Self {
+        Self(hours * Self::MINUTES_PER_HOUR)
+    }
+    pub const fn from_minutes(minutes: i64) -> Self {
+        Self(minutes)
+    }
+    pub const fn as_minutes(&self) -> i64 {
+        self.0
+    }
+}
+impl fmt::Display for Hours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_scaled(f, self.0, Self::MINUTES_PER_HOUR, 2)
+    }
+}
+impl fmt::Debug for Hours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}h", self)
+    }
+}
+impl FromStr for Hours {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_scaled(s, Self::MINUTES_PER_HOUR as i128).map(Self)
+    }
+}
+impl Add for Hours {
+    type Output = Hours;
+
+    fn add(self, rhs: Hours) -> Hours {
+        Hours(self.0 + rhs.0)
+    }
+}
+impl AddAssign for Hours {
+    fn add_assign(&mut self, rhs: Hours) {
+        self.0 += rhs.0;
+    }
+}
+impl Sub for Hours {
+    type Output = Hours;
+
+    fn sub(self, rhs: Hours) -> Hours {
+        Hours(self.0 - rhs.0)
+    }
+}
+impl SubAssign for Hours {
+    fn sub_assign(&mut self, rhs: Hours) {
+        self.0 -= rhs.0;
+    }
+}
+impl Mul<Rate> for Hours {
+    type Output = Hours;
+
+    fn mul(self, rhs: Rate) -> Hours {
+        Hours(div_round(
+            self.0 as i128 * rhs.as_basis_points() as i128,
+            Rate::BASIS_POINTS as i128,
+        ))
+    }
+}
+impl Sum for Hours {
+    fn sum<I: Iterator<Item = Hours>>(iter: I) -> Hours {
+        iter.fold(Hours::ZERO, Add::add)
+    }
+}
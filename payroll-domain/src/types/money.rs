@@ -0,0 +1,169 @@
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use super::decimal::{div_round, fmt_scaled, parse_scaled, ParseDecimalError};
+use super::{Hours, Rate};
+
+/// An exact amount of money, held as an integer number of cents.
+///
+/// Rounding policy: addition and subtraction are exact. Every multiplication
+/// (`Money * Hours`, `Money * Rate`) and every parse of a literal with more than
+/// two fraction digits is rounded to the nearest cent, ties away from zero.
+/// Products are rounded before they are added up, so totals are exact sums of
+/// the rounded amounts.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Money(i64);
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn from_cents(cents: i64) -> Self {
+        Self(cents)
+    }
+    pub const fn as_cents(&self) -> i64 {
+        self.0
+    }
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+    // self * numerator / denominator, rounded once
+    pub fn prorate(self, numerator: i64, denominator: i64) -> Money {
+        Money(div_round(
+            self.0 as i128 * numerator as i128,
+            denominator as i128,
+        ))
+    }
+}
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_scaled(f, self.0, 100, 2)
+    }
+}
+impl fmt::Debug for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+impl FromStr for Money {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_scaled(s, 100).map(Self)
+    }
+}
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, rhs: Money) -> Money {
+        Money(self.0 + rhs.0)
+    }
+}
+impl AddAssign for Money {
+    fn add_assign(&mut self, rhs: Money) {
+        self.0 += rhs.0;
+    }
+}
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, rhs: Money) -> Money {
+        Money(self.0 - rhs.0)
+    }
+}
+impl SubAssign for Money {
+    fn sub_assign(&mut self, rhs: Money) {
+        self.0 -= rhs.0;
+    }
+}
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(-self.0)
+    }
+}
+impl Mul<Hours> for Money {
+    type Output = Money;
+
+    fn mul(self, rhs: Hours) -> Money {
+        Money(div_round(
+            self.0 as i128 * rhs.as_minutes() as i128,
+            Hours::MINUTES_PER_HOUR as i128,
+        ))
+    }
+}
+impl Mul<Rate> for Money {
+    type Output = Money;
+
+    fn mul(self, rhs: Rate) -> Money {
+        Money(div_round(
+            self.0 as i128 * rhs.as_basis_points() as i128,
+            Rate::BASIS_POINTS as i128,
+        ))
+    }
+}
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod test_money {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("1020.85".parse(), Ok(Money::from_cents(102085)));
+        assert_eq!("1000".parse(), Ok(Money::from_cents(100000)));
+        assert_eq!("9.5".parse(), Ok(Money::from_cents(950)));
+        assert_eq!("1023.456".parse(), Ok(Money::from_cents(102346)));
+        assert_eq!("0.005".parse(), Ok(Money::from_cents(1)));
+        assert_eq!("-0.005".parse(), Ok(Money::from_cents(-1)));
+        assert!("12.3.4".parse::<Money>().is_err());
+        assert!("".parse::<Money>().is_err());
+        assert!("123456789012345678901234567890".parse::<Money>().is_err());
+        assert!("1.000000000000000000000000000000000000001"
+            .parse::<Money>()
+            .is_err());
+    }
+    #[test]
+    fn test_display() {
+        assert_eq!(Money::from_cents(102085).to_string(), "1020.85");
+        assert_eq!(Money::from_cents(5).to_string(), "0.05");
+        assert_eq!(Money::from_cents(-5).to_string(), "-0.05");
+    }
+    #[test]
+    fn test_mul() {
+        // 15.25 * 8.0h = 122.00
+        let rate = Money::from_cents(1525);
+        assert_eq!(rate * Hours::from_hours(8), Money::from_cents(12200));
+        // 15.25 * 1.5 = 22.875 -> 22.88
+        assert_eq!(
+            rate * Rate::from_basis_points(15000),
+            Money::from_cents(2288)
+        );
+        // 850.50 * 0.1 = 85.05, no float drift
+        let sales = Money::from_cents(85050);
+        assert_eq!(
+            sales * Rate::from_basis_points(1000),
+            Money::from_cents(8505)
+        );
+    }
+    #[test]
+    fn test_sum() {
+        let total: Money = ["0.1", "0.2", "0.3"]
+            .iter()
+            .map(|s| s.parse::<Money>().unwrap())
+            .sum();
+        assert_eq!(total, Money::from_cents(60));
+    }
+}
@@ -0,0 +1,40 @@
+use std::{fmt, str::FromStr};
+
+use super::decimal::{fmt_scaled, parse_scaled, ParseDecimalError};
+
+/// A dimensionless factor such as a commission rate (0.1) or an overtime
+/// multiplier (1.5), kept in basis points (1/10000).
+///
+/// Literals with more than four fraction digits are rounded to the nearest
+/// basis point, ties away from zero.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rate(i64);
+impl Rate {
+    pub const BASIS_POINTS: i64 = 10_000;
+    pub const ZERO: Rate = Rate(0);
+    pub const ONE: Rate = Rate(Self::BASIS_POINTS);
+
+    pub const fn from_basis_points(bp: i64) -> Self {
+        Self(bp)
+    }
+    pub const fn as_basis_points(&self) -> i64 {
+        self.0
+    }
+}
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_scaled(f, self.0, Self::BASIS_POINTS, 4)
+    }
+}
+impl fmt::Debug for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+impl FromStr for Rate {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_scaled(s, Self::BASIS_POINTS as i128).map(Self)
+    }
+}
@@ -1,47 +1,93 @@
 use chrono::{Datelike, NaiveDate, Weekday};
-use std::any::Any;
+use std::{any::Any, cell::RefCell, rc::Rc};
 
-use payroll_domain::{Affiliation, MemberId, Paycheck};
+use payroll_domain::{Affiliation, Deduction, MemberId, Money, Paycheck, Rate};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ServiceCharge {
     date: NaiveDate,
-    amount: f32,
+    amount: Money,
 }
 impl ServiceCharge {
-    pub fn new(date: NaiveDate, amount: f32) -> Self {
+    pub fn new(date: NaiveDate, amount: Money) -> Self {
         Self { date, amount }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AffiliationImpl {
-    Unaffiliated,
     Union {
         member_id: MemberId,
-        dues: f32,
+        dues: Money,
         service_charges: Vec<ServiceCharge>,
     },
+    // a fixed premium on every paycheck
+    HealthPremium {
+        premium: Money,
+    },
+    // a share of the gross pay on every paycheck
+    SavingsPlan {
+        rate: Rate,
+    },
 }
 impl AffiliationImpl {
     pub fn add_service_charge(&mut self, sc: ServiceCharge) {
         match self {
-            AffiliationImpl::Unaffiliated => {
-                panic!("Service charge is not applicable for unaffiliated");
-            }
             AffiliationImpl::Union {
                 service_charges, ..
             } => {
                 service_charges.push(sc);
             }
+            _ => panic!("Service charge is only applicable for union"),
+        }
+    }
+    // set the service charge on `date` when there is a single one; the number that matched
+    pub fn amend_service_charge(&mut self, date: NaiveDate, amount: Money) -> usize {
+        match self {
+            AffiliationImpl::Union {
+                service_charges, ..
+            } => {
+                let mut matched: Vec<_> = service_charges
+                    .iter_mut()
+                    .filter(|sc| sc.date == date)
+                    .collect();
+                if let [sc] = matched.as_mut_slice() {
+                    sc.amount = amount;
+                }
+                matched.len()
+            }
+            _ => panic!("Service charge is only applicable for union"),
+        }
+    }
+    pub fn remove_service_charges(&mut self, date: NaiveDate) -> Vec<ServiceCharge> {
+        match self {
+            AffiliationImpl::Union {
+                service_charges, ..
+            } => {
+                let (removed, kept) = service_charges
+                    .drain(..)
+                    .partition(|sc: &ServiceCharge| sc.date == date);
+                *service_charges = kept;
+                removed
+            }
+            _ => panic!("Service charge is only applicable for union"),
         }
     }
-    pub fn get_member_id(&self) -> MemberId {
+    pub fn get_member_id(&self) -> Option<MemberId> {
         match self {
-            AffiliationImpl::Unaffiliated => panic!("Unaffiliated has no member id"),
-            AffiliationImpl::Union { member_id, .. } => *member_id,
+            AffiliationImpl::Union { member_id, .. } => Some(*member_id),
+            _ => None,
         }
     }
+    // the member id of the union among `affiliations`
+    pub fn find_member_id(affiliations: &[Rc<RefCell<dyn Affiliation>>]) -> Option<MemberId> {
+        affiliations.iter().find_map(|a| {
+            a.borrow()
+                .as_any()
+                .downcast_ref::<AffiliationImpl>()
+                .and_then(|a| a.get_member_id())
+        })
+    }
 }
 impl Affiliation for AffiliationImpl {
     fn as_any(&self) -> &dyn Any {
@@ -50,31 +96,81 @@ impl Affiliation for AffiliationImpl {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
-    fn calculate_deductions(&self, pc: &Paycheck) -> f32 {
+    fn calculate_deductions(&self, pc: &Paycheck) -> Vec<Deduction> {
         match self {
-            AffiliationImpl::Unaffiliated => 0.0,
             AffiliationImpl::Union {
                 dues,
                 service_charges,
                 ..
             } => {
-                let mut total_deductions = 0.0;
+                let mut deductions = vec![];
                 let period = pc.get_period();
                 for d in period.start().iter_days() {
                     if d > *period.end() {
                         break;
                     }
                     if d.weekday() == Weekday::Fri {
-                        total_deductions += dues;
+                        deductions.push(Deduction::UnionDues {
+                            date: d,
+                            amount: *dues,
+                        });
                     }
                 }
                 for sc in service_charges {
                     if period.contains(&sc.date) {
-                        total_deductions += sc.amount;
+                        deductions.push(Deduction::ServiceCharge {
+                            date: sc.date,
+                            amount: sc.amount,
+                        });
                     }
                 }
-                total_deductions
+                deductions
+            }
+            AffiliationImpl::HealthPremium { premium } => {
+                vec![Deduction::HealthPremium { amount: *premium }]
+            }
+            AffiliationImpl::SavingsPlan { rate } => {
+                vec![Deduction::SavingsPlan {
+                    amount: pc.get_gross_pay() * *rate,
+                }]
             }
         }
     }
 }
+
+#[cfg(test)]
+mod test_union {
+    use super::*;
+    use crate::test_util::ymd;
+
+    #[test]
+    fn test_itemized_deductions() {
+        let pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        let mut union = AffiliationImpl::Union {
+            member_id: 7235,
+            dues: Money::from_cents(945),
+            service_charges: vec![],
+        };
+        union.add_service_charge(ServiceCharge::new(ymd(2024, 8, 8), Money::from_cents(350)));
+        union.add_service_charge(ServiceCharge::new(ymd(2024, 8, 20), Money::from_cents(500)));
+
+        // the dues for each Friday and each service charge within the period
+        assert_eq!(
+            union.calculate_deductions(&pc),
+            [
+                Deduction::UnionDues {
+                    date: ymd(2024, 8, 9),
+                    amount: Money::from_cents(945),
+                },
+                Deduction::UnionDues {
+                    date: ymd(2024, 8, 16),
+                    amount: Money::from_cents(945),
+                },
+                Deduction::ServiceCharge {
+                    date: ymd(2024, 8, 8),
+                    amount: Money::from_cents(350),
+                },
+            ]
+        );
+    }
+}
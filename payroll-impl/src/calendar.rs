@@ -0,0 +1,192 @@
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::{collections::BTreeSet, fs, path::Path, str::FromStr};
+use thiserror::Error;
+
+use payroll_domain::HolidayCalendar;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum HolidayCalendarError {
+    #[error("can't read holiday calendar {0}: {1}")]
+    ReadFailed(String, String),
+    #[error("invalid holiday calendar line {0}: {1}")]
+    InvalidLine(usize, String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HolidayCalendarImpl {
+    // every day is a business day
+    NoHolidays,
+    // weekends and Japanese national holidays
+    Japanese,
+    // weekends and the listed dates
+    Listed { holidays: BTreeSet<NaiveDate> },
+}
+impl HolidayCalendarImpl {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, HolidayCalendarError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|e| {
+            HolidayCalendarError::ReadFailed(path.display().to_string(), e.to_string())
+        })?;
+        text.parse()
+    }
+}
+// one holiday per line: "<date> [description]", '#' starts a comment
+impl FromStr for HolidayCalendarImpl {
+    type Err = HolidayCalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut holidays = BTreeSet::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let date = line.split_whitespace().next().unwrap_or("");
+            let date = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| HolidayCalendarError::InvalidLine(i + 1, line.to_string()))?;
+            holidays.insert(date);
+        }
+        Ok(HolidayCalendarImpl::Listed { holidays })
+    }
+}
+impl HolidayCalendar for HolidayCalendarImpl {
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        match self {
+            HolidayCalendarImpl::NoHolidays => true,
+            HolidayCalendarImpl::Japanese => {
+                !is_weekend(date) && !japanese_holidays(date.year()).contains(&date)
+            }
+            HolidayCalendarImpl::Listed { holidays } => {
+                !is_weekend(date) && !holidays.contains(&date)
+            }
+        }
+    }
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+// Rules of the Act on National Holidays in force since 2020.
+// The one-off moves for the Tokyo Olympics in 2020 and 2021 are not modeled.
+fn japanese_holidays(year: i32) -> BTreeSet<NaiveDate> {
+    let ymd = |m, d| NaiveDate::from_ymd_opt(year, m, d).unwrap();
+    let nth_monday = |m, n| NaiveDate::from_weekday_of_month_opt(year, m, Weekday::Mon, n).unwrap();
+    // equinox days, valid for 1980 to 2099
+    let y = (year - 1980) as i64;
+    let vernal = (20_843_100 + 242_194 * y) / 1_000_000 - y / 4;
+    let autumnal = (23_248_800 + 242_194 * y) / 1_000_000 - y / 4;
+
+    let national: BTreeSet<NaiveDate> = [
+        ymd(1, 1),
+        nth_monday(1, 2),
+        ymd(2, 11),
+        ymd(2, 23),
+        ymd(3, vernal as u32),
+        ymd(4, 29),
+        ymd(5, 3),
+        ymd(5, 4),
+        ymd(5, 5),
+        nth_monday(7, 3),
+        ymd(8, 11),
+        nth_monday(9, 3),
+        ymd(9, autumnal as u32),
+        nth_monday(10, 2),
+        ymd(11, 3),
+        ymd(11, 23),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut holidays = national.clone();
+    for d in &national {
+        // a day between two national holidays is a holiday as well
+        let next = *d + Days::new(1);
+        if !national.contains(&next) && national.contains(&(next + Days::new(1))) {
+            holidays.insert(next);
+        }
+        // a holiday on Sunday moves to the next day that isn't a holiday
+        if d.weekday() == Weekday::Sun {
+            let mut substitute = next;
+            while national.contains(&substitute) {
+                substitute = substitute + Days::new(1);
+            }
+            holidays.insert(substitute);
+        }
+    }
+    holidays
+}
+
+#[cfg(test)]
+mod test_holiday_calendar {
+    use super::*;
+    use crate::test_util::ymd;
+    use crate::PaymentScheduleImpl;
+    use payroll_domain::PaymentSchedule;
+
+    #[test]
+    fn test_japanese_holidays() {
+        let holidays = japanese_holidays(2024);
+        assert_eq!(holidays.len(), 21);
+        for d in [
+            ymd(2024, 2, 12),
+            ymd(2024, 3, 20),
+            ymd(2024, 5, 6),
+            ymd(2024, 9, 22),
+            ymd(2024, 9, 23),
+            ymd(2024, 11, 4),
+        ] {
+            assert!(holidays.contains(&d), "{}", d);
+        }
+        // Respect for the Aged Day and the autumnal equinox sandwich 2026-09-22
+        assert!(japanese_holidays(2026).contains(&ymd(2026, 9, 22)));
+        // 2025-05-04 is Sunday and 05-05 is a holiday, so the substitute is 05-06
+        assert!(japanese_holidays(2025).contains(&ymd(2025, 5, 6)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let calendar: HolidayCalendarImpl = "# company holidays
+             2024-08-13  summer break
+             2024-08-14"
+            .parse()
+            .unwrap();
+        assert!(!calendar.is_business_day(ymd(2024, 8, 13)));
+        assert!(!calendar.is_business_day(ymd(2024, 8, 17)));
+        assert!(calendar.is_business_day(ymd(2024, 8, 15)));
+        assert_eq!(
+            "2024-13-01".parse::<HolidayCalendarImpl>(),
+            Err(HolidayCalendarError::InvalidLine(
+                1,
+                "2024-13-01".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_shift_to_previous_business_day() {
+        let calendar = HolidayCalendarImpl::Japanese;
+        let monthly = PaymentScheduleImpl::Monthly;
+        // 2024-08-31 is Saturday
+        assert_eq!(
+            monthly.nominal_pay_date(ymd(2024, 8, 30), &calendar),
+            Some(ymd(2024, 8, 31))
+        );
+        assert!(!monthly.is_pay_date(ymd(2024, 8, 31), &calendar));
+        assert_eq!(
+            monthly.calculate_period(ymd(2024, 8, 31)),
+            ymd(2024, 8, 1)..=ymd(2024, 8, 31)
+        );
+
+        // 2024-02-23 is Friday and the Emperor's Birthday
+        let weekly = PaymentScheduleImpl::Weekly;
+        assert_eq!(
+            weekly.nominal_pay_date(ymd(2024, 2, 22), &calendar),
+            Some(ymd(2024, 2, 23))
+        );
+        assert!(!weekly.is_pay_date(ymd(2024, 2, 23), &calendar));
+        assert!(weekly.is_pay_date(ymd(2024, 3, 1), &calendar));
+
+        assert!(monthly.is_pay_date(ymd(2024, 8, 31), &HolidayCalendarImpl::NoHolidays));
+    }
+}
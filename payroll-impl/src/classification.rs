@@ -1,49 +1,128 @@
-use chrono::NaiveDate;
-use std::any::Any;
+use chrono::{Days, NaiveDate, NaiveDateTime, NaiveTime};
+use std::{any::Any, ops::RangeInclusive};
 
-use payroll_domain::{Paycheck, PaymentClassification};
+use payroll_domain::{
+    CommissionPlan, Earning, EntryId, Hours, Money, OvertimePolicy, PaidEntry, Paycheck,
+    PaymentClassification, Rate, ShiftDifferential,
+};
+
+use crate::rate_history::RateHistory;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TimeCard {
+    // given when the timecard is added
+    id: EntryId,
     date: NaiveDate,
-    hours: f32,
+    hours: Hours,
+    // paid time off rather than hours worked
+    leave: bool,
+    // clock-in and clock-out, when the hours come from them
+    shift: Option<(NaiveDateTime, NaiveDateTime)>,
 }
 impl TimeCard {
-    pub fn new(date: NaiveDate, hours: f32) -> Self {
-        Self { date, hours }
+    pub fn new(date: NaiveDate, hours: Hours) -> Self {
+        Self {
+            id: 0,
+            date,
+            hours,
+            leave: false,
+            shift: None,
+        }
+    }
+    // a shift starting on `date`, ending on the next day unless `clock_out` is after `clock_in`;
+    // None when there is no time between them
+    pub fn clocked(date: NaiveDate, clock_in: NaiveTime, clock_out: NaiveTime) -> Option<Self> {
+        if clock_in == clock_out {
+            return None;
+        }
+        let start = date.and_time(clock_in);
+        let end = if clock_in < clock_out {
+            date.and_time(clock_out)
+        } else {
+            (date + Days::new(1)).and_time(clock_out)
+        };
+        Some(Self {
+            id: 0,
+            date,
+            hours: Hours::from_minutes((end - start).num_minutes()),
+            leave: false,
+            shift: Some((start, end)),
+        })
+    }
+    pub fn leave(date: NaiveDate, hours: Hours) -> Self {
+        Self {
+            id: 0,
+            date,
+            hours,
+            leave: true,
+            shift: None,
+        }
+    }
+    fn entry(&self) -> PaidEntry {
+        PaidEntry::TimeCard {
+            date: self.date,
+            id: self.id,
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SalesReceipt {
+    // given when the receipt is added
+    id: EntryId,
     date: NaiveDate,
-    amount: f32,
+    amount: Money,
+    product: Option<String>,
 }
 impl SalesReceipt {
-    pub fn new(date: NaiveDate, amount: f32) -> Self {
-        Self { date, amount }
+    pub fn new(date: NaiveDate, amount: Money) -> Self {
+        Self {
+            id: 0,
+            date,
+            amount,
+            product: None,
+        }
+    }
+    pub fn of_product(date: NaiveDate, amount: Money, product: &str) -> Self {
+        Self {
+            id: 0,
+            date,
+            amount,
+            product: Some(product.to_string()),
+        }
+    }
+    fn entry(&self) -> PaidEntry {
+        PaidEntry::SalesReceipt {
+            date: self.date,
+            id: self.id,
+        }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PaymentClassificationImpl {
     Salaried {
-        salary: f32,
+        salary: RateHistory<Money>,
     },
     Hourly {
-        hourly_rate: f32,
+        hourly_rate: RateHistory<Money>,
         timecards: Vec<TimeCard>,
+        overtime: OvertimePolicy,
+        differential: ShiftDifferential,
     },
     Commissioned {
-        salary: f32,
-        commission_rate: f32,
+        salary: RateHistory<Money>,
+        commission_rate: RateHistory<Rate>,
         sales_receipts: Vec<SalesReceipt>,
+        // overrides the flat commission rate when set
+        plan: Option<CommissionPlan>,
     },
 }
 impl PaymentClassificationImpl {
-    pub fn add_timecard(&mut self, tc: TimeCard) {
+    pub fn add_timecard(&mut self, mut tc: TimeCard) {
         match self {
             PaymentClassificationImpl::Hourly { timecards, .. } => {
+                tc.id = timecards.iter().map(|tc| tc.id + 1).max().unwrap_or(1);
                 timecards.push(tc);
             }
             _ => {
@@ -51,9 +130,55 @@ impl PaymentClassificationImpl {
             }
         }
     }
-    pub fn add_sales_receipt(&mut self, sr: SalesReceipt) {
+    // drop the hours worked on `date`, leaving any leave taken that day alone
+    pub fn remove_timecards(&mut self, date: NaiveDate) -> Vec<TimeCard> {
+        match self {
+            PaymentClassificationImpl::Hourly { timecards, .. } => {
+                let (removed, kept) = timecards
+                    .drain(..)
+                    .partition(|tc: &TimeCard| tc.date == date && !tc.leave);
+                *timecards = kept;
+                removed
+            }
+            _ => {
+                panic!("Timecard is not applicable for this classification");
+            }
+        }
+    }
+    // whether the hours worked on `date` come from clock times
+    pub fn is_clocked(&self, date: NaiveDate) -> bool {
+        match self {
+            PaymentClassificationImpl::Hourly { timecards, .. } => timecards
+                .iter()
+                .any(|tc| tc.date == date && tc.shift.is_some()),
+            _ => false,
+        }
+    }
+    // set the hours worked on `date` when a single timecard has them, unless they come from
+    // clock times; the number of timecards that matched
+    pub fn amend_timecard(&mut self, date: NaiveDate, hours: Hours) -> usize {
+        match self {
+            PaymentClassificationImpl::Hourly { timecards, .. } => {
+                let mut matched: Vec<_> = timecards
+                    .iter_mut()
+                    .filter(|tc| tc.date == date && !tc.leave)
+                    .collect();
+                if let [tc] = matched.as_mut_slice() {
+                    if tc.shift.is_none() {
+                        tc.hours = hours;
+                    }
+                }
+                matched.len()
+            }
+            _ => {
+                panic!("Timecard is not applicable for this classification");
+            }
+        }
+    }
+    pub fn add_sales_receipt(&mut self, mut sr: SalesReceipt) {
         match self {
             PaymentClassificationImpl::Commissioned { sales_receipts, .. } => {
+                sr.id = sales_receipts.iter().map(|sr| sr.id + 1).max().unwrap_or(1);
                 sales_receipts.push(sr);
             }
             _ => {
@@ -61,48 +186,633 @@ impl PaymentClassificationImpl {
             }
         }
     }
+    // set the amount sold on `date` when a single receipt has it, keeping its product;
+    // the number of receipts that matched
+    pub fn amend_sales_receipt(&mut self, date: NaiveDate, amount: Money) -> usize {
+        match self {
+            PaymentClassificationImpl::Commissioned { sales_receipts, .. } => {
+                let mut matched: Vec<_> = sales_receipts
+                    .iter_mut()
+                    .filter(|sr| sr.date == date)
+                    .collect();
+                if let [sr] = matched.as_mut_slice() {
+                    sr.amount = amount;
+                }
+                matched.len()
+            }
+            _ => {
+                panic!("Sales receipt is not applicable for this classification");
+            }
+        }
+    }
+    pub fn remove_sales_receipts(&mut self, date: NaiveDate) -> Vec<SalesReceipt> {
+        match self {
+            PaymentClassificationImpl::Commissioned { sales_receipts, .. } => {
+                let (removed, kept) = sales_receipts
+                    .drain(..)
+                    .partition(|sr: &SalesReceipt| sr.date == date);
+                *sales_receipts = kept;
+                removed
+            }
+            _ => {
+                panic!("Sales receipt is not applicable for this classification");
+            }
+        }
+    }
 }
 impl PaymentClassification for PaymentClassificationImpl {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
-    fn calculate_pay(&self, pc: &Paycheck) -> f32 {
+    fn calculate_pay(&self, pc: &Paycheck) -> Vec<Earning> {
         match self {
-            PaymentClassificationImpl::Salaried { salary } => *salary,
+            PaymentClassificationImpl::Salaried { salary } => base_pay(salary, pc),
             PaymentClassificationImpl::Hourly {
                 hourly_rate,
                 timecards,
+                overtime,
+                differential,
             } => {
-                let calc_pay_for_timecard = |tc: &TimeCard| {
-                    let hours = tc.hours;
-                    let overtime = (hours - 8.0).max(0.0);
-                    let straight_time = hours - overtime;
-                    straight_time * hourly_rate + overtime * hourly_rate * 1.5
-                };
                 let period = pc.get_period();
-                let mut total_pay = 0.0;
-                for tc in timecards {
-                    if period.contains(&tc.date) {
-                        total_pay += calc_pay_for_timecard(tc);
-                    }
-                }
-                total_pay
+                let (paid, unpaid) = split_entries(pc, timecards, TimeCard::entry, |tc| tc.date);
+                let paid: Vec<(NaiveDate, Hours)> = paid
+                    .into_iter()
+                    .filter(|tc| !tc.leave)
+                    .map(|tc| (tc.date, tc.hours))
+                    .collect();
+                let days: Vec<(NaiveDate, Hours)> = unpaid
+                    .iter()
+                    .filter(|tc| !tc.leave)
+                    .map(|tc| (tc.date, tc.hours))
+                    .collect();
+                // leave doesn't count towards overtime
+                let leave = unpaid
+                    .iter()
+                    .filter(|tc| tc.leave)
+                    .map(|tc| Earning::Leave {
+                        date: tc.date,
+                        hours: tc.hours,
+                        amount: hourly_rate.at(tc.date) * tc.hours,
+                    });
+                let premiums = unpaid
+                    .iter()
+                    .filter_map(|tc| tc.shift.map(|(start, end)| (tc.date, start, end)))
+                    .flat_map(|(date, start, end)| {
+                        differential
+                            .premiums(start, end)
+                            .into_iter()
+                            .map(move |(hours, amount)| Earning::ShiftDifferential {
+                                date,
+                                hours,
+                                amount,
+                            })
+                    })
+                    .collect::<Vec<_>>();
+                overtime
+                    .split_hours(*period.start(), &paid, &days)
+                    .into_iter()
+                    .map(|(date, hours, multiplier)| {
+                        let hourly_rate = hourly_rate.at(date);
+                        if multiplier == Rate::ONE {
+                            Earning::RegularHours {
+                                date,
+                                hours,
+                                amount: hourly_rate * hours,
+                            }
+                        } else {
+                            Earning::OvertimeHours {
+                                date,
+                                hours,
+                                multiplier,
+                                amount: hourly_rate * hours * multiplier,
+                            }
+                        }
+                    })
+                    .chain(premiums)
+                    .chain(leave)
+                    .collect()
             }
             PaymentClassificationImpl::Commissioned {
                 salary,
                 commission_rate,
                 sales_receipts,
+                plan,
             } => {
-                let calc_pay_for_sales_receipt = |sr: &SalesReceipt| sr.amount * commission_rate;
-                let period = pc.get_period();
-                let mut total_pay = *salary;
-                for sr in sales_receipts {
-                    if period.contains(&sr.date) {
-                        total_pay += calc_pay_for_sales_receipt(sr);
+                let (mut paid, mut receipts) =
+                    split_entries(pc, sales_receipts, SalesReceipt::entry, |sr| sr.date);
+                let commissions = match plan {
+                    Some(plan) => {
+                        // the tiers fill up in the order the sales were made, after
+                        // the sales another paycheck paid for
+                        paid.sort_by_key(|sr| sr.date);
+                        receipts.sort_by_key(|sr| sr.date);
+                        let sales = paid
+                            .iter()
+                            .chain(&receipts)
+                            .map(|sr| (sr.product.as_deref(), sr.amount))
+                            .collect::<Vec<_>>();
+                        plan.calculate(&sales).split_off(paid.len())
                     }
-                }
-                total_pay
+                    None => receipts
+                        .iter()
+                        .map(|sr| sr.amount * commission_rate.at(sr.date))
+                        .collect(),
+                };
+                let mut earnings = base_pay(salary, pc);
+                earnings.extend(receipts.iter().zip(commissions).map(|(sr, amount)| {
+                    Earning::Commission {
+                        date: sr.date,
+                        sales: sr.amount,
+                        amount,
+                    }
+                }));
+                earnings
+            }
+        }
+    }
+    fn paid_entries(&self, pc: &Paycheck) -> Vec<PaidEntry> {
+        let base_pay = || {
+            unpaid_days(pc)
+                .into_iter()
+                .map(|days| PaidEntry::BasePay { days })
+        };
+        match self {
+            PaymentClassificationImpl::Salaried { .. } => base_pay().collect(),
+            PaymentClassificationImpl::Hourly { timecards, .. } => {
+                let (_, unpaid) = split_entries(pc, timecards, TimeCard::entry, |tc| tc.date);
+                unpaid.into_iter().map(TimeCard::entry).collect()
+            }
+            PaymentClassificationImpl::Commissioned { sales_receipts, .. } => {
+                let (_, unpaid) =
+                    split_entries(pc, sales_receipts, SalesReceipt::entry, |sr| sr.date);
+                base_pay()
+                    .chain(unpaid.into_iter().map(SalesReceipt::entry))
+                    .collect()
             }
         }
     }
 }
+
+// The entries within the period another paycheck paid for, and the entries on the days
+// employed that are still to be paid.
+fn split_entries<'a, T>(
+    pc: &Paycheck,
+    entries: &'a [T],
+    entry: impl Fn(&T) -> PaidEntry,
+    date: impl Fn(&T) -> NaiveDate,
+) -> (Vec<&'a T>, Vec<&'a T>) {
+    let period = pc.get_period();
+    let employed = pc.get_employed_period();
+    let (paid, unpaid): (Vec<_>, Vec<_>) = entries
+        .iter()
+        .filter(|e| period.contains(&date(e)))
+        .partition(|e| pc.is_paid_elsewhere(&entry(e)));
+    let unpaid = unpaid
+        .into_iter()
+        .filter(|e| employed.contains(&date(e)))
+        .collect();
+    (paid, unpaid)
+}
+
+// the runs of days employed that no other paycheck paid the base pay for
+fn unpaid_days(pc: &Paycheck) -> Vec<RangeInclusive<NaiveDate>> {
+    let employed = pc.get_employed_period();
+    let mut runs: Vec<RangeInclusive<NaiveDate>> = vec![];
+    for date in employed
+        .start()
+        .iter_days()
+        .take_while(|d| employed.contains(d))
+    {
+        if pc.is_base_pay_paid_elsewhere(date) {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if *run.end() + Days::new(1) == date => *run = *run.start()..=date,
+            _ => runs.push(date..=date),
+        }
+    }
+    runs
+}
+
+// one salary line per rate in force, for the calendar days employed within the period
+// that no other paycheck paid for
+fn base_pay(salary: &RateHistory<Money>, pc: &Paycheck) -> Vec<Earning> {
+    let period = pc.get_period();
+    let days = |r: &RangeInclusive<NaiveDate>| (*r.end() - *r.start()).num_days() + 1;
+    unpaid_days(pc)
+        .into_iter()
+        .flat_map(|run| salary.segments(run))
+        .map(|(range, salary)| Earning::Salary {
+            amount: if range == period {
+                salary
+            } else {
+                salary.prorate(days(&range), days(&period))
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test_itemized {
+    use super::*;
+    use crate::test_util::ymd;
+
+    #[test]
+    fn test_hourly_lines() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        pc.set_employed_period(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        let c = PaymentClassificationImpl::Hourly {
+            hourly_rate: Money::from_cents(1500).into(),
+            timecards: vec![
+                TimeCard::new(ymd(2024, 8, 5), Hours::from_hours(10)),
+                TimeCard::new(ymd(2024, 8, 6), Hours::from_hours(4)),
+            ],
+            overtime: OvertimePolicy::default(),
+            differential: ShiftDifferential::default(),
+        };
+
+        // a line for the straight and the overtime hours of each day
+        assert_eq!(
+            c.calculate_pay(&pc),
+            [
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 5),
+                    hours: Hours::from_hours(8),
+                    amount: Money::from_cents(12000),
+                },
+                Earning::OvertimeHours {
+                    date: ymd(2024, 8, 5),
+                    hours: Hours::from_hours(2),
+                    multiplier: Rate::from_basis_points(15000),
+                    amount: Money::from_cents(4500),
+                },
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 6),
+                    hours: Hours::from_hours(4),
+                    amount: Money::from_cents(6000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_commission_per_receipt() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        pc.set_employed_period(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        let c = PaymentClassificationImpl::Commissioned {
+            salary: Money::from_cents(100000).into(),
+            commission_rate: Rate::from_basis_points(1000).into(),
+            sales_receipts: vec![
+                SalesReceipt::new(ymd(2024, 8, 5), Money::from_cents(50000)),
+                SalesReceipt::new(ymd(2024, 8, 12), Money::from_cents(30000)),
+            ],
+            plan: None,
+        };
+
+        assert_eq!(
+            c.calculate_pay(&pc),
+            [
+                Earning::Salary {
+                    amount: Money::from_cents(100000),
+                },
+                Earning::Commission {
+                    date: ymd(2024, 8, 5),
+                    sales: Money::from_cents(50000),
+                    amount: Money::from_cents(5000),
+                },
+                Earning::Commission {
+                    date: ymd(2024, 8, 12),
+                    sales: Money::from_cents(30000),
+                    amount: Money::from_cents(3000),
+                },
+            ]
+        );
+    }
+}
+#[cfg(test)]
+mod test_proration {
+    use super::*;
+    use crate::test_util::ymd;
+
+    #[test]
+    fn test_salaried_hired_mid_month() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 1)..=ymd(2024, 8, 31));
+        pc.set_employed_period(ymd(2024, 8, 20)..=ymd(2024, 8, 31));
+        let c = PaymentClassificationImpl::Salaried {
+            salary: Money::from_cents(310000).into(),
+        };
+        // 12 of 31 days
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![Earning::Salary {
+                amount: Money::from_cents(120000)
+            }]
+        );
+    }
+
+    #[test]
+    fn test_commissioned_terminated() {
+        let mut pc = Paycheck::new(ymd(2024, 7, 27)..=ymd(2024, 8, 9));
+        pc.set_employed_period(ymd(2024, 7, 27)..=ymd(2024, 8, 2));
+        let c = PaymentClassificationImpl::Commissioned {
+            salary: Money::from_cents(140000).into(),
+            commission_rate: Rate::from_basis_points(1000).into(),
+            sales_receipts: vec![
+                SalesReceipt::new(ymd(2024, 8, 1), Money::from_cents(50000)),
+                SalesReceipt::new(ymd(2024, 8, 5), Money::from_cents(50000)),
+            ],
+            plan: None,
+        };
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![
+                Earning::Salary {
+                    amount: Money::from_cents(70000)
+                },
+                Earning::Commission {
+                    date: ymd(2024, 8, 1),
+                    sales: Money::from_cents(50000),
+                    amount: Money::from_cents(5000)
+                }
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_rate_change {
+    use super::*;
+    use crate::test_util::ymd;
+
+    #[test]
+    fn test_hourly_raise_mid_week() {
+        let pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        let mut hourly_rate = RateHistory::new(Money::from_cents(1000));
+        hourly_rate.change(ymd(2024, 8, 7), Money::from_cents(1200));
+        let c = PaymentClassificationImpl::Hourly {
+            hourly_rate,
+            timecards: vec![
+                TimeCard::new(ymd(2024, 8, 6), Hours::from_hours(8)),
+                TimeCard::new(ymd(2024, 8, 7), Hours::from_hours(8)),
+            ],
+            overtime: OvertimePolicy::default(),
+            differential: ShiftDifferential::default(),
+        };
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 6),
+                    hours: Hours::from_hours(8),
+                    amount: Money::from_cents(8000)
+                },
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 7),
+                    hours: Hours::from_hours(8),
+                    amount: Money::from_cents(9600)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_salaried_raise_mid_month() {
+        let pc = Paycheck::new(ymd(2024, 8, 1)..=ymd(2024, 8, 31));
+        let mut salary = RateHistory::new(Money::from_cents(310000));
+        salary.change(ymd(2024, 8, 21), Money::from_cents(341000));
+        let c = PaymentClassificationImpl::Salaried { salary };
+        // 20 days at the old salary and 11 days at the new one
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![
+                Earning::Salary {
+                    amount: Money::from_cents(200000)
+                },
+                Earning::Salary {
+                    amount: Money::from_cents(121000)
+                },
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod test_leave {
+    use super::*;
+    use crate::test_util::ymd;
+
+    #[test]
+    fn test_leave_at_regular_rate() {
+        let pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        let c = PaymentClassificationImpl::Hourly {
+            hourly_rate: Money::from_cents(1000).into(),
+            timecards: vec![
+                TimeCard::new(ymd(2024, 8, 6), Hours::from_hours(8)),
+                TimeCard::leave(ymd(2024, 8, 6), Hours::from_hours(4)),
+            ],
+            overtime: OvertimePolicy::default(),
+            differential: ShiftDifferential::default(),
+        };
+        // the leave hours don't push the day into overtime
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 6),
+                    hours: Hours::from_hours(8),
+                    amount: Money::from_cents(8000)
+                },
+                Earning::Leave {
+                    date: ymd(2024, 8, 6),
+                    hours: Hours::from_hours(4),
+                    amount: Money::from_cents(4000)
+                },
+            ]
+        );
+    }
+}
+#[cfg(test)]
+mod test_correction {
+    use super::*;
+    use crate::test_util::ymd;
+    use payroll_domain::ShiftWindow;
+
+    #[test]
+    fn test_amend_timecard() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        pc.set_employed_period(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        let mut c = PaymentClassificationImpl::Hourly {
+            hourly_rate: Money::from_cents(1000).into(),
+            timecards: vec![
+                TimeCard::new(ymd(2024, 8, 5), Hours::from_hours(80)),
+                TimeCard::leave(ymd(2024, 8, 5), Hours::from_hours(2)),
+            ],
+            overtime: OvertimePolicy::default(),
+            differential: ShiftDifferential::default(),
+        };
+        assert_eq!(c.remove_timecards(ymd(2024, 8, 6)), vec![]);
+        assert_eq!(
+            c.remove_timecards(ymd(2024, 8, 5)),
+            vec![TimeCard::new(ymd(2024, 8, 5), Hours::from_hours(80))]
+        );
+        c.add_timecard(TimeCard::new(ymd(2024, 8, 5), Hours::from_hours(8)));
+        // the leave taken that day is still paid
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 5),
+                    hours: Hours::from_hours(8),
+                    amount: Money::from_cents(8000),
+                },
+                Earning::Leave {
+                    date: ymd(2024, 8, 5),
+                    hours: Hours::from_hours(2),
+                    amount: Money::from_cents(2000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_amend_clocked_timecard() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        pc.set_employed_period(ymd(2024, 8, 3)..=ymd(2024, 8, 9));
+        let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let mut c = PaymentClassificationImpl::Hourly {
+            hourly_rate: Money::from_cents(1000).into(),
+            timecards: vec![TimeCard::clocked(ymd(2024, 8, 6), hm(20, 0), hm(2, 30)).unwrap()],
+            overtime: OvertimePolicy::default(),
+            differential: ShiftDifferential::new(vec![ShiftWindow::new(
+                hm(22, 0),
+                hm(6, 0),
+                Money::from_cents(150),
+            )]),
+        };
+        assert!(c.is_clocked(ymd(2024, 8, 6)));
+        assert_eq!(c.amend_timecard(ymd(2024, 8, 6), Hours::from_hours(6)), 1);
+        // the hours stay those of the shift
+        assert_eq!(
+            c.calculate_pay(&pc),
+            vec![
+                Earning::RegularHours {
+                    date: ymd(2024, 8, 6),
+                    hours: Hours::from_minutes(390),
+                    amount: Money::from_cents(6500),
+                },
+                Earning::ShiftDifferential {
+                    date: ymd(2024, 8, 6),
+                    hours: Hours::from_minutes(270),
+                    amount: Money::from_cents(675),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_amend_ambiguous_sales_receipt() {
+        let mut c = PaymentClassificationImpl::Commissioned {
+            salary: Money::ZERO.into(),
+            commission_rate: Rate::from_basis_points(1000).into(),
+            sales_receipts: vec![
+                SalesReceipt::new(ymd(2024, 8, 6), Money::from_cents(100000)),
+                SalesReceipt::new(ymd(2024, 8, 6), Money::from_cents(85050)),
+            ],
+            plan: None,
+        };
+        let before = c.clone();
+        assert_eq!(
+            c.amend_sales_receipt(ymd(2024, 8, 6), Money::from_cents(50000)),
+            2
+        );
+        assert_eq!(c, before);
+    }
+}
+#[cfg(test)]
+mod test_commission_plan {
+    use super::*;
+    use crate::test_util::ymd;
+    use payroll_domain::CommissionTier;
+
+    #[test]
+    fn test_tiers_fill_in_date_order() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        pc.set_employed_period(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        let c = PaymentClassificationImpl::Commissioned {
+            salary: Money::ZERO.into(),
+            commission_rate: Rate::from_basis_points(1000).into(),
+            sales_receipts: vec![
+                SalesReceipt::new(ymd(2024, 8, 13), Money::from_cents(100000)),
+                SalesReceipt::of_product(ymd(2024, 8, 6), Money::from_cents(50000), "Service"),
+                SalesReceipt::new(ymd(2024, 8, 5), Money::from_cents(150000)),
+            ],
+            plan: Some(CommissionPlan::new(
+                "Tiered",
+                vec![
+                    CommissionTier::new(Money::ZERO, Rate::from_basis_points(500)),
+                    CommissionTier::new(Money::from_cents(200000), Rate::from_basis_points(800)),
+                ],
+                vec![("Service".to_string(), Rate::from_basis_points(1200))],
+                None,
+            )),
+        };
+        assert_eq!(
+            c.calculate_pay(&pc)[1..],
+            [
+                Earning::Commission {
+                    date: ymd(2024, 8, 5),
+                    sales: Money::from_cents(150000),
+                    amount: Money::from_cents(7500),
+                },
+                Earning::Commission {
+                    date: ymd(2024, 8, 6),
+                    sales: Money::from_cents(50000),
+                    amount: Money::from_cents(6000),
+                },
+                // 500.00 at 5% and 500.00 at 8%
+                Earning::Commission {
+                    date: ymd(2024, 8, 13),
+                    sales: Money::from_cents(100000),
+                    amount: Money::from_cents(6500),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_amend_keeps_product() {
+        let mut pc = Paycheck::new(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        pc.set_employed_period(ymd(2024, 8, 3)..=ymd(2024, 8, 16));
+        let mut c = PaymentClassificationImpl::Commissioned {
+            salary: Money::ZERO.into(),
+            commission_rate: Rate::from_basis_points(1000).into(),
+            sales_receipts: vec![SalesReceipt::of_product(
+                ymd(2024, 8, 6),
+                Money::from_cents(50000),
+                "Service",
+            )],
+            plan: Some(CommissionPlan::new(
+                "Service",
+                vec![CommissionTier::new(
+                    Money::ZERO,
+                    Rate::from_basis_points(500),
+                )],
+                vec![("Service".to_string(), Rate::from_basis_points(1200))],
+                None,
+            )),
+        };
+        assert_eq!(
+            c.amend_sales_receipt(ymd(2024, 8, 6), Money::from_cents(60000)),
+            1
+        );
+        // still paid at the product rate
+        assert_eq!(
+            c.calculate_pay(&pc)[1..],
+            [Earning::Commission {
+                date: ymd(2024, 8, 6),
+                sales: Money::from_cents(60000),
+                amount: Money::from_cents(7200),
+            }]
+        );
+    }
+}
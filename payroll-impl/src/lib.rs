@@ -1,9 +1,18 @@
 mod affiliation;
+mod calendar;
 mod classification;
 mod method;
+mod rate_history;
 mod schedule;
+mod withholding;
 
 pub use affiliation::{AffiliationImpl, ServiceCharge};
+pub use calendar::{HolidayCalendarError, HolidayCalendarImpl};
 pub use classification::{PaymentClassificationImpl, SalesReceipt, TimeCard};
 pub use method::PaymentMethodImpl;
+pub use rate_history::RateHistory;
 pub use schedule::PaymentScheduleImpl;
+pub use withholding::{TaxBracket, TaxTable, TaxTableError, WithholdingImpl};
+
+#[cfg(test)]
+mod test_util;
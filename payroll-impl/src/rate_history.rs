@@ -0,0 +1,98 @@
+use chrono::{Days, NaiveDate};
+use std::{
+    collections::BTreeMap,
+    ops::{Bound, RangeInclusive},
+};
+
+// a pay rate and its dated changes, each one in force from its date on
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateHistory<T> {
+    initial: T,
+    changes: BTreeMap<NaiveDate, T>,
+}
+impl<T: Copy> RateHistory<T> {
+    pub fn new(initial: T) -> Self {
+        Self {
+            initial,
+            changes: BTreeMap::new(),
+        }
+    }
+    pub fn get_changes(&self) -> &BTreeMap<NaiveDate, T> {
+        &self.changes
+    }
+    // the rate in force on `date`
+    pub fn at(&self, date: NaiveDate) -> T {
+        self.changes
+            .range(..=date)
+            .next_back()
+            .map(|(_, rate)| *rate)
+            .unwrap_or(self.initial)
+    }
+    pub fn change(&mut self, effective: NaiveDate, rate: T) {
+        self.changes.insert(effective, rate);
+    }
+    // forget the history and use `rate` for every date
+    pub fn reset(&mut self, rate: T) {
+        self.initial = rate;
+        self.changes.clear();
+    }
+    // split `range` into the sub-ranges that have a single rate in force
+    pub fn segments(
+        &self,
+        range: RangeInclusive<NaiveDate>,
+    ) -> Vec<(RangeInclusive<NaiveDate>, T)> {
+        let (start, end) = range.into_inner();
+        let mut segments = vec![];
+        let mut from = start;
+        for (&date, _) in self
+            .changes
+            .range((Bound::Excluded(start), Bound::Included(end)))
+        {
+            segments.push((from..=date - Days::new(1), self.at(from)));
+            from = date;
+        }
+        segments.push((from..=end, self.at(from)));
+        segments
+    }
+}
+impl<T: Copy> From<T> for RateHistory<T> {
+    fn from(rate: T) -> Self {
+        Self::new(rate)
+    }
+}
+
+#[cfg(test)]
+mod test_rate_history {
+    use super::*;
+    use crate::test_util::ymd;
+    use payroll_domain::Money;
+
+    #[test]
+    fn test_at() {
+        let mut h = RateHistory::new(Money::from_cents(1525));
+        h.change(ymd(2024, 8, 5), Money::from_cents(1600));
+        assert_eq!(h.at(ymd(2024, 8, 4)), Money::from_cents(1525));
+        assert_eq!(h.at(ymd(2024, 8, 5)), Money::from_cents(1600));
+        h.reset(Money::from_cents(1700));
+        assert_eq!(h.at(ymd(2024, 8, 4)), Money::from_cents(1700));
+        assert!(h.get_changes().is_empty());
+    }
+
+    #[test]
+    fn test_segments() {
+        let mut h = RateHistory::new(1);
+        h.change(ymd(2024, 8, 1), 2);
+        h.change(ymd(2024, 8, 16), 3);
+        assert_eq!(
+            h.segments(ymd(2024, 8, 1)..=ymd(2024, 8, 31)),
+            vec![
+                (ymd(2024, 8, 1)..=ymd(2024, 8, 15), 2),
+                (ymd(2024, 8, 16)..=ymd(2024, 8, 31), 3),
+            ]
+        );
+        assert_eq!(
+            h.segments(ymd(2024, 7, 1)..=ymd(2024, 7, 31)),
+            vec![(ymd(2024, 7, 1)..=ymd(2024, 7, 31), 1)]
+        );
+    }
+}
@@ -7,17 +7,33 @@ use payroll_domain::PaymentSchedule;
 pub enum PaymentScheduleImpl {
     Monthly,
     Weekly,
-    Biweekly,
+    // every 14 days counted from the `anchor` payday
+    Biweekly { anchor: NaiveDate },
+    // the 15th and the last day of every month
+    SemiMonthly,
+    // every `weeks` weeks counted from the `anchor` payday
+    EveryNWeeks { weeks: u32, anchor: NaiveDate },
+}
+impl PaymentScheduleImpl {
+    // Biweekly used to pay on Fridays of even ISO weeks. This anchor gives the
+    // same paydays up to the end of 2026, where the 53rd ISO week broke the parity.
+    pub fn biweekly() -> Self {
+        PaymentScheduleImpl::Biweekly {
+            anchor: NaiveDate::from_ymd_opt(2024, 1, 12).unwrap(),
+        }
+    }
 }
 impl PaymentSchedule for PaymentScheduleImpl {
-    fn is_pay_date(&self, date: NaiveDate) -> bool {
+    fn is_nominal_pay_date(&self, date: NaiveDate) -> bool {
         match self {
-            PaymentScheduleImpl::Monthly => {
-                date.month() != date.checked_add_days(Days::new(1)).unwrap().month()
-            }
+            PaymentScheduleImpl::Monthly => is_last_day_of_month(date),
             PaymentScheduleImpl::Weekly => date.weekday() == Weekday::Fri,
-            PaymentScheduleImpl::Biweekly => {
-                date.weekday() == Weekday::Fri && date.iso_week().week() % 2 == 0
+            PaymentScheduleImpl::Biweekly { anchor } => {
+                (date - *anchor).num_days().rem_euclid(14) == 0
+            }
+            PaymentScheduleImpl::SemiMonthly => date.day() == 15 || is_last_day_of_month(date),
+            PaymentScheduleImpl::EveryNWeeks { weeks, anchor } => {
+                (date - *anchor).num_days().rem_euclid(7 * *weeks as i64) == 0
             }
         }
     }
@@ -26,9 +42,136 @@ impl PaymentSchedule for PaymentScheduleImpl {
         match self {
             PaymentScheduleImpl::Monthly => payday.with_day(1).unwrap()..=payday,
             PaymentScheduleImpl::Weekly => payday.checked_sub_days(Days::new(6)).unwrap()..=payday,
-            PaymentScheduleImpl::Biweekly => {
+            PaymentScheduleImpl::Biweekly { .. } => {
                 payday.checked_sub_days(Days::new(13)).unwrap()..=payday
             }
+            PaymentScheduleImpl::SemiMonthly => {
+                if payday.day() <= 15 {
+                    payday.with_day(1).unwrap()..=payday
+                } else {
+                    payday.with_day(16).unwrap()..=payday
+                }
+            }
+            PaymentScheduleImpl::EveryNWeeks { weeks, .. } => {
+                let days = 7 * *weeks as u64 - 1;
+                payday.checked_sub_days(Days::new(days)).unwrap()..=payday
+            }
+        }
+    }
+
+    fn pay_periods_per_year(&self) -> u32 {
+        match self {
+            PaymentScheduleImpl::Monthly => 12,
+            PaymentScheduleImpl::Weekly => 52,
+            PaymentScheduleImpl::Biweekly { .. } => 26,
+            PaymentScheduleImpl::SemiMonthly => 24,
+            PaymentScheduleImpl::EveryNWeeks { weeks, .. } => (52 + weeks / 2) / weeks,
+        }
+    }
+}
+
+fn is_last_day_of_month(date: NaiveDate) -> bool {
+    date.month() != date.checked_add_days(Days::new(1)).unwrap().month()
+}
+
+#[cfg(test)]
+mod test_payment_schedule {
+    use super::*;
+    use crate::test_util::ymd;
+
+    fn paydays(s: &PaymentScheduleImpl, from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take_while(|d| *d <= to)
+            .filter(|d| s.is_nominal_pay_date(*d))
+            .collect()
+    }
+
+    #[test]
+    fn test_biweekly_keeps_legacy_paydays() {
+        let s = PaymentScheduleImpl::biweekly();
+        for d in ymd(2024, 1, 1).iter_days().take_while(|d| d.year() < 2027) {
+            let legacy = d.weekday() == Weekday::Fri && d.iso_week().week() % 2 == 0;
+            assert_eq!(s.is_nominal_pay_date(d), legacy, "{}", d);
         }
     }
+
+    #[test]
+    fn test_biweekly_across_2026_2027() {
+        // 2026 has 53 ISO weeks, so even-week parity would skip to 2027-01-15
+        let s = PaymentScheduleImpl::biweekly();
+        assert_eq!(
+            paydays(&s, ymd(2026, 12, 1), ymd(2027, 1, 31)),
+            vec![
+                ymd(2026, 12, 11),
+                ymd(2026, 12, 25),
+                ymd(2027, 1, 8),
+                ymd(2027, 1, 22)
+            ]
+        );
+        assert_eq!(
+            s.calculate_period(ymd(2027, 1, 8)),
+            ymd(2026, 12, 26)..=ymd(2027, 1, 8)
+        );
+    }
+
+    #[test]
+    fn test_biweekly_anchor() {
+        let s = PaymentScheduleImpl::Biweekly {
+            anchor: ymd(2027, 1, 15),
+        };
+        assert_eq!(
+            paydays(&s, ymd(2026, 12, 1), ymd(2027, 1, 31)),
+            vec![
+                ymd(2026, 12, 4),
+                ymd(2026, 12, 18),
+                ymd(2027, 1, 1),
+                ymd(2027, 1, 15),
+                ymd(2027, 1, 29)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_semi_monthly() {
+        let s = PaymentScheduleImpl::SemiMonthly;
+        assert_eq!(
+            paydays(&s, ymd(2024, 2, 1), ymd(2024, 3, 31)),
+            vec![
+                ymd(2024, 2, 15),
+                ymd(2024, 2, 29),
+                ymd(2024, 3, 15),
+                ymd(2024, 3, 31)
+            ]
+        );
+        assert_eq!(
+            s.calculate_period(ymd(2024, 2, 15)),
+            ymd(2024, 2, 1)..=ymd(2024, 2, 15)
+        );
+        assert_eq!(
+            s.calculate_period(ymd(2024, 2, 29)),
+            ymd(2024, 2, 16)..=ymd(2024, 2, 29)
+        );
+    }
+
+    #[test]
+    fn test_every_n_weeks() {
+        let s = PaymentScheduleImpl::EveryNWeeks {
+            weeks: 4,
+            anchor: ymd(2024, 8, 2),
+        };
+        assert_eq!(
+            paydays(&s, ymd(2024, 7, 1), ymd(2024, 9, 30)),
+            vec![
+                ymd(2024, 7, 5),
+                ymd(2024, 8, 2),
+                ymd(2024, 8, 30),
+                ymd(2024, 9, 27)
+            ]
+        );
+        assert_eq!(
+            s.calculate_period(ymd(2024, 8, 30)),
+            ymd(2024, 8, 3)..=ymd(2024, 8, 30)
+        );
+        assert_eq!(s.pay_periods_per_year(), 13);
+    }
 }
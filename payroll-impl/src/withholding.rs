@@ -0,0 +1,173 @@
+use std::{any::Any, fs, path::Path, str::FromStr};
+use thiserror::Error;
+
+use payroll_domain::{Deduction, Money, Paycheck, PaymentSchedule, Rate, Withholding};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum TaxTableError {
+    #[error("can't read tax table {0}: {1}")]
+    ReadFailed(String, String),
+    #[error("invalid tax table line {0}: {1}")]
+    InvalidLine(usize, String),
+}
+
+// the marginal rate applied to annual income above `over`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxBracket {
+    over: Money,
+    rate: Rate,
+}
+impl TaxBracket {
+    pub fn new(over: Money, rate: Rate) -> Self {
+        Self { over, rate }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxTable {
+    brackets: Vec<TaxBracket>,
+}
+impl TaxTable {
+    pub fn new(mut brackets: Vec<TaxBracket>) -> Self {
+        brackets.sort_by_key(|b| b.over);
+        Self { brackets }
+    }
+    pub fn get_brackets(&self) -> &[TaxBracket] {
+        &self.brackets
+    }
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, TaxTableError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| TaxTableError::ReadFailed(path.display().to_string(), e.to_string()))?;
+        text.parse()
+    }
+    pub fn annual_tax(&self, income: Money) -> Money {
+        let mut tax = Money::ZERO;
+        for (i, b) in self.brackets.iter().enumerate() {
+            if income <= b.over {
+                break;
+            }
+            let upper = match self.brackets.get(i + 1) {
+                Some(next) if next.over < income => next.over,
+                _ => income,
+            };
+            tax += (upper - b.over) * b.rate;
+        }
+        tax
+    }
+}
+// one bracket per line: "<annual income over> <marginal rate>", '#' starts a comment
+impl FromStr for TaxTable {
+    type Err = TaxTableError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut brackets = vec![];
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = || TaxTableError::InvalidLine(i + 1, line.to_string());
+            let mut cols = line.split_whitespace();
+            let (Some(over), Some(rate), None) = (cols.next(), cols.next(), cols.next()) else {
+                return Err(invalid());
+            };
+            let over = over.parse::<Money>().map_err(|_| invalid())?;
+            let rate = rate.parse::<Rate>().map_err(|_| invalid())?;
+            brackets.push(TaxBracket::new(over, rate));
+        }
+        Ok(Self::new(brackets))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WithholdingImpl {
+    Exempt,
+    Table { table: TaxTable },
+}
+impl Withholding for WithholdingImpl {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    // annualize the gross pay, look up the tax, then spread it back over the year's periods
+    fn calculate_withholding(
+        &self,
+        pc: &Paycheck,
+        schedule: &dyn PaymentSchedule,
+    ) -> Vec<Deduction> {
+        match self {
+            WithholdingImpl::Exempt => vec![],
+            WithholdingImpl::Table { table } => {
+                let periods = schedule.pay_periods_per_year() as i64;
+                let annual = pc.get_gross_pay().prorate(periods, 1);
+                let amount = table.annual_tax(annual).prorate(1, periods);
+                if amount.is_zero() {
+                    return vec![];
+                }
+                vec![Deduction::Withholding { amount }]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_tax_table {
+    use super::*;
+    use crate::PaymentScheduleImpl;
+    use payroll_domain::Earning;
+
+    fn table() -> TaxTable {
+        "# over  rate
+         0       0.05
+         10000   0.10  # second bracket
+         40000   0.20"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(table().get_brackets().len(), 3);
+        assert_eq!(
+            "0 0.05 extra".parse::<TaxTable>(),
+            Err(TaxTableError::InvalidLine(1, "0 0.05 extra".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_annual_tax() {
+        let t = table();
+        assert_eq!(t.annual_tax(Money::ZERO), Money::ZERO);
+        assert_eq!(
+            t.annual_tax(Money::from_cents(800000)),
+            Money::from_cents(40000)
+        );
+        // 500 + 3000 + 2000
+        assert_eq!(
+            t.annual_tax(Money::from_cents(5000000)),
+            Money::from_cents(550000)
+        );
+    }
+
+    #[test]
+    fn test_withholding_per_period() {
+        let mut pc = Paycheck::new(
+            chrono::NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                ..=chrono::NaiveDate::from_ymd_opt(2024, 8, 31).unwrap(),
+        );
+        pc.add_earnings(vec![Earning::Salary {
+            amount: Money::from_cents(300000),
+        }]);
+        let w = WithholdingImpl::Table { table: table() };
+        // 36000 a year: 500 + 2600 = 3100, spread over 12 months
+        assert_eq!(
+            w.calculate_withholding(&pc, &PaymentScheduleImpl::Monthly),
+            vec![Deduction::Withholding {
+                amount: Money::from_cents(25833)
+            }]
+        );
+        assert!(WithholdingImpl::Exempt
+            .calculate_withholding(&pc, &PaymentScheduleImpl::Monthly)
+            .is_empty());
+    }
+}
@@ -1,6 +1,10 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
+use std::ops::RangeInclusive;
 
-use payroll_domain::EmployeeId;
+use payroll_domain::{
+    CommissionPlan, EmployeeId, GarnishmentAmount, Hours, LateEntryPolicy, LoanRepayment, Money,
+    OvertimePolicy, PtoAccrual, Rate, ShiftDifferential,
+};
 use tx_app::Transaction;
 use tx_factory::TransactionFactory;
 
@@ -10,38 +14,102 @@ pub enum Command {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        salary: f32,
+        salary: Money,
+        hire_date: Option<NaiveDate>,
     },
     AddHourlyEmp {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        hire_date: Option<NaiveDate>,
     },
     AddCommissionedEmp {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        hire_date: Option<NaiveDate>,
     },
     DelEmp {
         emp_id: EmployeeId,
     },
+    Terminate {
+        emp_id: EmployeeId,
+        last_day: NaiveDate,
+    },
     TimeCard {
         emp_id: EmployeeId,
         date: NaiveDate,
-        hours: f32,
+        hours: Hours,
+    },
+    ClockTimeCard {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        clock_in: NaiveTime,
+        clock_out: NaiveTime,
+    },
+    Leave {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        hours: Hours,
+    },
+    AmendTimeCard {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        hours: Hours,
+    },
+    DelTimeCard {
+        emp_id: EmployeeId,
+        date: NaiveDate,
     },
     SalesReceipt {
         emp_id: EmployeeId,
         date: NaiveDate,
-        amount: f32,
+        amount: Money,
+        product: Option<String>,
+    },
+    AmendSalesReceipt {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        amount: Money,
+    },
+    DelSalesReceipt {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+    },
+    Bonus {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    },
+    Allowance {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    },
+    Reimbursement {
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: String,
+        amount: Money,
     },
     ServiceCharge {
         member_id: EmployeeId,
         date: NaiveDate,
-        amount: f32,
+        amount: Money,
+    },
+    AmendServiceCharge {
+        member_id: EmployeeId,
+        date: NaiveDate,
+        amount: Money,
+    },
+    DelServiceCharge {
+        member_id: EmployeeId,
+        date: NaiveDate,
     },
     ChgName {
         emp_id: EmployeeId,
@@ -53,16 +121,37 @@ pub enum Command {
     },
     ChgHourly {
         emp_id: EmployeeId,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        effective: Option<NaiveDate>,
     },
     ChgSalaried {
         emp_id: EmployeeId,
-        salary: f32,
+        salary: Money,
+        effective: Option<NaiveDate>,
     },
     ChgCommissioned {
         emp_id: EmployeeId,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        effective: Option<NaiveDate>,
+    },
+    CommissionPlan {
+        plan: CommissionPlan,
+    },
+    ChgCommissionPlan {
+        emp_id: EmployeeId,
+        name: String,
+    },
+    ChgNoCommissionPlan {
+        emp_id: EmployeeId,
+    },
+    ChgOvertime {
+        emp_id: EmployeeId,
+        policy: OvertimePolicy,
+    },
+    ChgShiftDifferential {
+        emp_id: EmployeeId,
+        differential: ShiftDifferential,
     },
     ChgHold {
         emp_id: EmployeeId,
@@ -76,16 +165,124 @@ pub enum Command {
         emp_id: EmployeeId,
         address: String,
     },
+    ChgBiweekly {
+        emp_id: EmployeeId,
+        anchor: NaiveDate,
+    },
+    ChgSemiMonthly {
+        emp_id: EmployeeId,
+    },
+    ChgEveryNWeeks {
+        emp_id: EmployeeId,
+        weeks: u32,
+        anchor: NaiveDate,
+    },
     ChgMember {
         emp_id: EmployeeId,
         member_id: EmployeeId,
-        dues: f32,
+        dues: Money,
     },
     ChgNoMember {
         emp_id: EmployeeId,
     },
+    ChgHealthPremium {
+        emp_id: EmployeeId,
+        premium: Money,
+    },
+    ChgNoHealthPremium {
+        emp_id: EmployeeId,
+    },
+    ChgSavingsPlan {
+        emp_id: EmployeeId,
+        rate: Rate,
+    },
+    ChgNoSavingsPlan {
+        emp_id: EmployeeId,
+    },
+    ChgGarnishment {
+        emp_id: EmployeeId,
+        case: String,
+        amount: GarnishmentAmount,
+        max_per_period: Option<Money>,
+        balance: Money,
+        ahead_of_union_dues: bool,
+    },
+    ChgNoGarnishment {
+        emp_id: EmployeeId,
+        case: String,
+    },
+    ChgLoan {
+        emp_id: EmployeeId,
+        name: String,
+        principal: Money,
+        repayment: LoanRepayment,
+    },
+    ListLoans {
+        emp_id: EmployeeId,
+    },
+    ChgPto {
+        emp_id: EmployeeId,
+        accrual: PtoAccrual,
+        balance: Option<Hours>,
+    },
+    ChgTaxTable {
+        emp_id: EmployeeId,
+        path: String,
+    },
+    JapaneseCalendar,
+    LoadHolidayCalendar {
+        path: String,
+    },
+    LateEntries {
+        policy: LateEntryPolicy,
+    },
+    // one of the ChgEmp commands for name, address, payment method or union membership
+    Scheduled {
+        effective: NaiveDate,
+        command: Box<Command>,
+    },
+    CancelScheduled {
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    },
+    ListScheduled,
+    AdvanceDate {
+        date: NaiveDate,
+    },
+    CalculatePayroll {
+        pay_date: NaiveDate,
+        rerun: bool,
+    },
+    ReviewPayroll {
+        pay_date: NaiveDate,
+    },
+    ApprovePayroll {
+        pay_date: NaiveDate,
+    },
+    DiscardPayroll {
+        pay_date: NaiveDate,
+    },
+    VoidPaycheck {
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        off_cycle: bool,
+    },
+    VoidPayrollRun {
+        pay_date: NaiveDate,
+    },
+    OffCyclePaycheck {
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        period: RangeInclusive<NaiveDate>,
+        extras: Vec<(String, Money)>,
+    },
+    RetroPay {
+        emp_id: EmployeeId,
+        range: RangeInclusive<NaiveDate>,
+    },
     Payday {
         pay_date: NaiveDate,
+        rerun: bool,
     },
 }
 impl Command {
@@ -99,58 +296,137 @@ impl Command {
                 name,
                 address,
                 salary,
-            } => tx_factory.mk_add_salary_employee_tx(emp_id, name, address, salary),
+                hire_date,
+            } => tx_factory.mk_add_salary_employee_tx(emp_id, name, address, salary, hire_date),
             Command::AddHourlyEmp {
                 emp_id,
                 name,
                 address,
                 hourly_rate,
-            } => tx_factory.mk_add_hourly_employee_tx(emp_id, name, address, hourly_rate),
+                hire_date,
+            } => {
+                tx_factory.mk_add_hourly_employee_tx(emp_id, name, address, hourly_rate, hire_date)
+            }
             Command::AddCommissionedEmp {
                 emp_id,
                 name,
                 address,
                 salary,
                 commission_rate,
+                hire_date,
             } => tx_factory.mk_add_commissioned_employee_tx(
                 emp_id,
                 name,
                 address,
                 salary,
                 commission_rate,
+                hire_date,
             ),
             Command::DelEmp { emp_id } => tx_factory.mk_delete_employee_tx(emp_id),
+            Command::Terminate { emp_id, last_day } => {
+                tx_factory.mk_terminate_employee_tx(emp_id, last_day)
+            }
             Command::TimeCard {
                 emp_id,
                 date,
                 hours,
             } => tx_factory.mk_timecard_tx(emp_id, date, hours),
+            Command::AmendTimeCard {
+                emp_id,
+                date,
+                hours,
+            } => tx_factory.mk_amend_timecard_tx(emp_id, date, hours),
+            Command::DelTimeCard { emp_id, date } => tx_factory.mk_delete_timecard_tx(emp_id, date),
+            Command::ClockTimeCard {
+                emp_id,
+                date,
+                clock_in,
+                clock_out,
+            } => tx_factory.mk_clock_timecard_tx(emp_id, date, clock_in, clock_out),
+            Command::Leave {
+                emp_id,
+                date,
+                hours,
+            } => tx_factory.mk_leave_tx(emp_id, date, hours),
             Command::SalesReceipt {
                 emp_id,
                 date,
                 amount,
-            } => tx_factory.mk_sales_receipt_tx(emp_id, date, amount),
+                product,
+            } => tx_factory.mk_sales_receipt_tx(emp_id, date, amount, product),
+            Command::AmendSalesReceipt {
+                emp_id,
+                date,
+                amount,
+            } => tx_factory.mk_amend_sales_receipt_tx(emp_id, date, amount),
+            Command::DelSalesReceipt { emp_id, date } => {
+                tx_factory.mk_delete_sales_receipt_tx(emp_id, date)
+            }
+            Command::Bonus {
+                emp_id,
+                date,
+                description,
+                amount,
+            } => tx_factory.mk_add_bonus_tx(emp_id, date, description, amount),
+            Command::Allowance {
+                emp_id,
+                date,
+                description,
+                amount,
+            } => tx_factory.mk_add_allowance_tx(emp_id, date, description, amount),
+            Command::Reimbursement {
+                emp_id,
+                date,
+                description,
+                amount,
+            } => tx_factory.mk_reimbursement_tx(emp_id, date, description, amount),
             Command::ServiceCharge {
                 member_id,
                 date,
                 amount,
             } => tx_factory.mk_service_charge_tx(member_id, date, amount),
+            Command::AmendServiceCharge {
+                member_id,
+                date,
+                amount,
+            } => tx_factory.mk_amend_service_charge_tx(member_id, date, amount),
+            Command::DelServiceCharge { member_id, date } => {
+                tx_factory.mk_delete_service_charge_tx(member_id, date)
+            }
             Command::ChgName { emp_id, name } => tx_factory.mk_change_name_tx(emp_id, name),
             Command::ChgAddress { emp_id, address } => {
                 tx_factory.mk_change_address_tx(emp_id, address)
             }
-            Command::ChgSalaried { emp_id, salary } => {
-                tx_factory.mk_change_salaried_tx(emp_id, salary)
-            }
+            Command::ChgSalaried {
+                emp_id,
+                salary,
+                effective,
+            } => tx_factory.mk_change_salaried_tx(emp_id, salary, effective),
             Command::ChgHourly {
                 emp_id,
                 hourly_rate,
-            } => tx_factory.mk_change_hourly_tx(emp_id, hourly_rate),
+                effective,
+            } => tx_factory.mk_change_hourly_tx(emp_id, hourly_rate, effective),
             Command::ChgCommissioned {
                 emp_id,
                 salary,
                 commission_rate,
-            } => tx_factory.mk_change_commissioned_tx(emp_id, salary, commission_rate),
+                effective,
+            } => tx_factory.mk_change_commissioned_tx(emp_id, salary, commission_rate, effective),
+            Command::CommissionPlan { plan } => tx_factory.mk_define_commission_plan_tx(plan),
+            Command::ChgCommissionPlan { emp_id, name } => {
+                tx_factory.mk_assign_commission_plan_tx(emp_id, name)
+            }
+            Command::ChgNoCommissionPlan { emp_id } => {
+                tx_factory.mk_unassign_commission_plan_tx(emp_id)
+            }
+            Command::ChgOvertime { emp_id, policy } => {
+                tx_factory.mk_change_overtime_tx(emp_id, policy)
+            }
+            Command::ChgShiftDifferential {
+                emp_id,
+                differential,
+            } => tx_factory.mk_change_shift_differential_tx(emp_id, differential),
             Command::ChgHold { emp_id } => tx_factory.mk_change_hold_tx(emp_id),
             Command::ChgDirect {
                 emp_id,
@@ -158,13 +434,115 @@ impl Command {
                 account,
             } => tx_factory.mk_change_direct_tx(emp_id, bank, account),
             Command::ChgMail { emp_id, address } => tx_factory.mk_change_mail_tx(emp_id, address),
+            Command::ChgBiweekly { emp_id, anchor } => {
+                tx_factory.mk_change_biweekly_tx(emp_id, anchor)
+            }
+            Command::ChgSemiMonthly { emp_id } => tx_factory.mk_change_semi_monthly_tx(emp_id),
+            Command::ChgEveryNWeeks {
+                emp_id,
+                weeks,
+                anchor,
+            } => tx_factory.mk_change_every_n_weeks_tx(emp_id, weeks, anchor),
             Command::ChgMember {
                 emp_id,
                 member_id,
                 dues,
             } => tx_factory.mk_change_union_member_tx(emp_id, member_id, dues),
             Command::ChgNoMember { emp_id } => tx_factory.mk_change_unaffiliated_tx(emp_id),
-            Command::Payday { pay_date } => tx_factory.mk_payday_tx(pay_date),
+            Command::ChgHealthPremium { emp_id, premium } => {
+                tx_factory.mk_add_health_premium_tx(emp_id, premium)
+            }
+            Command::ChgNoHealthPremium { emp_id } => {
+                tx_factory.mk_remove_health_premium_tx(emp_id)
+            }
+            Command::ChgSavingsPlan { emp_id, rate } => {
+                tx_factory.mk_add_savings_plan_tx(emp_id, rate)
+            }
+            Command::ChgNoSavingsPlan { emp_id } => tx_factory.mk_remove_savings_plan_tx(emp_id),
+            Command::ChgGarnishment {
+                emp_id,
+                case,
+                amount,
+                max_per_period,
+                balance,
+                ahead_of_union_dues,
+            } => tx_factory.mk_add_garnishment_tx(
+                emp_id,
+                case,
+                amount,
+                max_per_period,
+                balance,
+                ahead_of_union_dues,
+            ),
+            Command::ChgNoGarnishment { emp_id, case } => {
+                tx_factory.mk_remove_garnishment_tx(emp_id, case)
+            }
+            Command::ChgLoan {
+                emp_id,
+                name,
+                principal,
+                repayment,
+            } => tx_factory.mk_add_loan_tx(emp_id, name, principal, repayment),
+            Command::ListLoans { emp_id } => tx_factory.mk_list_loans_tx(emp_id),
+            Command::ChgPto {
+                emp_id,
+                accrual,
+                balance,
+            } => tx_factory.mk_change_pto_tx(emp_id, accrual, balance),
+            Command::ChgTaxTable { emp_id, path } => {
+                tx_factory.mk_change_tax_table_tx(emp_id, path)
+            }
+            Command::JapaneseCalendar => tx_factory.mk_change_japanese_calendar_tx(),
+            Command::LoadHolidayCalendar { path } => tx_factory.mk_load_holiday_calendar_tx(path),
+            Command::LateEntries { policy } => tx_factory.mk_change_late_entry_policy_tx(policy),
+            Command::Scheduled { effective, command } => match *command {
+                Command::ChgName { emp_id, name } => {
+                    tx_factory.mk_schedule_name_tx(emp_id, effective, name)
+                }
+                Command::ChgAddress { emp_id, address } => {
+                    tx_factory.mk_schedule_address_tx(emp_id, effective, address)
+                }
+                Command::ChgHold { emp_id } => tx_factory.mk_schedule_hold_tx(emp_id, effective),
+                Command::ChgDirect {
+                    emp_id,
+                    bank,
+                    account,
+                } => tx_factory.mk_schedule_direct_tx(emp_id, effective, bank, account),
+                Command::ChgMail { emp_id, address } => {
+                    tx_factory.mk_schedule_mail_tx(emp_id, effective, address)
+                }
+                Command::ChgMember {
+                    emp_id,
+                    member_id,
+                    dues,
+                } => tx_factory.mk_schedule_union_member_tx(emp_id, effective, member_id, dues),
+                command => unreachable!("can't schedule {:?}", command),
+            },
+            Command::CancelScheduled { emp_id, effective } => {
+                tx_factory.mk_cancel_scheduled_changes_tx(emp_id, effective)
+            }
+            Command::ListScheduled => tx_factory.mk_list_scheduled_changes_tx(),
+            Command::AdvanceDate { date } => tx_factory.mk_advance_date_tx(date),
+            Command::CalculatePayroll { pay_date, rerun } => {
+                tx_factory.mk_calculate_payroll_tx(pay_date, rerun)
+            }
+            Command::ReviewPayroll { pay_date } => tx_factory.mk_review_payroll_tx(pay_date),
+            Command::ApprovePayroll { pay_date } => tx_factory.mk_approve_payroll_tx(pay_date),
+            Command::DiscardPayroll { pay_date } => tx_factory.mk_discard_payroll_tx(pay_date),
+            Command::VoidPaycheck {
+                emp_id,
+                pay_date,
+                off_cycle,
+            } => tx_factory.mk_void_paycheck_tx(emp_id, pay_date, off_cycle),
+            Command::VoidPayrollRun { pay_date } => tx_factory.mk_void_payroll_run_tx(pay_date),
+            Command::OffCyclePaycheck {
+                emp_id,
+                pay_date,
+                period,
+                extras,
+            } => tx_factory.mk_off_cycle_paycheck_tx(emp_id, pay_date, period, extras),
+            Command::RetroPay { emp_id, range } => tx_factory.mk_retro_pay_tx(emp_id, range),
+            Command::Payday { pay_date, rerun } => tx_factory.mk_payday_tx(pay_date, rerun),
         }
     }
 }
@@ -1,8 +1,12 @@
-use chrono::NaiveDate;
-use parsec_rs::{char, float32, int32, keyword, pred, spaces, string, uint32, Parser};
+use chrono::{NaiveDate, NaiveTime};
+use parsec_rs::{char, int32, keyword, pred, spaces, string, uint32, Parser};
 use std::collections::VecDeque;
 
 use crate::command::Command;
+use payroll_domain::{
+    CommissionPlan, CommissionTier, GarnishmentAmount, Hours, LateEntryPolicy, LoanRepayment,
+    Money, OvertimePolicy, OvertimeRule, PtoAccrual, Rate, ShiftDifferential, ShiftWindow,
+};
 
 pub fn read_commands(script: &str) -> VecDeque<Command> {
     let commands: VecDeque<Command> = transactions()
@@ -22,19 +26,64 @@ pub fn transaction() -> impl Parser<Item = Command> {
             .or(add_hourly_emp())
             .or(add_commissioned_emp())
             .or(del_emp())
+            .or(terminate())
+            .or(clock_time_card())
             .or(time_card())
+            .or(amend_time_card())
+            .or(del_time_card())
+            .or(leave())
             .or(sales_receipt())
+            .or(amend_sales_receipt())
+            .or(del_sales_receipt())
+            .or(bonus())
+            .or(allowance())
+            .or(reimbursement())
             .or(service_charge())
+            .or(amend_service_charge())
+            .or(del_service_charge())
             .or(chg_name())
             .or(chg_address())
             .or(chg_hourly())
             .or(chg_salaried())
             .or(chg_commissioned())
+            .or(commission_plan())
+            .or(chg_commission_plan())
+            .or(chg_no_commission_plan())
+            .or(chg_overtime())
+            .or(chg_shift_differential())
             .or(chg_hold())
             .or(chg_direct())
             .or(chg_mail())
+            .or(chg_biweekly())
+            .or(chg_semi_monthly())
+            .or(chg_every_n_weeks())
             .or(chg_member())
             .or(chg_no_member())
+            .or(chg_health_premium())
+            .or(chg_no_health_premium())
+            .or(chg_savings_plan())
+            .or(chg_no_savings_plan())
+            .or(chg_garnishment())
+            .or(chg_no_garnishment())
+            .or(chg_loan())
+            .or(list_loans())
+            .or(chg_pto())
+            .or(chg_tax_table())
+            .or(japanese_calendar())
+            .or(load_holiday_calendar())
+            .or(late_entries())
+            .or(scheduled())
+            .or(cancel_scheduled())
+            .or(list_scheduled())
+            .or(advance_date())
+            .or(calculate_payroll())
+            .or(review_payroll())
+            .or(approve_payroll())
+            .or(discard_payroll())
+            .or(void_payroll_run())
+            .or(void_paycheck())
+            .or(off_cycle_paycheck())
+            .or(retro_pay())
             .or(payday()),
     )
 }
@@ -81,7 +130,8 @@ mod test_transaction {
                     emp_id: 42,
                     name: "Bob".to_string(),
                     address: "Home".to_string(),
-                    salary: 1000.0
+                    salary: Money::from_cents(100000),
+                    hire_date: None
                 },
                 ""
             ))
@@ -98,7 +148,8 @@ mod test_transaction {
                     emp_id: 42,
                     name: "Bob".to_string(),
                     address: "Home".to_string(),
-                    hourly_rate: 1000.0
+                    hourly_rate: Money::from_cents(100000),
+                    hire_date: None
                 },
                 ""
             ))
@@ -115,8 +166,27 @@ mod test_transaction {
                     emp_id: 42,
                     name: "Bob".to_string(),
                     address: "Home".to_string(),
-                    salary: 1000.0,
-                    commission_rate: 0.1
+                    salary: Money::from_cents(100000),
+                    commission_rate: Rate::from_basis_points(1000),
+                    hire_date: None
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_add_emp_hired() {
+        let input = r#"AddEmp 42 "Bob" "Home" S 1000.0 Hired 2024-08-20"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AddSalaryEmp {
+                    emp_id: 42,
+                    name: "Bob".to_string(),
+                    address: "Home".to_string(),
+                    salary: Money::from_cents(100000),
+                    hire_date: Some(NaiveDate::from_ymd_opt(2024, 8, 20).unwrap())
                 },
                 ""
             ))
@@ -129,6 +199,21 @@ mod test_transaction {
         assert_eq!(result, Ok((Command::DelEmp { emp_id: 42 }, "")));
     }
     #[test]
+    fn test_terminate() {
+        let input = r#"Terminate 42 2024-08-23"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Terminate {
+                    emp_id: 42,
+                    last_day: NaiveDate::from_ymd_opt(2024, 8, 23).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
     fn test_time_card() {
         let input = r#"TimeCard 42 2021-01-01 8.0"#;
         let result = transaction().parse(input);
@@ -138,7 +223,71 @@ mod test_transaction {
                 Command::TimeCard {
                     emp_id: 42,
                     date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
-                    hours: 8.0
+                    hours: Hours::from_hours(8)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_clock_time_card() {
+        let input = r#"TimeCard 42 2024-08-06 22:00 06:30"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ClockTimeCard {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 6).unwrap(),
+                    clock_in: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                    clock_out: NaiveTime::from_hms_opt(6, 30, 0).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_amend_time_card() {
+        let input = r#"AmendTimeCard 42 2024-08-01 8.0"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AmendTimeCard {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    hours: Hours::from_hours(8)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_del_time_card() {
+        let input = r#"DelTimeCard 42 2024-08-01"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DelTimeCard {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_leave() {
+        let input = r#"Leave 42 2024-08-08 4.0"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Leave {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 8).unwrap(),
+                    hours: Hours::from_hours(4)
                 },
                 ""
             ))
@@ -154,7 +303,90 @@ mod test_transaction {
                 Command::SalesReceipt {
                     emp_id: 42,
                     date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
-                    amount: 1000.0
+                    amount: Money::from_cents(100000),
+                    product: None
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_amend_sales_receipt() {
+        let input = r#"AmendSalesReceipt 42 2024-08-01 850.50"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AmendSalesReceipt {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    amount: Money::from_cents(85050)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_del_sales_receipt() {
+        let input = r#"DelSalesReceipt 42 2024-08-01"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DelSalesReceipt {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_bonus() {
+        let input = r#"Bonus 42 2024-08-15 "Summer bonus" 1000.00"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Bonus {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 15).unwrap(),
+                    description: "Summer bonus".to_string(),
+                    amount: Money::from_cents(100000)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_allowance() {
+        let input = r#"Allowance 42 2024-08-15 "Commuting" 85.50"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Allowance {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 15).unwrap(),
+                    description: "Commuting".to_string(),
+                    amount: Money::from_cents(8550)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_reimbursement() {
+        let input = r#"Reimbursement 42 2024-08-15 "Taxi" 32.80"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Reimbursement {
+                    emp_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 15).unwrap(),
+                    description: "Taxi".to_string(),
+                    amount: Money::from_cents(3280)
                 },
                 ""
             ))
@@ -170,7 +402,38 @@ mod test_transaction {
                 Command::ServiceCharge {
                     member_id: 42,
                     date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
-                    amount: 1000.0
+                    amount: Money::from_cents(100000)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_amend_service_charge() {
+        let input = r#"AmendServiceCharge 42 2024-08-01 12.95"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AmendServiceCharge {
+                    member_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    amount: Money::from_cents(1295)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_del_service_charge() {
+        let input = r#"DelServiceCharge 42 2024-08-01"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DelServiceCharge {
+                    member_id: 42,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
                 },
                 ""
             ))
@@ -215,7 +478,8 @@ mod test_transaction {
             Ok((
                 Command::ChgHourly {
                     emp_id: 42,
-                    hourly_rate: 1000.0
+                    hourly_rate: Money::from_cents(100000),
+                    effective: None
                 },
                 ""
             ))
@@ -230,7 +494,24 @@ mod test_transaction {
             Ok((
                 Command::ChgSalaried {
                     emp_id: 42,
-                    salary: 1000.0
+                    salary: Money::from_cents(100000),
+                    effective: None
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_chg_salaried_effective() {
+        let input = r#"ChgEmp 42 Salaried 1100.0 Effective 2024-08-21"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgSalaried {
+                    emp_id: 42,
+                    salary: Money::from_cents(110000),
+                    effective: Some(NaiveDate::from_ymd_opt(2024, 8, 21).unwrap())
                 },
                 ""
             ))
@@ -245,8 +526,101 @@ mod test_transaction {
             Ok((
                 Command::ChgCommissioned {
                     emp_id: 42,
-                    salary: 1000.0,
-                    commission_rate: 0.1
+                    salary: Money::from_cents(100000),
+                    commission_rate: Rate::from_basis_points(1000),
+                    effective: None
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_commission_plan() {
+        let input = r#"CommissionPlan "Tiered" Tier 0 0.05 Tier 10000.00 0.08 Cap 2000.00"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::CommissionPlan {
+                    plan: CommissionPlan::new(
+                        "Tiered",
+                        vec![
+                            CommissionTier::new(Money::ZERO, Rate::from_basis_points(500)),
+                            CommissionTier::new(
+                                Money::from_cents(1000000),
+                                Rate::from_basis_points(800)
+                            ),
+                        ],
+                        vec![],
+                        Some(Money::from_cents(200000))
+                    )
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_chg_commission_plan() {
+        let input = r#"ChgEmp 42 CommissionPlan "Tiered""#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgCommissionPlan {
+                    emp_id: 42,
+                    name: "Tiered".to_string()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_chg_no_commission_plan() {
+        let input = r#"ChgEmp 42 NoCommissionPlan"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((Command::ChgNoCommissionPlan { emp_id: 42 }, ""))
+        );
+    }
+    #[test]
+    fn test_chg_overtime() {
+        let input = r#"ChgEmp 42 Overtime Daily 8.0 1.5 Weekly 40.0 1.5"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgOvertime {
+                    emp_id: 42,
+                    policy: OvertimePolicy::new(vec![
+                        OvertimeRule::Daily {
+                            threshold: Hours::from_hours(8),
+                            multiplier: Rate::from_basis_points(15000)
+                        },
+                        OvertimeRule::Weekly {
+                            threshold: Hours::from_hours(40),
+                            multiplier: Rate::from_basis_points(15000)
+                        },
+                    ])
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_chg_shift_differential() {
+        let input = r#"ChgEmp 42 ShiftDiff 22:00 06:00 1.50"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgShiftDifferential {
+                    emp_id: 42,
+                    differential: ShiftDifferential::new(vec![ShiftWindow::new(
+                        NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                        NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                        Money::from_cents(150)
+                    )])
                 },
                 ""
             ))
@@ -290,6 +664,43 @@ mod test_transaction {
         );
     }
     #[test]
+    fn test_chg_biweekly() {
+        let input = r#"ChgEmp 42 Biweekly From 2027-01-08"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgBiweekly {
+                    emp_id: 42,
+                    anchor: NaiveDate::from_ymd_opt(2027, 1, 8).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_chg_semi_monthly() {
+        let input = r#"ChgEmp 42 SemiMonthly"#;
+        let result = transaction().parse(input);
+        assert_eq!(result, Ok((Command::ChgSemiMonthly { emp_id: 42 }, "")));
+    }
+    #[test]
+    fn test_chg_every_n_weeks() {
+        let input = r#"ChgEmp 42 Every 4 Weeks From 2024-08-02"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgEveryNWeeks {
+                    emp_id: 42,
+                    weeks: 4,
+                    anchor: NaiveDate::from_ymd_opt(2024, 8, 2).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
     fn test_chg_member() {
         let input = r#"ChgEmp 42 Member 7234 Dues 9.45"#;
         let result = transaction().parse(input);
@@ -299,7 +710,7 @@ mod test_transaction {
                 Command::ChgMember {
                     emp_id: 42,
                     member_id: 7234,
-                    dues: 9.45,
+                    dues: Money::from_cents(945),
                 },
                 "",
             ))
@@ -311,54 +722,2177 @@ mod test_transaction {
         let result = transaction().parse(input);
         assert_eq!(result, Ok((Command::ChgNoMember { emp_id: 42 }, "")));
     }
-}
-
-fn go_through() -> impl Parser<Item = ()> {
-    let comment = char('#').skip(pred(|c| c != '\n').many0().with(char('\n')));
-    let space_comment = spaces().skip(comment).map(|_| ());
-    let ignore = space_comment.many1().map(|_| ()).or(spaces().map(|_| ()));
-
-    spaces().skip(ignore).skip(spaces()).map(|_| ())
-}
-
-fn add_salary_emp() -> impl Parser<Item = Command> {
-    let prefix = keyword("AddEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let name = string().with(spaces());
-    let address = string().with(spaces());
-    let monthly_rate = char('S').skip(spaces()).skip(float32());
-
-    prefix
-        .skip(emp_id)
-        .join(name)
-        .join(address)
-        .join(monthly_rate)
-        .map(
-            |(((emp_id, name), address), salary)| Command::AddSalaryEmp {
-                emp_id,
-                name,
+    #[test]
+    fn test_health_premium() {
+        let input = r#"ChgEmp 42 HealthPremium 25.50"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgHealthPremium {
+                    emp_id: 42,
+                    premium: Money::from_cents(2550)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_no_health_premium() {
+        let input = r#"ChgEmp 42 NoHealthPremium"#;
+        let result = transaction().parse(input);
+        assert_eq!(result, Ok((Command::ChgNoHealthPremium { emp_id: 42 }, "")));
+    }
+    #[test]
+    fn test_savings_plan() {
+        let input = r#"ChgEmp 42 SavingsPlan 0.05"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgSavingsPlan {
+                    emp_id: 42,
+                    rate: Rate::from_basis_points(500)
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_no_savings_plan() {
+        let input = r#"ChgEmp 42 NoSavingsPlan"#;
+        let result = transaction().parse(input);
+        assert_eq!(result, Ok((Command::ChgNoSavingsPlan { emp_id: 42 }, "")));
+    }
+    #[test]
+    fn test_garnishment() {
+        let input = r#"ChgEmp 42 Garnish "CS-1234" Fixed 100.00 Balance 1200.00 Max 150.00"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgGarnishment {
+                    emp_id: 42,
+                    case: "CS-1234".to_string(),
+                    amount: GarnishmentAmount::Fixed {
+                        amount: Money::from_cents(10000)
+                    },
+                    max_per_period: Some(Money::from_cents(15000)),
+                    balance: Money::from_cents(120000),
+                    ahead_of_union_dues: false,
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_no_garnishment() {
+        let input = r#"ChgEmp 42 NoGarnish "CS-1234""#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgNoGarnishment {
+                    emp_id: 42,
+                    case: "CS-1234".to_string()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_loan() {
+        let input = r#"ChgEmp 42 Loan "Advance" 100.00 Installments 3"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgLoan {
+                    emp_id: 42,
+                    name: "Advance".to_string(),
+                    principal: Money::from_cents(10000),
+                    repayment: LoanRepayment::Count { count: 3 },
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_list_loans() {
+        let input = r#"Loans 42"#;
+        let result = transaction().parse(input);
+        assert_eq!(result, Ok((Command::ListLoans { emp_id: 42 }, "")));
+    }
+    #[test]
+    fn test_pto() {
+        let input = r#"ChgEmp 42 Pto PerPeriod 4.0 Balance 8.0"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgPto {
+                    emp_id: 42,
+                    accrual: PtoAccrual::PerPeriod {
+                        hours: Hours::from_hours(4)
+                    },
+                    balance: Some(Hours::from_hours(8)),
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_japanese_calendar() {
+        let input = r#"HolidayCalendar Japan"#;
+        let result = transaction().parse(input);
+        assert_eq!(result, Ok((Command::JapaneseCalendar, "")));
+    }
+    #[test]
+    fn test_late_entries() {
+        let input = r#"LateEntries Carry"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::LateEntries {
+                    policy: LateEntryPolicy::CarryForward
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_load_holiday_calendar() {
+        let input = r#"HolidayCalendar "script/holidays.txt""#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::LoadHolidayCalendar {
+                    path: "script/holidays.txt".to_string()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_tax_table() {
+        let input = r#"ChgEmp 42 TaxTable "script/tax_table.txt""#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgTaxTable {
+                    emp_id: 42,
+                    path: "script/tax_table.txt".to_string()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_scheduled() {
+        let input = r#"On 2024-09-01 ChgEmp 42 Member 7234 Dues 9.45"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Scheduled {
+                    effective: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+                    command: Box::new(Command::ChgMember {
+                        emp_id: 42,
+                        member_id: 7234,
+                        dues: Money::from_cents(945)
+                    })
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_cancel_scheduled() {
+        let input = r#"CancelChange 42 2024-09-01"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::CancelScheduled {
+                    emp_id: 42,
+                    effective: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_list_scheduled() {
+        let input = r#"ListChanges"#;
+        let result = transaction().parse(input);
+        assert_eq!(result, Ok((Command::ListScheduled, "")));
+    }
+    #[test]
+    fn test_payday_rerun() {
+        let input = r#"Payday 2024-08-09 Rerun"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Payday {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                    rerun: true
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_calculate_payroll() {
+        let input = r#"Calculate 2024-08-09 Rerun"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::CalculatePayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                    rerun: true
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_review_payroll() {
+        let input = r#"Review 2024-08-09"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ReviewPayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_approve_payroll() {
+        let input = r#"Approve 2024-08-09"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ApprovePayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_discard_payroll() {
+        let input = r#"Discard 2024-08-09"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DiscardPayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_void_paycheck() {
+        let input = r#"Void 42 2024-08-09"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::VoidPaycheck {
+                    emp_id: 42,
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                    off_cycle: false,
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_void_payroll_run() {
+        let input = r#"VoidRun 2024-08-09"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::VoidPayrollRun {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_off_cycle_paycheck() {
+        let input = r#"OffCycle 42 2024-08-20 2024-08-01 2024-08-20 Extra "Severance" 500.00"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::OffCyclePaycheck {
+                    emp_id: 42,
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+                    period: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                        ..=NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+                    extras: vec![("Severance".to_string(), Money::from_cents(50000))],
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_retro_pay() {
+        let input = r#"RetroPay 42 2024-08-01 2024-08-31"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::RetroPay {
+                    emp_id: 42,
+                    range: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                        ..=NaiveDate::from_ymd_opt(2024, 8, 31).unwrap(),
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_advance_date() {
+        let input = r#"AdvanceDate 2024-09-01"#;
+        let result = transaction().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AdvanceDate {
+                    date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn go_through() -> impl Parser<Item = ()> {
+    let comment = char('#').skip(pred(|c| c != '\n').many0().with(char('\n')));
+    let space_comment = spaces().skip(comment).map(|_| ());
+    let ignore = space_comment.many1().map(|_| ()).or(spaces().map(|_| ()));
+
+    spaces().skip(ignore).skip(spaces()).map(|_| ())
+}
+
+fn add_salary_emp() -> impl Parser<Item = Command> {
+    let prefix = keyword("AddEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let name = string().with(spaces());
+    let address = string().with(spaces());
+    let monthly_rate = char('S').skip(spaces()).skip(money());
+
+    prefix
+        .skip(emp_id)
+        .join(name)
+        .join(address)
+        .join(monthly_rate)
+        .join(hire_date())
+        .map(
+            |((((emp_id, name), address), salary), hire_date)| Command::AddSalaryEmp {
+                emp_id,
+                name,
                 address,
                 salary,
+                hire_date,
+            },
+        )
+}
+#[cfg(test)]
+mod test_add_salary_emp {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"AddEmp 1 "Bob" "Home" S 1000.0"#;
+        let result = add_salary_emp().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AddSalaryEmp {
+                    emp_id: 1,
+                    name: "Bob".to_string(),
+                    address: "Home".to_string(),
+                    salary: Money::from_cents(100000),
+                    hire_date: None
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn add_hourly_emp() -> impl Parser<Item = Command> {
+    let prefix = keyword("AddEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let name = string().with(spaces());
+    let address = string().with(spaces());
+    let hourly_rate = char('H').skip(spaces()).skip(money());
+
+    prefix
+        .skip(emp_id)
+        .join(name)
+        .join(address)
+        .join(hourly_rate)
+        .join(hire_date())
+        .map(
+            |((((emp_id, name), address), hourly_rate), hire_date)| Command::AddHourlyEmp {
+                emp_id,
+                name,
+                address,
+                hourly_rate,
+                hire_date,
+            },
+        )
+}
+#[cfg(test)]
+mod test_add_hourly_emp {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"AddEmp 1 "Bob" "Home" H 1000.0"#;
+        let result = add_hourly_emp().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AddHourlyEmp {
+                    emp_id: 1,
+                    name: "Bob".to_string(),
+                    address: "Home".to_string(),
+                    hourly_rate: Money::from_cents(100000),
+                    hire_date: None
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn add_commissioned_emp() -> impl Parser<Item = Command> {
+    let prefix = keyword("AddEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let name = string().with(spaces());
+    let address = string().with(spaces());
+    let salary = char('C').skip(spaces()).skip(money()).with(spaces());
+    let commission_rate = rate();
+
+    prefix
+        .skip(emp_id)
+        .join(name)
+        .join(address)
+        .join(salary)
+        .join(commission_rate)
+        .join(hire_date())
+        .map(
+            |(((((emp_id, name), address), salary), commission_rate), hire_date)| {
+                Command::AddCommissionedEmp {
+                    emp_id,
+                    name,
+                    address,
+                    salary,
+                    commission_rate,
+                    hire_date,
+                }
+            },
+        )
+}
+#[cfg(test)]
+mod test_add_commissioned_emp {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"AddEmp 1 "Bob" "Home" C 1000.0 0.1"#;
+        let result = add_commissioned_emp().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AddCommissionedEmp {
+                    emp_id: 1,
+                    name: "Bob".to_string(),
+                    address: "Home".to_string(),
+                    salary: Money::from_cents(100000),
+                    commission_rate: Rate::from_basis_points(1000),
+                    hire_date: None
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn del_emp() -> impl Parser<Item = Command> {
+    let prefix = keyword("DelEmp").skip(spaces());
+    let emp_id = uint32();
+
+    prefix.skip(emp_id).map(|emp_id| Command::DelEmp { emp_id })
+}
+#[cfg(test)]
+mod test_del_emp {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"DelEmp 1"#;
+        let result = del_emp().parse(input);
+        assert_eq!(result, Ok((Command::DelEmp { emp_id: 1 }, "")));
+    }
+}
+
+fn terminate() -> impl Parser<Item = Command> {
+    let prefix = keyword("Terminate").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let last_day = date();
+
+    prefix
+        .skip(emp_id)
+        .join(last_day)
+        .map(|(emp_id, last_day)| Command::Terminate { emp_id, last_day })
+}
+#[cfg(test)]
+mod test_terminate {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"Terminate 1 2024-08-23"#;
+        let result = terminate().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Terminate {
+                    emp_id: 1,
+                    last_day: NaiveDate::from_ymd_opt(2024, 8, 23).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn date() -> impl Parser<Item = NaiveDate> {
+    let year = int32().with(char('-'));
+    let month = uint32().with(char('-'));
+    let day = uint32();
+
+    year.join(month)
+        .join(day)
+        .map(|((y, m), d)| NaiveDate::from_ymd_opt(y, m, d).expect("date"))
+}
+#[cfg(test)]
+mod test_date {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = "2021-01-01";
+        let result = date().parse(input);
+        assert_eq!(
+            result,
+            Ok((NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), ""))
+        );
+    }
+}
+
+fn time() -> impl Parser<Item = NaiveTime> {
+    let digit =
+        |from: char, to: char| pred(move |c| from <= c && c <= to).map(|c| c as u32 - '0' as u32);
+    // 00-23, or a single digit
+    let hour = digit('0', '1')
+        .join(digit('0', '9'))
+        .or(char('2').skip(digit('0', '3')).map(|d| (2, d)))
+        .map(|(t, d)| t * 10 + d)
+        .or(digit('0', '9'))
+        .with(char(':'));
+    // 00-59
+    let minute = digit('0', '5')
+        .join(digit('0', '9'))
+        .map(|(t, d)| t * 10 + d);
+
+    hour.join(minute)
+        .map(|(h, m)| NaiveTime::from_hms_opt(h, m, 0).expect("time"))
+}
+#[cfg(test)]
+mod test_time {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = "06:30";
+        let result = time().parse(input);
+        assert_eq!(result, Ok((NaiveTime::from_hms_opt(6, 30, 0).unwrap(), "")));
+
+        let input = "6:30";
+        let result = time().parse(input);
+        assert_eq!(result, Ok((NaiveTime::from_hms_opt(6, 30, 0).unwrap(), "")));
+
+        let input = "23:59";
+        let result = time().parse(input);
+        assert_eq!(
+            result,
+            Ok((NaiveTime::from_hms_opt(23, 59, 0).unwrap(), ""))
+        );
+    }
+    #[test]
+    fn test_out_of_range() {
+        assert!(time().parse("24:00").is_err());
+        assert!(time().parse("12:60").is_err());
+    }
+}
+
+fn hire_date() -> impl Parser<Item = Option<NaiveDate>> {
+    let hired = spaces()
+        .skip(keyword("Hired"))
+        .skip(spaces())
+        .skip(date())
+        .map(Some);
+
+    hired.or(spaces().map(|_| None))
+}
+#[cfg(test)]
+mod test_hire_date {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#" Hired 2024-08-20"#;
+        let result = hire_date().parse(input);
+        assert_eq!(
+            result,
+            Ok((Some(NaiveDate::from_ymd_opt(2024, 8, 20).unwrap()), ""))
+        );
+
+        let input = r#""#;
+        let result = hire_date().parse(input);
+        assert_eq!(result, Ok((None, "")));
+    }
+}
+
+fn effective_date() -> impl Parser<Item = Option<NaiveDate>> {
+    let effective = spaces()
+        .skip(keyword("Effective"))
+        .skip(spaces())
+        .skip(date())
+        .map(Some);
+
+    effective.or(spaces().map(|_| None))
+}
+#[cfg(test)]
+mod test_effective_date {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#" Effective 2024-08-05"#;
+        let result = effective_date().parse(input);
+        assert_eq!(
+            result,
+            Ok((Some(NaiveDate::from_ymd_opt(2024, 8, 5).unwrap()), ""))
+        );
+
+        let input = r#""#;
+        let result = effective_date().parse(input);
+        assert_eq!(result, Ok((None, "")));
+    }
+}
+
+fn rerun() -> impl Parser<Item = bool> {
+    let rerun = spaces().skip(keyword("Rerun")).map(|_| true);
+
+    rerun.or(spaces().map(|_| false))
+}
+#[cfg(test)]
+mod test_rerun {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#" Rerun"#;
+        let result = rerun().parse(input);
+        assert_eq!(result, Ok((true, "")));
+
+        let input = r#""#;
+        let result = rerun().parse(input);
+        assert_eq!(result, Ok((false, "")));
+    }
+}
+
+// ['-'] digits ['.' digits]; the whole part must fit in a u32 and digits past the
+// ninth decimal place are dropped, so every literal admitted is one the types accept
+fn decimal() -> impl Parser<Item = String> {
+    // matches nothing without consuming any input
+    let none = || pred(|_| false).many0();
+    let sign = char('-').map(|c| vec![c]).or(none());
+    let fraction = char('.')
+        .join(pred(|c| c.is_ascii_digit()).many1())
+        .map(|(point, mut digits)| {
+            digits.truncate(9);
+            digits.insert(0, point);
+            digits
+        })
+        .or(none());
+
+    sign.join(uint32())
+        .join(fraction)
+        .map(|((sign, whole), fraction)| {
+            let sign: String = sign.into_iter().collect();
+            let fraction: String = fraction.into_iter().collect();
+            format!("{}{}{}", sign, whole, fraction)
+        })
+}
+fn money() -> impl Parser<Item = Money> {
+    decimal().map(|s| s.parse().expect("a literal admitted by decimal"))
+}
+fn hours() -> impl Parser<Item = Hours> {
+    decimal().map(|s| s.parse().expect("a literal admitted by decimal"))
+}
+fn rate() -> impl Parser<Item = Rate> {
+    decimal().map(|s| s.parse().expect("a literal admitted by decimal"))
+}
+#[cfg(test)]
+mod test_decimal {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test_money() {
+        let input = "1020.85";
+        let result = money().parse(input);
+        assert_eq!(result, Ok((Money::from_cents(102085), "")));
+
+        let input = "1000 ";
+        let result = money().parse(input);
+        assert_eq!(result, Ok((Money::from_cents(100000), " ")));
+
+        let input = "-12.50";
+        let result = money().parse(input);
+        assert_eq!(result, Ok((Money::from_cents(-1250), "")));
+    }
+    #[test]
+    fn test_malformed() {
+        let input = "1.2.3";
+        let result = money().parse(input);
+        assert_eq!(result, Ok((Money::from_cents(120), ".3")));
+
+        let input = "12.";
+        let result = money().parse(input);
+        assert_eq!(result, Ok((Money::from_cents(1200), ".")));
+
+        let input = ".";
+        let result = money().parse(input);
+        assert!(result.is_err());
+
+        let input = ".5";
+        let result = money().parse(input);
+        assert!(result.is_err());
+
+        let input = "-";
+        let result = money().parse(input);
+        assert!(result.is_err());
+
+        let input = "123456789012345678901234567890";
+        let result = money().parse(input);
+        assert!(result.is_err());
+
+        let input = "1.000000000000000000000000000000000000000001";
+        let result = rate().parse(input);
+        assert_eq!(result, Ok((Rate::from_basis_points(10000), "")));
+    }
+    #[test]
+    fn test_hours() {
+        let input = "7.25";
+        let result = hours().parse(input);
+        assert_eq!(result, Ok((Hours::from_minutes(435), "")));
+    }
+    #[test]
+    fn test_rate() {
+        let input = "0.19";
+        let result = rate().parse(input);
+        assert_eq!(result, Ok((Rate::from_basis_points(1900), "")));
+    }
+}
+
+fn clock_time_card() -> impl Parser<Item = Command> {
+    let prefix = keyword("TimeCard").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let clock_in = time().with(spaces());
+    let clock_out = time();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(clock_in)
+        .join(clock_out)
+        .map(
+            |(((emp_id, date), clock_in), clock_out)| Command::ClockTimeCard {
+                emp_id,
+                date,
+                clock_in,
+                clock_out,
+            },
+        )
+}
+#[cfg(test)]
+mod test_clock_time_card {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"TimeCard 1 2024-08-06 22:00 06:30"#;
+        let result = clock_time_card().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ClockTimeCard {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 6).unwrap(),
+                    clock_in: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                    clock_out: NaiveTime::from_hms_opt(6, 30, 0).unwrap()
+                },
+                ""
+            ))
+        );
+
+        let input = r#"TimeCard 1 2024-08-06 8.0"#;
+        let result = clock_time_card().parse(input);
+        assert!(result.is_err());
+    }
+}
+
+fn time_card() -> impl Parser<Item = Command> {
+    let prefix = keyword("TimeCard").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let hours = hours();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(hours)
+        .map(|((emp_id, date), hours)| Command::TimeCard {
+            emp_id,
+            date,
+            hours,
+        })
+}
+#[cfg(test)]
+mod test_time_card {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"TimeCard 1 2021-01-01 8.0"#;
+        let result = time_card().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::TimeCard {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                    hours: Hours::from_hours(8)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn amend_time_card() -> impl Parser<Item = Command> {
+    let prefix = keyword("AmendTimeCard").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let hours = hours();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(hours)
+        .map(|((emp_id, date), hours)| Command::AmendTimeCard {
+            emp_id,
+            date,
+            hours,
+        })
+}
+#[cfg(test)]
+mod test_amend_time_card {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"AmendTimeCard 1 2024-08-01 8.0"#;
+        let result = amend_time_card().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AmendTimeCard {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    hours: Hours::from_hours(8)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn del_time_card() -> impl Parser<Item = Command> {
+    let prefix = keyword("DelTimeCard").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .map(|(emp_id, date)| Command::DelTimeCard { emp_id, date })
+}
+#[cfg(test)]
+mod test_del_time_card {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"DelTimeCard 1 2024-08-01"#;
+        let result = del_time_card().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DelTimeCard {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn leave() -> impl Parser<Item = Command> {
+    let prefix = keyword("Leave").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let hours = hours();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(hours)
+        .map(|((emp_id, date), hours)| Command::Leave {
+            emp_id,
+            date,
+            hours,
+        })
+}
+#[cfg(test)]
+mod test_leave {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"Leave 1 2024-08-08 4.0"#;
+        let result = leave().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Leave {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 8).unwrap(),
+                    hours: Hours::from_hours(4)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn sales_receipt() -> impl Parser<Item = Command> {
+    let prefix = keyword("SalesReceipt").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let amount = money();
+    let product = spaces()
+        .skip(keyword("Product"))
+        .skip(spaces())
+        .skip(string())
+        .map(Some)
+        .or(spaces().map(|_| None));
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(amount)
+        .join(product)
+        .map(
+            |(((emp_id, date), amount), product)| Command::SalesReceipt {
+                emp_id,
+                date,
+                amount,
+                product,
+            },
+        )
+}
+#[cfg(test)]
+mod test_sales_receipt {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"SalesReceipt 1 2021-01-01 1000.0"#;
+        let result = sales_receipt().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::SalesReceipt {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                    amount: Money::from_cents(100000),
+                    product: None
+                },
+                ""
+            ))
+        );
+
+        let input = r#"SalesReceipt 1 2021-01-01 1000.0 Product "Service""#;
+        let result = sales_receipt().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::SalesReceipt {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                    amount: Money::from_cents(100000),
+                    product: Some("Service".to_string())
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn amend_sales_receipt() -> impl Parser<Item = Command> {
+    let prefix = keyword("AmendSalesReceipt").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let amount = money();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(amount)
+        .map(|((emp_id, date), amount)| Command::AmendSalesReceipt {
+            emp_id,
+            date,
+            amount,
+        })
+}
+#[cfg(test)]
+mod test_amend_sales_receipt {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"AmendSalesReceipt 1 2024-08-01 850.50"#;
+        let result = amend_sales_receipt().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AmendSalesReceipt {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    amount: Money::from_cents(85050)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn del_sales_receipt() -> impl Parser<Item = Command> {
+    let prefix = keyword("DelSalesReceipt").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .map(|(emp_id, date)| Command::DelSalesReceipt { emp_id, date })
+}
+#[cfg(test)]
+mod test_del_sales_receipt {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"DelSalesReceipt 1 2024-08-01"#;
+        let result = del_sales_receipt().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DelSalesReceipt {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn bonus() -> impl Parser<Item = Command> {
+    let prefix = keyword("Bonus").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let description = string().with(spaces());
+    let amount = money();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(description)
+        .join(amount)
+        .map(|(((emp_id, date), description), amount)| Command::Bonus {
+            emp_id,
+            date,
+            description,
+            amount,
+        })
+}
+#[cfg(test)]
+mod test_bonus {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"Bonus 1 2024-08-15 "Summer bonus" 1000.00"#;
+        let result = bonus().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Bonus {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 15).unwrap(),
+                    description: "Summer bonus".to_string(),
+                    amount: Money::from_cents(100000)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn allowance() -> impl Parser<Item = Command> {
+    let prefix = keyword("Allowance").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let description = string().with(spaces());
+    let amount = money();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(description)
+        .join(amount)
+        .map(
+            |(((emp_id, date), description), amount)| Command::Allowance {
+                emp_id,
+                date,
+                description,
+                amount,
+            },
+        )
+}
+#[cfg(test)]
+mod test_allowance {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"Allowance 1 2024-08-15 "Commuting" 85.50"#;
+        let result = allowance().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Allowance {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 15).unwrap(),
+                    description: "Commuting".to_string(),
+                    amount: Money::from_cents(8550)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn reimbursement() -> impl Parser<Item = Command> {
+    let prefix = keyword("Reimbursement").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let description = string().with(spaces());
+    let amount = money();
+
+    prefix
+        .skip(emp_id)
+        .join(date)
+        .join(description)
+        .join(amount)
+        .map(
+            |(((emp_id, date), description), amount)| Command::Reimbursement {
+                emp_id,
+                date,
+                description,
+                amount,
+            },
+        )
+}
+#[cfg(test)]
+mod test_reimbursement {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"Reimbursement 1 2024-08-15 "Taxi" 32.80"#;
+        let result = reimbursement().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Reimbursement {
+                    emp_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 15).unwrap(),
+                    description: "Taxi".to_string(),
+                    amount: Money::from_cents(3280)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn service_charge() -> impl Parser<Item = Command> {
+    let prefix = keyword("ServiceCharge").skip(spaces());
+    let member_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let amount = money();
+
+    prefix
+        .skip(member_id)
+        .join(date)
+        .join(amount)
+        .map(|((member_id, date), amount)| Command::ServiceCharge {
+            member_id,
+            date,
+            amount,
+        })
+}
+#[cfg(test)]
+mod test_service_charge {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ServiceCharge 1 2021-01-01 1000.0"#;
+        let result = service_charge().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ServiceCharge {
+                    member_id: 1,
+                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                    amount: Money::from_cents(100000)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn amend_service_charge() -> impl Parser<Item = Command> {
+    let prefix = keyword("AmendServiceCharge").skip(spaces());
+    let member_id = uint32().with(spaces());
+    let date = date().with(spaces());
+    let amount = money();
+
+    prefix
+        .skip(member_id)
+        .join(date)
+        .join(amount)
+        .map(|((member_id, date), amount)| Command::AmendServiceCharge {
+            member_id,
+            date,
+            amount,
+        })
+}
+#[cfg(test)]
+mod test_amend_service_charge {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"AmendServiceCharge 1 2024-08-01 12.95"#;
+        let result = amend_service_charge().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::AmendServiceCharge {
+                    member_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap(),
+                    amount: Money::from_cents(1295)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn del_service_charge() -> impl Parser<Item = Command> {
+    let prefix = keyword("DelServiceCharge").skip(spaces());
+    let member_id = uint32().with(spaces());
+    let date = date();
+
+    prefix
+        .skip(member_id)
+        .join(date)
+        .map(|(member_id, date)| Command::DelServiceCharge { member_id, date })
+}
+#[cfg(test)]
+mod test_del_service_charge {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"DelServiceCharge 1 2024-08-01"#;
+        let result = del_service_charge().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DelServiceCharge {
+                    member_id: 1,
+                    date: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_name() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let name = keyword("Name").skip(spaces()).skip(string());
+
+    prefix
+        .skip(emp_id)
+        .join(name)
+        .map(|(emp_id, name)| Command::ChgName { emp_id, name })
+}
+#[cfg(test)]
+mod test_chg_name {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Name "Bob""#;
+        let result = chg_name().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgName {
+                    emp_id: 1,
+                    name: "Bob".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_address() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let address = keyword("Address").skip(spaces()).skip(string());
+
+    prefix
+        .skip(emp_id)
+        .join(address)
+        .map(|(emp_id, address)| Command::ChgAddress { emp_id, address })
+}
+#[cfg(test)]
+mod test_chg_address {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Address "123 Main St""#;
+        let result = chg_address().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgAddress {
+                    emp_id: 1,
+                    address: "123 Main St".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_hourly() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let hourly_rate = keyword("Hourly").skip(spaces()).skip(money());
+
+    prefix
+        .skip(emp_id)
+        .join(hourly_rate)
+        .join(effective_date())
+        .map(|((emp_id, hourly_rate), effective)| Command::ChgHourly {
+            emp_id,
+            hourly_rate,
+            effective,
+        })
+}
+#[cfg(test)]
+mod test_chg_hourly {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Hourly 13.78"#;
+        let result = chg_hourly().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgHourly {
+                    emp_id: 1,
+                    hourly_rate: Money::from_cents(1378),
+                    effective: None
+                },
+                ""
+            ))
+        );
+
+        let input = r#"ChgEmp 1 Hourly 13.78 Effective 2024-08-05"#;
+        let result = chg_hourly().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgHourly {
+                    emp_id: 1,
+                    hourly_rate: Money::from_cents(1378),
+                    effective: Some(NaiveDate::from_ymd_opt(2024, 8, 5).unwrap())
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_salaried() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let salaried = keyword("Salaried").skip(spaces()).skip(money());
+
+    prefix
+        .skip(emp_id)
+        .join(salaried)
+        .join(effective_date())
+        .map(|((emp_id, salary), effective)| Command::ChgSalaried {
+            emp_id,
+            salary,
+            effective,
+        })
+}
+#[cfg(test)]
+mod test_chg_salaried {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Salaried 1023.456"#;
+        let result = chg_salaried().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgSalaried {
+                    emp_id: 1,
+                    salary: Money::from_cents(102346),
+                    effective: None
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_commissioned() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let salary = keyword("Commissioned")
+        .skip(spaces())
+        .skip(money())
+        .with(spaces());
+    let commission_rate = rate();
+
+    prefix
+        .skip(emp_id)
+        .join(salary)
+        .join(commission_rate)
+        .join(effective_date())
+        .map(
+            |(((emp_id, salary), commission_rate), effective)| Command::ChgCommissioned {
+                emp_id,
+                salary,
+                commission_rate,
+                effective,
+            },
+        )
+}
+#[cfg(test)]
+mod test_chg_commissioned {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Commissioned 1018.91 0.19"#;
+        let result = chg_commissioned().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgCommissioned {
+                    emp_id: 1,
+                    salary: Money::from_cents(101891),
+                    commission_rate: Rate::from_basis_points(1900),
+                    effective: None
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn commission_plan() -> impl Parser<Item = Command> {
+    let prefix = keyword("CommissionPlan").skip(spaces());
+    let name = string();
+    let tier = || {
+        keyword("Tier")
+            .skip(spaces())
+            .skip(money())
+            .with(spaces())
+            .join(rate())
+            .map(|(threshold, rate)| CommissionTier::new(threshold, rate))
+    };
+    let tiers = spaces().skip(tier()).join(spaces().skip(tier()).many0());
+    let product = keyword("Product")
+        .skip(spaces())
+        .skip(string())
+        .with(spaces())
+        .join(rate());
+    let products = spaces().skip(product).many0();
+    let cap = spaces()
+        .skip(keyword("Cap"))
+        .skip(spaces())
+        .skip(money())
+        .map(Some)
+        .or(spaces().map(|_| None));
+
+    prefix.skip(name).join(tiers).join(products).join(cap).map(
+        |(((name, (tier, tiers)), products), cap)| Command::CommissionPlan {
+            plan: CommissionPlan::new(&name, [vec![tier], tiers].concat(), products, cap),
+        },
+    )
+}
+#[cfg(test)]
+mod test_commission_plan {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"CommissionPlan "Tiered" Tier 0 0.05 Tier 10000.00 0.08 Product "Service" 0.12 Product "Support" 0.10 Cap 2000.00"#;
+        let result = commission_plan().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::CommissionPlan {
+                    plan: CommissionPlan::new(
+                        "Tiered",
+                        vec![
+                            CommissionTier::new(Money::ZERO, Rate::from_basis_points(500)),
+                            CommissionTier::new(
+                                Money::from_cents(1000000),
+                                Rate::from_basis_points(800)
+                            ),
+                        ],
+                        vec![
+                            ("Service".to_string(), Rate::from_basis_points(1200)),
+                            ("Support".to_string(), Rate::from_basis_points(1000)),
+                        ],
+                        Some(Money::from_cents(200000))
+                    )
+                },
+                ""
+            ))
+        );
+
+        let input = r#"CommissionPlan "Flat" Tier 0 0.10"#;
+        let result = commission_plan().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::CommissionPlan {
+                    plan: CommissionPlan::new(
+                        "Flat",
+                        vec![CommissionTier::new(
+                            Money::ZERO,
+                            Rate::from_basis_points(1000)
+                        )],
+                        vec![],
+                        None
+                    )
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_commission_plan() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let name = keyword("CommissionPlan").skip(spaces()).skip(string());
+
+    prefix
+        .skip(emp_id)
+        .join(name)
+        .map(|(emp_id, name)| Command::ChgCommissionPlan { emp_id, name })
+}
+#[cfg(test)]
+mod test_chg_commission_plan {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 CommissionPlan "Tiered""#;
+        let result = chg_commission_plan().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgCommissionPlan {
+                    emp_id: 1,
+                    name: "Tiered".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_no_commission_plan() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let no_plan = keyword("NoCommissionPlan");
+
+    prefix
+        .skip(emp_id)
+        .with(no_plan)
+        .map(|emp_id| Command::ChgNoCommissionPlan { emp_id })
+}
+#[cfg(test)]
+mod test_chg_no_commission_plan {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 NoCommissionPlan"#;
+        let result = chg_no_commission_plan().parse(input);
+        assert_eq!(result, Ok((Command::ChgNoCommissionPlan { emp_id: 1 }, "")));
+    }
+}
+
+fn overtime_rule() -> impl Parser<Item = OvertimeRule> {
+    let daily = keyword("Daily")
+        .skip(spaces())
+        .skip(hours())
+        .with(spaces())
+        .join(rate())
+        .map(|(threshold, multiplier)| OvertimeRule::Daily {
+            threshold,
+            multiplier,
+        });
+    let weekly = keyword("Weekly")
+        .skip(spaces())
+        .skip(hours())
+        .with(spaces())
+        .join(rate())
+        .map(|(threshold, multiplier)| OvertimeRule::Weekly {
+            threshold,
+            multiplier,
+        });
+    let weekend = keyword("Weekend")
+        .skip(spaces())
+        .skip(rate())
+        .map(|multiplier| OvertimeRule::Weekend { multiplier });
+    let holiday = keyword("Holiday")
+        .skip(spaces())
+        .skip(date())
+        .with(spaces())
+        .join(rate())
+        .map(|(date, multiplier)| OvertimeRule::Holiday { date, multiplier });
+
+    daily.or(weekly).or(weekend).or(holiday)
+}
+fn chg_overtime() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let rules = keyword("Overtime")
+        .skip(spaces())
+        .skip(overtime_rule())
+        .join(spaces().skip(overtime_rule()).many0());
+
+    prefix
+        .skip(emp_id)
+        .join(rules)
+        .map(|(emp_id, (rule, rules))| Command::ChgOvertime {
+            emp_id,
+            policy: OvertimePolicy::new([vec![rule], rules].concat()),
+        })
+}
+#[cfg(test)]
+mod test_chg_overtime {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input =
+            r#"ChgEmp 1 Overtime Daily 8.0 1.5 Daily 12.0 2.0 Weekend 2.0 Holiday 2024-08-12 2.5"#;
+        let result = chg_overtime().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgOvertime {
+                    emp_id: 1,
+                    policy: OvertimePolicy::new(vec![
+                        OvertimeRule::Daily {
+                            threshold: Hours::from_hours(8),
+                            multiplier: Rate::from_basis_points(15000)
+                        },
+                        OvertimeRule::Daily {
+                            threshold: Hours::from_hours(12),
+                            multiplier: Rate::from_basis_points(20000)
+                        },
+                        OvertimeRule::Weekend {
+                            multiplier: Rate::from_basis_points(20000)
+                        },
+                        OvertimeRule::Holiday {
+                            date: NaiveDate::from_ymd_opt(2024, 8, 12).unwrap(),
+                            multiplier: Rate::from_basis_points(25000)
+                        },
+                    ])
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn shift_window() -> impl Parser<Item = ShiftWindow> {
+    time()
+        .with(spaces())
+        .join(time())
+        .with(spaces())
+        .join(money())
+        .map(|((start, end), premium)| ShiftWindow::new(start, end, premium))
+}
+fn chg_shift_differential() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let windows = keyword("ShiftDiff")
+        .skip(spaces())
+        .skip(shift_window())
+        .join(spaces().skip(shift_window()).many0());
+
+    prefix
+        .skip(emp_id)
+        .join(windows)
+        .map(
+            |(emp_id, (window, windows))| Command::ChgShiftDifferential {
+                emp_id,
+                differential: ShiftDifferential::new([vec![window], windows].concat()),
             },
         )
 }
 #[cfg(test)]
-mod test_add_salary_emp {
+mod test_chg_shift_differential {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 ShiftDiff 18:00 22:00 0.75 22:00 06:00 1.50"#;
+        let result = chg_shift_differential().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgShiftDifferential {
+                    emp_id: 1,
+                    differential: ShiftDifferential::new(vec![
+                        ShiftWindow::new(
+                            NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+                            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                            Money::from_cents(75)
+                        ),
+                        ShiftWindow::new(
+                            NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                            NaiveTime::from_hms_opt(6, 0, 0).unwrap(),
+                            Money::from_cents(150)
+                        ),
+                    ])
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_hold() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let hold = keyword("Hold");
+
+    prefix
+        .skip(emp_id)
+        .with(hold)
+        .map(|emp_id| Command::ChgHold { emp_id })
+}
+#[cfg(test)]
+mod test_chg_hold {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Hold"#;
+        let result = chg_hold().parse(input);
+        assert_eq!(result, Ok((Command::ChgHold { emp_id: 1 }, "")));
+    }
+}
+
+fn chg_direct() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let bank = keyword("Direct")
+        .skip(spaces())
+        .skip(string())
+        .with(spaces());
+    let account = string();
+
+    prefix
+        .skip(emp_id)
+        .join(bank)
+        .join(account)
+        .map(|((emp_id, bank), account)| Command::ChgDirect {
+            emp_id,
+            bank,
+            account,
+        })
+}
+#[cfg(test)]
+mod test_chg_direct {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Direct "Bank" "Account""#;
+        let result = chg_direct().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgDirect {
+                    emp_id: 1,
+                    bank: "Bank".to_string(),
+                    account: "Account".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_mail() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let address = keyword("Mail").skip(spaces()).skip(string());
+
+    prefix
+        .skip(emp_id)
+        .join(address)
+        .map(|(emp_id, address)| Command::ChgMail { emp_id, address })
+}
+#[cfg(test)]
+mod test_chg_mail {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Mail "bob@gmail.com""#;
+        let result = chg_mail().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgMail {
+                    emp_id: 1,
+                    address: "bob@gmail.com".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_biweekly() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let anchor = keyword("Biweekly")
+        .skip(spaces())
+        .skip(keyword("From"))
+        .skip(spaces())
+        .skip(date());
+
+    prefix
+        .skip(emp_id)
+        .join(anchor)
+        .map(|(emp_id, anchor)| Command::ChgBiweekly { emp_id, anchor })
+}
+#[cfg(test)]
+mod test_chg_biweekly {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Biweekly From 2024-01-12"#;
+        let result = chg_biweekly().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgBiweekly {
+                    emp_id: 1,
+                    anchor: NaiveDate::from_ymd_opt(2024, 1, 12).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_semi_monthly() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let semi_monthly = keyword("SemiMonthly");
+
+    prefix
+        .skip(emp_id)
+        .with(semi_monthly)
+        .map(|emp_id| Command::ChgSemiMonthly { emp_id })
+}
+#[cfg(test)]
+mod test_chg_semi_monthly {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 SemiMonthly"#;
+        let result = chg_semi_monthly().parse(input);
+        assert_eq!(result, Ok((Command::ChgSemiMonthly { emp_id: 1 }, "")));
+    }
+}
+
+fn chg_every_n_weeks() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let weeks = keyword("Every")
+        .skip(spaces())
+        .skip(uint32())
+        .with(spaces())
+        .with(keyword("Weeks"))
+        .with(spaces());
+    let anchor = keyword("From").skip(spaces()).skip(date());
+
+    prefix
+        .skip(emp_id)
+        .join(weeks)
+        .join(anchor)
+        .map(|((emp_id, weeks), anchor)| Command::ChgEveryNWeeks {
+            emp_id,
+            weeks,
+            anchor,
+        })
+}
+#[cfg(test)]
+mod test_chg_every_n_weeks {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Every 3 Weeks From 2024-01-05"#;
+        let result = chg_every_n_weeks().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgEveryNWeeks {
+                    emp_id: 1,
+                    weeks: 3,
+                    anchor: NaiveDate::from_ymd_opt(2024, 1, 5).unwrap()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_member() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let member_id = keyword("Member")
+        .skip(spaces())
+        .skip(uint32())
+        .with(spaces());
+    let dues = keyword("Dues").skip(spaces()).skip(money());
+
+    prefix
+        .skip(emp_id)
+        .join(member_id)
+        .join(dues)
+        .map(|((emp_id, member_id), dues)| Command::ChgMember {
+            emp_id,
+            member_id,
+            dues,
+        })
+}
+#[cfg(test)]
+mod test_chg_member {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 Member 2 Dues 100.0"#;
+        let result = chg_member().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgMember {
+                    emp_id: 1,
+                    member_id: 2,
+                    dues: Money::from_cents(10000)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_no_member() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let no_member = keyword("NoMember");
+
+    prefix
+        .skip(emp_id)
+        .with(no_member)
+        .map(|emp_id| Command::ChgNoMember { emp_id })
+}
+#[cfg(test)]
+mod test_chg_no_member {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"AddEmp 1 "Bob" "Home" S 1000.0"#;
-        let result = add_salary_emp().parse(input);
+        let input = r#"ChgEmp 1 NoMember"#;
+        let result = chg_no_member().parse(input);
+        assert_eq!(result, Ok((Command::ChgNoMember { emp_id: 1 }, "")));
+    }
+}
+
+fn chg_health_premium() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let premium = keyword("HealthPremium").skip(spaces()).skip(money());
+
+    prefix
+        .skip(emp_id)
+        .join(premium)
+        .map(|(emp_id, premium)| Command::ChgHealthPremium { emp_id, premium })
+}
+#[cfg(test)]
+mod test_chg_health_premium {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 HealthPremium 30.0"#;
+        let result = chg_health_premium().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::AddSalaryEmp {
+                Command::ChgHealthPremium {
                     emp_id: 1,
-                    name: "Bob".to_string(),
-                    address: "Home".to_string(),
-                    salary: 1000.0
+                    premium: Money::from_cents(3000)
                 },
                 ""
             ))
@@ -366,44 +2900,177 @@ mod test_add_salary_emp {
     }
 }
 
-fn add_hourly_emp() -> impl Parser<Item = Command> {
-    let prefix = keyword("AddEmp").skip(spaces());
+fn chg_no_health_premium() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
     let emp_id = uint32().with(spaces());
-    let name = string().with(spaces());
-    let address = string().with(spaces());
-    let hourly_rate = char('H').skip(spaces()).skip(float32());
+    let no_health_premium = keyword("NoHealthPremium");
 
     prefix
         .skip(emp_id)
-        .join(name)
-        .join(address)
-        .join(hourly_rate)
+        .with(no_health_premium)
+        .map(|emp_id| Command::ChgNoHealthPremium { emp_id })
+}
+#[cfg(test)]
+mod test_chg_no_health_premium {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 NoHealthPremium"#;
+        let result = chg_no_health_premium().parse(input);
+        assert_eq!(result, Ok((Command::ChgNoHealthPremium { emp_id: 1 }, "")));
+    }
+}
+
+fn chg_savings_plan() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let rate = keyword("SavingsPlan").skip(spaces()).skip(rate());
+
+    prefix
+        .skip(emp_id)
+        .join(rate)
+        .map(|(emp_id, rate)| Command::ChgSavingsPlan { emp_id, rate })
+}
+#[cfg(test)]
+mod test_chg_savings_plan {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 SavingsPlan 0.1"#;
+        let result = chg_savings_plan().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgSavingsPlan {
+                    emp_id: 1,
+                    rate: Rate::from_basis_points(1000)
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn chg_no_savings_plan() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let no_savings_plan = keyword("NoSavingsPlan");
+
+    prefix
+        .skip(emp_id)
+        .with(no_savings_plan)
+        .map(|emp_id| Command::ChgNoSavingsPlan { emp_id })
+}
+#[cfg(test)]
+mod test_chg_no_savings_plan {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 NoSavingsPlan"#;
+        let result = chg_no_savings_plan().parse(input);
+        assert_eq!(result, Ok((Command::ChgNoSavingsPlan { emp_id: 1 }, "")));
+    }
+}
+
+fn garnishment_amount() -> impl Parser<Item = GarnishmentAmount> {
+    let fixed = keyword("Fixed")
+        .skip(spaces())
+        .skip(money())
+        .map(|amount| GarnishmentAmount::Fixed { amount });
+    let percent = keyword("Percent")
+        .skip(spaces())
+        .skip(rate())
+        .map(|rate| GarnishmentAmount::Percent { rate });
+
+    fixed.or(percent)
+}
+fn chg_garnishment() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let case = keyword("Garnish").skip(spaces()).skip(string());
+    let amount = spaces().skip(garnishment_amount());
+    let balance = spaces()
+        .skip(keyword("Balance"))
+        .skip(spaces())
+        .skip(money());
+    let max_per_period = spaces()
+        .skip(keyword("Max"))
+        .skip(spaces())
+        .skip(money())
+        .map(Some)
+        .or(spaces().map(|_| None));
+    let ahead_of_union_dues = spaces()
+        .skip(keyword("AheadOfDues"))
+        .map(|_| true)
+        .or(spaces().map(|_| false));
+
+    prefix
+        .skip(emp_id)
+        .join(case)
+        .join(amount)
+        .join(balance)
+        .join(max_per_period)
+        .join(ahead_of_union_dues)
         .map(
-            |(((emp_id, name), address), hourly_rate)| Command::AddHourlyEmp {
-                emp_id,
-                name,
-                address,
-                hourly_rate,
+            |(((((emp_id, case), amount), balance), max_per_period), ahead_of_union_dues)| {
+                Command::ChgGarnishment {
+                    emp_id,
+                    case,
+                    amount,
+                    max_per_period,
+                    balance,
+                    ahead_of_union_dues,
+                }
             },
         )
 }
 #[cfg(test)]
-mod test_add_hourly_emp {
+mod test_chg_garnishment {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"AddEmp 1 "Bob" "Home" H 1000.0"#;
-        let result = add_hourly_emp().parse(input);
+        let input = r#"ChgEmp 1 Garnish "CS-1234" Fixed 100.00 Balance 1200.00"#;
+        let result = chg_garnishment().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::AddHourlyEmp {
+                Command::ChgGarnishment {
                     emp_id: 1,
-                    name: "Bob".to_string(),
-                    address: "Home".to_string(),
-                    hourly_rate: 1000.0
+                    case: "CS-1234".to_string(),
+                    amount: GarnishmentAmount::Fixed {
+                        amount: Money::from_cents(10000)
+                    },
+                    max_per_period: None,
+                    balance: Money::from_cents(120000),
+                    ahead_of_union_dues: false,
+                },
+                ""
+            ))
+        );
+
+        let input =
+            r#"ChgEmp 1 Garnish "DR-77" Percent 0.15 Balance 900.00 Max 150.00 AheadOfDues"#;
+        let result = chg_garnishment().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgGarnishment {
+                    emp_id: 1,
+                    case: "DR-77".to_string(),
+                    amount: GarnishmentAmount::Percent {
+                        rate: Rate::from_basis_points(1500)
+                    },
+                    max_per_period: Some(Money::from_cents(15000)),
+                    balance: Money::from_cents(90000),
+                    ahead_of_union_dues: true,
                 },
                 ""
             ))
@@ -411,48 +3078,105 @@ mod test_add_hourly_emp {
     }
 }
 
-fn add_commissioned_emp() -> impl Parser<Item = Command> {
-    let prefix = keyword("AddEmp").skip(spaces());
+fn chg_no_garnishment() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
     let emp_id = uint32().with(spaces());
-    let name = string().with(spaces());
-    let address = string().with(spaces());
-    let salary = char('C').skip(spaces()).skip(float32()).with(spaces());
-    let commission_rate = float32();
+    let case = keyword("NoGarnish").skip(spaces()).skip(string());
+
+    prefix
+        .skip(emp_id)
+        .join(case)
+        .map(|(emp_id, case)| Command::ChgNoGarnishment { emp_id, case })
+}
+#[cfg(test)]
+mod test_chg_no_garnishment {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 NoGarnish "CS-1234""#;
+        let result = chg_no_garnishment().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgNoGarnishment {
+                    emp_id: 1,
+                    case: "CS-1234".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn loan_repayment() -> impl Parser<Item = LoanRepayment> {
+    let count = keyword("Installments")
+        .skip(spaces())
+        .skip(uint32())
+        .map(|count| LoanRepayment::Count { count });
+    let installment = keyword("Installment")
+        .skip(spaces())
+        .skip(money())
+        .map(|amount| LoanRepayment::Installment { amount });
+
+    count.or(installment)
+}
+fn chg_loan() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let name = keyword("Loan").skip(spaces()).skip(string());
+    let principal = spaces().skip(money());
+    let repayment = spaces().skip(loan_repayment());
 
     prefix
         .skip(emp_id)
         .join(name)
-        .join(address)
-        .join(salary)
-        .join(commission_rate)
+        .join(principal)
+        .join(repayment)
         .map(
-            |((((emp_id, name), address), salary), commission_rate)| Command::AddCommissionedEmp {
+            |(((emp_id, name), principal), repayment)| Command::ChgLoan {
                 emp_id,
                 name,
-                address,
-                salary,
-                commission_rate,
+                principal,
+                repayment,
             },
         )
 }
 #[cfg(test)]
-mod test_add_commissioned_emp {
+mod test_chg_loan {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"AddEmp 1 "Bob" "Home" C 1000.0 0.1"#;
-        let result = add_commissioned_emp().parse(input);
+        let input = r#"ChgEmp 1 Loan "Car" 500.00 Installment 200.00"#;
+        let result = chg_loan().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::AddCommissionedEmp {
+                Command::ChgLoan {
                     emp_id: 1,
-                    name: "Bob".to_string(),
-                    address: "Home".to_string(),
-                    salary: 1000.0,
-                    commission_rate: 0.1
+                    name: "Car".to_string(),
+                    principal: Money::from_cents(50000),
+                    repayment: LoanRepayment::Installment {
+                        amount: Money::from_cents(20000)
+                    },
+                },
+                ""
+            ))
+        );
+
+        let input = r#"ChgEmp 1 Loan "Advance" 100.00 Installments 3"#;
+        let result = chg_loan().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgLoan {
+                    emp_id: 1,
+                    name: "Advance".to_string(),
+                    principal: Money::from_cents(10000),
+                    repayment: LoanRepayment::Count { count: 3 },
                 },
                 ""
             ))
@@ -460,82 +3184,174 @@ mod test_add_commissioned_emp {
     }
 }
 
-fn del_emp() -> impl Parser<Item = Command> {
-    let prefix = keyword("DelEmp").skip(spaces());
+fn list_loans() -> impl Parser<Item = Command> {
+    let prefix = keyword("Loans").skip(spaces());
     let emp_id = uint32();
 
-    prefix.skip(emp_id).map(|emp_id| Command::DelEmp { emp_id })
+    prefix
+        .skip(emp_id)
+        .map(|emp_id| Command::ListLoans { emp_id })
 }
 #[cfg(test)]
-mod test_del_emp {
+mod test_list_loans {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"DelEmp 1"#;
-        let result = del_emp().parse(input);
-        assert_eq!(result, Ok((Command::DelEmp { emp_id: 1 }, "")));
+        let input = r#"Loans 1"#;
+        let result = list_loans().parse(input);
+        assert_eq!(result, Ok((Command::ListLoans { emp_id: 1 }, "")));
     }
 }
 
-fn date() -> impl Parser<Item = NaiveDate> {
-    let year = int32().with(char('-'));
-    let month = uint32().with(char('-'));
-    let day = uint32();
+fn pto_accrual() -> impl Parser<Item = PtoAccrual> {
+    let per_period = keyword("PerPeriod")
+        .skip(spaces())
+        .skip(hours())
+        .map(|hours| PtoAccrual::PerPeriod { hours });
+    let per_hour = keyword("PerHour")
+        .skip(spaces())
+        .skip(rate())
+        .map(|rate| PtoAccrual::PerHourWorked { rate });
 
-    year.join(month)
-        .join(day)
-        .map(|((y, m), d)| NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).expect("date"))
+    per_period.or(per_hour)
+}
+fn chg_pto() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
+    let emp_id = uint32().with(spaces());
+    let accrual = keyword("Pto").skip(spaces()).skip(pto_accrual());
+    let balance = spaces()
+        .skip(keyword("Balance"))
+        .skip(spaces())
+        .skip(hours())
+        .map(Some)
+        .or(spaces().map(|_| None));
+
+    prefix
+        .skip(emp_id)
+        .join(accrual)
+        .join(balance)
+        .map(|((emp_id, accrual), balance)| Command::ChgPto {
+            emp_id,
+            accrual,
+            balance,
+        })
 }
 #[cfg(test)]
-mod test_date {
+mod test_chg_pto {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = "2021-01-01";
-        let result = date().parse(input);
+        let input = r#"ChgEmp 1 Pto PerPeriod 4.0"#;
+        let result = chg_pto().parse(input);
         assert_eq!(
             result,
-            Ok((NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), ""))
+            Ok((
+                Command::ChgPto {
+                    emp_id: 1,
+                    accrual: PtoAccrual::PerPeriod {
+                        hours: Hours::from_hours(4)
+                    },
+                    balance: None,
+                },
+                ""
+            ))
+        );
+
+        let input = r#"ChgEmp 1 Pto PerHour 0.05 Balance 8.0"#;
+        let result = chg_pto().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgPto {
+                    emp_id: 1,
+                    accrual: PtoAccrual::PerHourWorked {
+                        rate: Rate::from_basis_points(500)
+                    },
+                    balance: Some(Hours::from_hours(8)),
+                },
+                ""
+            ))
         );
     }
 }
 
-fn time_card() -> impl Parser<Item = Command> {
-    let prefix = keyword("TimeCard").skip(spaces());
+fn chg_tax_table() -> impl Parser<Item = Command> {
+    let prefix = keyword("ChgEmp").skip(spaces());
     let emp_id = uint32().with(spaces());
-    let date = date().with(spaces());
-    let hours = float32();
+    let path = keyword("TaxTable").skip(spaces()).skip(string());
 
     prefix
         .skip(emp_id)
-        .join(date)
-        .join(hours)
-        .map(|((emp_id, date), hours)| Command::TimeCard {
-            emp_id,
-            date,
-            hours,
-        })
+        .join(path)
+        .map(|(emp_id, path)| Command::ChgTaxTable { emp_id, path })
+}
+#[cfg(test)]
+mod test_chg_tax_table {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"ChgEmp 1 TaxTable "tax.txt""#;
+        let result = chg_tax_table().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::ChgTaxTable {
+                    emp_id: 1,
+                    path: "tax.txt".to_string()
+                },
+                ""
+            ))
+        );
+    }
+}
+
+fn japanese_calendar() -> impl Parser<Item = Command> {
+    let prefix = keyword("HolidayCalendar").skip(spaces());
+    let japan = keyword("Japan");
+
+    prefix.skip(japan).map(|_| Command::JapaneseCalendar)
+}
+#[cfg(test)]
+mod test_japanese_calendar {
+    use super::*;
+    use parsec_rs::Parser;
+
+    #[test]
+    fn test() {
+        let input = r#"HolidayCalendar Japan"#;
+        let result = japanese_calendar().parse(input);
+        assert_eq!(result, Ok((Command::JapaneseCalendar, "")));
+    }
+}
+
+fn load_holiday_calendar() -> impl Parser<Item = Command> {
+    let prefix = keyword("HolidayCalendar").skip(spaces());
+    let path = string();
+
+    prefix
+        .skip(path)
+        .map(|path| Command::LoadHolidayCalendar { path })
 }
 #[cfg(test)]
-mod test_time_card {
+mod test_load_holiday_calendar {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"TimeCard 1 2021-01-01 8.0"#;
-        let result = time_card().parse(input);
+        let input = r#"HolidayCalendar "holidays.txt""#;
+        let result = load_holiday_calendar().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::TimeCard {
-                    emp_id: 1,
-                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
-                    hours: 8.0
+                Command::LoadHolidayCalendar {
+                    path: "holidays.txt".to_string()
                 },
                 ""
             ))
@@ -543,38 +3359,41 @@ mod test_time_card {
     }
 }
 
-fn sales_receipt() -> impl Parser<Item = Command> {
-    let prefix = keyword("SalesReceipt").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let date = date().with(spaces());
-    let amount = float32();
+fn late_entries() -> impl Parser<Item = Command> {
+    let prefix = keyword("LateEntries").skip(spaces());
+    let reject = keyword("Reject").map(|_| LateEntryPolicy::Reject);
+    let carry = keyword("Carry").map(|_| LateEntryPolicy::CarryForward);
 
     prefix
-        .skip(emp_id)
-        .join(date)
-        .join(amount)
-        .map(|((emp_id, date), amount)| Command::SalesReceipt {
-            emp_id,
-            date,
-            amount,
-        })
+        .skip(reject.or(carry))
+        .map(|policy| Command::LateEntries { policy })
 }
 #[cfg(test)]
-mod test_sales_receipt {
+mod test_late_entries {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"SalesReceipt 1 2021-01-01 1000.0"#;
-        let result = sales_receipt().parse(input);
+        let input = r#"LateEntries Reject"#;
+        let result = late_entries().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::SalesReceipt {
-                    emp_id: 1,
-                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
-                    amount: 1000.0
+                Command::LateEntries {
+                    policy: LateEntryPolicy::Reject
+                },
+                ""
+            ))
+        );
+
+        let input = r#"LateEntries Carry"#;
+        let result = late_entries().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::LateEntries {
+                    policy: LateEntryPolicy::CarryForward
                 },
                 ""
             ))
@@ -582,70 +3401,78 @@ mod test_sales_receipt {
     }
 }
 
-fn service_charge() -> impl Parser<Item = Command> {
-    let prefix = keyword("ServiceCharge").skip(spaces());
-    let member_id = uint32().with(spaces());
-    let date = date().with(spaces());
-    let amount = float32();
+fn scheduled() -> impl Parser<Item = Command> {
+    let prefix = keyword("On").skip(spaces());
+    let effective = date().with(spaces());
+    let command = chg_name()
+        .or(chg_address())
+        .or(chg_hold())
+        .or(chg_direct())
+        .or(chg_mail())
+        .or(chg_member());
 
     prefix
-        .skip(member_id)
-        .join(date)
-        .join(amount)
-        .map(|((member_id, date), amount)| Command::ServiceCharge {
-            member_id,
-            date,
-            amount,
+        .skip(effective)
+        .join(command)
+        .map(|(effective, command)| Command::Scheduled {
+            effective,
+            command: Box::new(command),
         })
 }
 #[cfg(test)]
-mod test_service_charge {
+mod test_scheduled {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ServiceCharge 1 2021-01-01 1000.0"#;
-        let result = service_charge().parse(input);
+        let input = r#"On 2024-09-01 ChgEmp 1 Address "456 Main St.""#;
+        let result = scheduled().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ServiceCharge {
-                    member_id: 1,
-                    date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
-                    amount: 1000.0
+                Command::Scheduled {
+                    effective: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap(),
+                    command: Box::new(Command::ChgAddress {
+                        emp_id: 1,
+                        address: "456 Main St.".to_string()
+                    })
                 },
                 ""
             ))
         );
+
+        let input = r#"On 2024-09-01 ChgEmp 1 Hourly 16.00"#;
+        let result = scheduled().parse(input);
+        assert!(result.is_err());
     }
 }
 
-fn chg_name() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
+fn cancel_scheduled() -> impl Parser<Item = Command> {
+    let prefix = keyword("CancelChange").skip(spaces());
     let emp_id = uint32().with(spaces());
-    let name = keyword("Name").skip(spaces()).skip(string());
+    let effective = date();
 
     prefix
         .skip(emp_id)
-        .join(name)
-        .map(|(emp_id, name)| Command::ChgName { emp_id, name })
+        .join(effective)
+        .map(|(emp_id, effective)| Command::CancelScheduled { emp_id, effective })
 }
 #[cfg(test)]
-mod test_chg_name {
+mod test_cancel_scheduled {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Name "Bob""#;
-        let result = chg_name().parse(input);
+        let input = r#"CancelChange 1 2024-09-01"#;
+        let result = cancel_scheduled().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgName {
+                Command::CancelScheduled {
                     emp_id: 1,
-                    name: "Bob".to_string()
+                    effective: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()
                 },
                 ""
             ))
@@ -653,31 +3480,42 @@ mod test_chg_name {
     }
 }
 
-fn chg_address() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let address = keyword("Address").skip(spaces()).skip(string());
+fn list_scheduled() -> impl Parser<Item = Command> {
+    keyword("ListChanges").map(|_| Command::ListScheduled)
+}
+#[cfg(test)]
+mod test_list_scheduled {
+    use super::*;
+    use parsec_rs::Parser;
 
-    prefix
-        .skip(emp_id)
-        .join(address)
-        .map(|(emp_id, address)| Command::ChgAddress { emp_id, address })
+    #[test]
+    fn test() {
+        let input = r#"ListChanges"#;
+        let result = list_scheduled().parse(input);
+        assert_eq!(result, Ok((Command::ListScheduled, "")));
+    }
+}
+
+fn advance_date() -> impl Parser<Item = Command> {
+    let prefix = keyword("AdvanceDate").skip(spaces());
+    let date = date();
+
+    prefix.skip(date).map(|date| Command::AdvanceDate { date })
 }
 #[cfg(test)]
-mod test_chg_address {
+mod test_advance_date {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Address "123 Main St""#;
-        let result = chg_address().parse(input);
+        let input = r#"AdvanceDate 2024-09-01"#;
+        let result = advance_date().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgAddress {
-                    emp_id: 1,
-                    address: "123 Main St".to_string()
+                Command::AdvanceDate {
+                    date: NaiveDate::from_ymd_opt(2024, 9, 1).unwrap()
                 },
                 ""
             ))
@@ -685,34 +3523,30 @@ mod test_chg_address {
     }
 }
 
-fn chg_hourly() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let hourly_rate = keyword("Hourly").skip(spaces()).skip(float32());
+fn calculate_payroll() -> impl Parser<Item = Command> {
+    let prefix = keyword("Calculate").skip(spaces());
+    let date = date();
 
     prefix
-        .skip(emp_id)
-        .join(hourly_rate)
-        .map(|(emp_id, hourly_rate)| Command::ChgHourly {
-            emp_id,
-            hourly_rate,
-        })
+        .skip(date)
+        .join(rerun())
+        .map(|(pay_date, rerun)| Command::CalculatePayroll { pay_date, rerun })
 }
 #[cfg(test)]
-mod test_chg_hourly {
+mod test_calculate_payroll {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Hourly 13.78"#;
-        let result = chg_hourly().parse(input);
+        let input = r#"Calculate 2024-08-09"#;
+        let result = calculate_payroll().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgHourly {
-                    emp_id: 1,
-                    hourly_rate: 13.78
+                Command::CalculatePayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                    rerun: false
                 },
                 ""
             ))
@@ -720,31 +3554,28 @@ mod test_chg_hourly {
     }
 }
 
-fn chg_salaried() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let salaried = keyword("Salaried").skip(spaces()).skip(float32());
+fn review_payroll() -> impl Parser<Item = Command> {
+    let prefix = keyword("Review").skip(spaces());
+    let date = date();
 
     prefix
-        .skip(emp_id)
-        .join(salaried)
-        .map(|(emp_id, salary)| Command::ChgSalaried { emp_id, salary })
+        .skip(date)
+        .map(|pay_date| Command::ReviewPayroll { pay_date })
 }
 #[cfg(test)]
-mod test_chg_salaried {
+mod test_review_payroll {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Salaried 1023.456"#;
-        let result = chg_salaried().parse(input);
+        let input = r#"Review 2024-08-09"#;
+        let result = review_payroll().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgSalaried {
-                    emp_id: 1,
-                    salary: 1023.456
+                Command::ReviewPayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
                 },
                 ""
             ))
@@ -752,39 +3583,28 @@ mod test_chg_salaried {
     }
 }
 
-fn chg_commissioned() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let salary = keyword("Commissioned")
-        .skip(spaces())
-        .skip(float32())
-        .with(spaces());
-    let commission_rate = float32();
+fn approve_payroll() -> impl Parser<Item = Command> {
+    let prefix = keyword("Approve").skip(spaces());
+    let date = date();
 
-    prefix.skip(emp_id).join(salary).join(commission_rate).map(
-        |((emp_id, salary), commission_rate)| Command::ChgCommissioned {
-            emp_id,
-            salary,
-            commission_rate,
-        },
-    )
+    prefix
+        .skip(date)
+        .map(|pay_date| Command::ApprovePayroll { pay_date })
 }
 #[cfg(test)]
-mod test_chg_commissioned {
+mod test_approve_payroll {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Commissioned 1018.91 0.19"#;
-        let result = chg_commissioned().parse(input);
+        let input = r#"Approve 2024-08-09"#;
+        let result = approve_payroll().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgCommissioned {
-                    emp_id: 1,
-                    salary: 1018.91,
-                    commission_rate: 0.19
+                Command::ApprovePayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
                 },
                 ""
             ))
@@ -792,64 +3612,84 @@ mod test_chg_commissioned {
     }
 }
 
-fn chg_hold() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let hold = keyword("Hold");
+fn discard_payroll() -> impl Parser<Item = Command> {
+    let prefix = keyword("Discard").skip(spaces());
+    let date = date();
 
     prefix
-        .skip(emp_id)
-        .with(hold)
-        .map(|emp_id| Command::ChgHold { emp_id })
+        .skip(date)
+        .map(|pay_date| Command::DiscardPayroll { pay_date })
 }
 #[cfg(test)]
-mod test_chg_hold {
+mod test_discard_payroll {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Hold"#;
-        let result = chg_hold().parse(input);
-        assert_eq!(result, Ok((Command::ChgHold { emp_id: 1 }, "")));
+        let input = r#"Discard 2024-08-09"#;
+        let result = discard_payroll().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::DiscardPayroll {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
+                },
+                ""
+            ))
+        );
     }
 }
 
-fn chg_direct() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let bank = keyword("Direct")
-        .skip(spaces())
-        .skip(string())
+fn void_paycheck() -> impl Parser<Item = Command> {
+    let prefix = keyword("VoidOffCycle")
+        .map(|_| true)
+        .or(keyword("Void").map(|_| false))
         .with(spaces());
-    let account = string();
+    let emp_id = uint32().with(spaces());
+    let pay_date = date();
 
     prefix
-        .skip(emp_id)
-        .join(bank)
-        .join(account)
-        .map(|((emp_id, bank), account)| Command::ChgDirect {
+        .join(emp_id)
+        .join(pay_date)
+        .map(|((off_cycle, emp_id), pay_date)| Command::VoidPaycheck {
             emp_id,
-            bank,
-            account,
+            pay_date,
+            off_cycle,
         })
 }
 #[cfg(test)]
-mod test_chg_direct {
+mod test_void_paycheck {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Direct "Bank" "Account""#;
-        let result = chg_direct().parse(input);
+        let input = r#"Void 1 2024-08-09"#;
+        let result = void_paycheck().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgDirect {
+                Command::VoidPaycheck {
                     emp_id: 1,
-                    bank: "Bank".to_string(),
-                    account: "Account".to_string()
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                    off_cycle: false,
+                },
+                ""
+            ))
+        );
+    }
+    #[test]
+    fn test_off_cycle() {
+        let input = r#"VoidOffCycle 1 2024-08-09"#;
+        let result = void_paycheck().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::VoidPaycheck {
+                    emp_id: 1,
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                    off_cycle: true,
                 },
                 ""
             ))
@@ -857,31 +3697,28 @@ mod test_chg_direct {
     }
 }
 
-fn chg_mail() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
-    let emp_id = uint32().with(spaces());
-    let address = keyword("Mail").skip(spaces()).skip(string());
+fn void_payroll_run() -> impl Parser<Item = Command> {
+    let prefix = keyword("VoidRun").skip(spaces());
+    let date = date();
 
     prefix
-        .skip(emp_id)
-        .join(address)
-        .map(|(emp_id, address)| Command::ChgMail { emp_id, address })
+        .skip(date)
+        .map(|pay_date| Command::VoidPayrollRun { pay_date })
 }
 #[cfg(test)]
-mod test_chg_mail {
+mod test_void_payroll_run {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Mail "bob@gmail.com""#;
-        let result = chg_mail().parse(input);
+        let input = r#"VoidRun 2024-08-09"#;
+        let result = void_payroll_run().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgMail {
-                    emp_id: 1,
-                    address: "bob@gmail.com".to_string()
+                Command::VoidPayrollRun {
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 9).unwrap()
                 },
                 ""
             ))
@@ -889,41 +3726,71 @@ mod test_chg_mail {
     }
 }
 
-fn chg_member() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
+fn off_cycle_paycheck() -> impl Parser<Item = Command> {
+    let prefix = keyword("OffCycle").skip(spaces());
     let emp_id = uint32().with(spaces());
-    let member_id = keyword("Member")
+    let pay_date = date().with(spaces());
+    let start = date().with(spaces());
+    let end = date();
+    let extra = keyword("Extra")
         .skip(spaces())
-        .skip(uint32())
-        .with(spaces());
-    let dues = keyword("Dues").skip(spaces()).skip(float32());
+        .skip(string())
+        .with(spaces())
+        .join(money());
+    let extras = spaces().skip(extra).many0();
 
     prefix
         .skip(emp_id)
-        .join(member_id)
-        .join(dues)
-        .map(|((emp_id, member_id), dues)| Command::ChgMember {
-            emp_id,
-            member_id,
-            dues,
-        })
+        .join(pay_date)
+        .join(start)
+        .join(end)
+        .join(extras)
+        .map(
+            |((((emp_id, pay_date), start), end), extras)| Command::OffCyclePaycheck {
+                emp_id,
+                pay_date,
+                period: start..=end,
+                extras,
+            },
+        )
 }
 #[cfg(test)]
-mod test_chg_member {
+mod test_off_cycle_paycheck {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 Member 2 Dues 100.0"#;
-        let result = chg_member().parse(input);
+        let input = r#"OffCycle 1 2024-08-20 2024-08-01 2024-08-20"#;
+        let result = off_cycle_paycheck().parse(input);
         assert_eq!(
             result,
             Ok((
-                Command::ChgMember {
+                Command::OffCyclePaycheck {
                     emp_id: 1,
-                    member_id: 2,
-                    dues: 100.0
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+                    period: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                        ..=NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+                    extras: vec![],
+                },
+                ""
+            ))
+        );
+
+        let input = r#"OffCycle 1 2024-08-20 2024-08-01 2024-08-20 Extra "Severance" 500.00 Extra "Unused leave" 120.50"#;
+        let result = off_cycle_paycheck().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::OffCyclePaycheck {
+                    emp_id: 1,
+                    pay_date: NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+                    period: NaiveDate::from_ymd_opt(2024, 8, 1).unwrap()
+                        ..=NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+                    extras: vec![
+                        ("Severance".to_string(), Money::from_cents(50000)),
+                        ("Unused leave".to_string(), Money::from_cents(12050)),
+                    ],
                 },
                 ""
             ))
@@ -931,26 +3798,41 @@ mod test_chg_member {
     }
 }
 
-fn chg_no_member() -> impl Parser<Item = Command> {
-    let prefix = keyword("ChgEmp").skip(spaces());
+fn retro_pay() -> impl Parser<Item = Command> {
+    let prefix = keyword("RetroPay").skip(spaces());
     let emp_id = uint32().with(spaces());
-    let no_member = keyword("NoMember");
+    let start = date().with(spaces());
+    let end = date();
 
     prefix
         .skip(emp_id)
-        .with(no_member)
-        .map(|emp_id| Command::ChgNoMember { emp_id })
+        .join(start)
+        .join(end)
+        .map(|((emp_id, start), end)| Command::RetroPay {
+            emp_id,
+            range: start..=end,
+        })
 }
 #[cfg(test)]
-mod test_chg_no_member {
+mod test_retro_pay {
     use super::*;
     use parsec_rs::Parser;
 
     #[test]
     fn test() {
-        let input = r#"ChgEmp 1 NoMember"#;
-        let result = chg_no_member().parse(input);
-        assert_eq!(result, Ok((Command::ChgNoMember { emp_id: 1 }, "")));
+        let input = r#"RetroPay 1 2024-08-03 2024-08-09"#;
+        let result = retro_pay().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::RetroPay {
+                    emp_id: 1,
+                    range: NaiveDate::from_ymd_opt(2024, 8, 3).unwrap()
+                        ..=NaiveDate::from_ymd_opt(2024, 8, 9).unwrap(),
+                },
+                ""
+            ))
+        );
     }
 }
 
@@ -960,7 +3842,8 @@ fn payday() -> impl Parser<Item = Command> {
 
     prefix
         .skip(date)
-        .map(|pay_date| Command::Payday { pay_date })
+        .join(rerun())
+        .map(|(pay_date, rerun)| Command::Payday { pay_date, rerun })
 }
 #[cfg(test)]
 mod test_payday {
@@ -975,7 +3858,21 @@ mod test_payday {
             result,
             Ok((
                 Command::Payday {
-                    pay_date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()
+                    pay_date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                    rerun: false
+                },
+                ""
+            ))
+        );
+
+        let input = r#"Payday 2021-01-01 Rerun"#;
+        let result = payday().parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                Command::Payday {
+                    pay_date: NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                    rerun: true
                 },
                 ""
             ))
@@ -1,6 +1,10 @@
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveTime};
+use std::ops::RangeInclusive;
 
-use payroll_domain::{EmployeeId, MemberId};
+use payroll_domain::{
+    CommissionPlan, EmployeeId, GarnishmentAmount, Hours, LateEntryPolicy, LoanRepayment, MemberId,
+    Money, OvertimePolicy, PtoAccrual, Rate, ShiftDifferential,
+};
 use tx_app::Transaction;
 
 pub trait TransactionFactory<Ctx> {
@@ -9,35 +13,100 @@ pub trait TransactionFactory<Ctx> {
         emp_id: EmployeeId,
         name: String,
         address: String,
-        salary: f32,
+        salary: Money,
+        hire_date: Option<NaiveDate>,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_add_hourly_employee_tx(
         &self,
         emp_id: EmployeeId,
         name: String,
         address: String,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        hire_date: Option<NaiveDate>,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_add_commissioned_employee_tx(
         &self,
         emp_id: EmployeeId,
         name: String,
         address: String,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        hire_date: Option<NaiveDate>,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_delete_employee_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_terminate_employee_tx(
+        &self,
+        emp_id: EmployeeId,
+        last_day: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
     fn mk_timecard_tx(
         &self,
         emp_id: EmployeeId,
         date: NaiveDate,
-        hours: f32,
+        hours: Hours,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_clock_timecard_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        clock_in: NaiveTime,
+        clock_out: NaiveTime,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_amend_timecard_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        hours: Hours,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_delete_timecard_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_sales_receipt_tx(
         &self,
         emp_id: EmployeeId,
         date: NaiveDate,
-        amount: f32,
+        amount: Money,
+        product: Option<String>,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_amend_sales_receipt_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        amount: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_delete_sales_receipt_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_add_bonus_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_add_allowance_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_reimbursement_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: String,
+        amount: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_leave_tx(
+        &self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        hours: Hours,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_name_tx(&self, emp_id: EmployeeId, name: String) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_address_tx(
@@ -45,17 +114,41 @@ pub trait TransactionFactory<Ctx> {
         emp_id: EmployeeId,
         address: String,
     ) -> Box<dyn Transaction<Ctx>>;
-    fn mk_change_salaried_tx(&self, emp_id: EmployeeId, salary: f32) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_salaried_tx(
+        &self,
+        emp_id: EmployeeId,
+        salary: Money,
+        effective: Option<NaiveDate>,
+    ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_hourly_tx(
         &self,
         emp_id: EmployeeId,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        effective: Option<NaiveDate>,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_commissioned_tx(
         &self,
         emp_id: EmployeeId,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        effective: Option<NaiveDate>,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_define_commission_plan_tx(&self, plan: CommissionPlan) -> Box<dyn Transaction<Ctx>>;
+    fn mk_assign_commission_plan_tx(
+        &self,
+        emp_id: EmployeeId,
+        name: String,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_unassign_commission_plan_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_overtime_tx(
+        &self,
+        emp_id: EmployeeId,
+        policy: OvertimePolicy,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_shift_differential_tx(
+        &self,
+        emp_id: EmployeeId,
+        differential: ShiftDifferential,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_direct_tx(
         &self,
@@ -65,18 +158,153 @@ pub trait TransactionFactory<Ctx> {
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_mail_tx(&self, emp_id: EmployeeId, address: String) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_hold_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_biweekly_tx(
+        &self,
+        emp_id: EmployeeId,
+        anchor: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_semi_monthly_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_every_n_weeks_tx(
+        &self,
+        emp_id: EmployeeId,
+        weeks: u32,
+        anchor: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_union_member_tx(
         &self,
         emp_id: EmployeeId,
         member_id: MemberId,
-        dues: f32,
+        dues: Money,
     ) -> Box<dyn Transaction<Ctx>>;
     fn mk_change_unaffiliated_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_add_health_premium_tx(
+        &self,
+        emp_id: EmployeeId,
+        premium: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_remove_health_premium_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_add_savings_plan_tx(&self, emp_id: EmployeeId, rate: Rate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_remove_savings_plan_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_add_garnishment_tx(
+        &self,
+        emp_id: EmployeeId,
+        case: String,
+        amount: GarnishmentAmount,
+        max_per_period: Option<Money>,
+        balance: Money,
+        ahead_of_union_dues: bool,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_remove_garnishment_tx(
+        &self,
+        emp_id: EmployeeId,
+        case: String,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_add_loan_tx(
+        &self,
+        emp_id: EmployeeId,
+        name: String,
+        principal: Money,
+        repayment: LoanRepayment,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_list_loans_tx(&self, emp_id: EmployeeId) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_pto_tx(
+        &self,
+        emp_id: EmployeeId,
+        accrual: PtoAccrual,
+        balance: Option<Hours>,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_tax_table_tx(&self, emp_id: EmployeeId, path: String)
+        -> Box<dyn Transaction<Ctx>>;
     fn mk_service_charge_tx(
         &self,
         member_id: MemberId,
         date: NaiveDate,
-        amount: f32,
+        amount: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_amend_service_charge_tx(
+        &self,
+        member_id: MemberId,
+        date: NaiveDate,
+        amount: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_delete_service_charge_tx(
+        &self,
+        member_id: MemberId,
+        date: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_japanese_calendar_tx(&self) -> Box<dyn Transaction<Ctx>>;
+    fn mk_change_late_entry_policy_tx(&self, policy: LateEntryPolicy) -> Box<dyn Transaction<Ctx>>;
+    fn mk_load_holiday_calendar_tx(&self, path: String) -> Box<dyn Transaction<Ctx>>;
+    fn mk_schedule_name_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        name: String,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_schedule_address_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        address: String,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_schedule_hold_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_schedule_direct_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        bank: String,
+        account: String,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_schedule_mail_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        address: String,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_schedule_union_member_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        member_id: MemberId,
+        dues: Money,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_cancel_scheduled_changes_tx(
+        &self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_list_scheduled_changes_tx(&self) -> Box<dyn Transaction<Ctx>>;
+    fn mk_advance_date_tx(&self, date: NaiveDate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_calculate_payroll_tx(
+        &self,
+        pay_date: NaiveDate,
+        rerun: bool,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_review_payroll_tx(&self, pay_date: NaiveDate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_approve_payroll_tx(&self, pay_date: NaiveDate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_discard_payroll_tx(&self, pay_date: NaiveDate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_void_paycheck_tx(
+        &self,
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        off_cycle: bool,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_void_payroll_run_tx(&self, pay_date: NaiveDate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_off_cycle_paycheck_tx(
+        &self,
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        period: RangeInclusive<NaiveDate>,
+        extras: Vec<(String, Money)>,
+    ) -> Box<dyn Transaction<Ctx>>;
+    fn mk_retro_pay_tx(
+        &self,
+        emp_id: EmployeeId,
+        range: RangeInclusive<NaiveDate>,
     ) -> Box<dyn Transaction<Ctx>>;
-    fn mk_payday_tx(&self, pay_date: NaiveDate) -> Box<dyn Transaction<Ctx>>;
+    fn mk_payday_tx(&self, pay_date: NaiveDate, rerun: bool) -> Box<dyn Transaction<Ctx>>;
 }
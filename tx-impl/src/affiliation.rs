@@ -1,7 +1,19 @@
+mod add_health_premium_tx;
+mod add_savings_plan_tx;
+mod amend_service_charge_tx;
 mod change_unaffiliated_tx;
 mod change_union_member_tx;
+mod delete_service_charge_tx;
+mod remove_health_premium_tx;
+mod remove_savings_plan_tx;
 mod service_charge_tx;
 
+pub use add_health_premium_tx::AddHealthPremiumTx;
+pub use add_savings_plan_tx::AddSavingsPlanTx;
+pub use amend_service_charge_tx::AmendServiceChargeTx;
 pub use change_unaffiliated_tx::ChangeUnaffiliatedTx;
 pub use change_union_member_tx::ChangeUnionMemberTx;
+pub use delete_service_charge_tx::DeleteServiceChargeTx;
+pub use remove_health_premium_tx::RemoveHealthPremiumTx;
+pub use remove_savings_plan_tx::RemoveSavingsPlanTx;
 pub use service_charge_tx::ServiceChargeTx;
@@ -0,0 +1,40 @@
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{AddAffiliationTx, UsecaseError};
+use dao::HavePayrollDao;
+use payroll_domain::{EmployeeId, Money};
+use payroll_impl::AffiliationImpl;
+
+pub trait AddHealthPremiumTx<Ctx>: AddAffiliationTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        premium: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        AddAffiliationTx::execute(
+            self,
+            emp_id,
+            move |_, emp| {
+                let enrolled = emp.get_affiliations().iter().any(|a| {
+                    matches!(
+                        a.borrow().as_any().downcast_ref::<AffiliationImpl>(),
+                        Some(AffiliationImpl::HealthPremium { .. })
+                    )
+                });
+                if enrolled {
+                    return Err(UsecaseError::UnexpectedAffiliation(format!(
+                        "already paying health premium emp_id: {}",
+                        emp_id
+                    )));
+                }
+                Ok(())
+            },
+            Rc::new(RefCell::new(AffiliationImpl::HealthPremium { premium })),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> AddHealthPremiumTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,82 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{MemberId, Money};
+use payroll_impl::AffiliationImpl;
+
+pub trait AmendServiceChargeTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        member_id: MemberId,
+        date: NaiveDate,
+        amount: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let emp_id = self
+                .dao()
+                .find_union_member(member_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            let emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            if self
+                .dao()
+                .is_paid_on(emp_id, date)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+            {
+                return Err(UsecaseError::AlreadyPaid(format!(
+                    "emp_id: {}, date: {}",
+                    emp_id, date
+                )));
+            }
+            let matched = emp
+                .get_affiliations()
+                .iter()
+                .find(|a| {
+                    a.borrow()
+                        .as_any()
+                        .downcast_ref::<AffiliationImpl>()
+                        .is_some_and(|a| a.get_member_id() == Some(member_id))
+                })
+                .ok_or(UsecaseError::UnexpectedAffiliation(format!(
+                    "expected union emp_id: {}",
+                    emp_id
+                )))?
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<AffiliationImpl>()
+                .map(|a| a.amend_service_charge(date, amount))
+                .unwrap();
+            match matched {
+                0 => {
+                    return Err(UsecaseError::EntryNotFound(format!(
+                        "member_id: {}, date: {}",
+                        member_id, date
+                    )))
+                }
+                1 => {}
+                n => {
+                    return Err(UsecaseError::AmbiguousEntry(format!(
+                        "member_id: {}, date: {}, {} entries",
+                        member_id, date, n
+                    )))
+                }
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AmendServiceChargeTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
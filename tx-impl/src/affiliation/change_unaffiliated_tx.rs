@@ -1,12 +1,11 @@
-use std::{cell::RefCell, rc::Rc};
 use tx_rs::Tx;
 
-use abstract_tx::{ChangeAffiliationTx, UsecaseError};
+use abstract_tx::{RemoveAffiliationTx, UsecaseError};
 use dao::{HavePayrollDao, PayrollDao};
 use payroll_domain::EmployeeId;
 use payroll_impl::AffiliationImpl;
 
-pub trait ChangeUnaffiliatedTx<Ctx>: ChangeAffiliationTx<Ctx> {
+pub trait ChangeUnaffiliatedTx<Ctx>: RemoveAffiliationTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
@@ -14,28 +13,33 @@ pub trait ChangeUnaffiliatedTx<Ctx>: ChangeAffiliationTx<Ctx> {
     where
         Ctx: 'a,
     {
-        ChangeAffiliationTx::execute(
+        RemoveAffiliationTx::execute(
             self,
             emp_id,
-            move |ctx, emp| {
-                let member_id = emp
-                    .get_affiliation()
-                    .borrow()
-                    .as_any()
-                    .downcast_ref::<AffiliationImpl>()
-                    .map_or(
-                        Err(UsecaseError::UnexpectedAffiliation(format!(
-                            "expected unaffiliated emp_id: {}",
+            |a| {
+                matches!(
+                    a.as_any().downcast_ref::<AffiliationImpl>(),
+                    Some(AffiliationImpl::Union { .. })
+                )
+            },
+            move |ctx, removed| {
+                for a in removed {
+                    let member_id = a
+                        .borrow()
+                        .as_any()
+                        .downcast_ref::<AffiliationImpl>()
+                        .and_then(|a| a.get_member_id())
+                        .ok_or(UsecaseError::UnexpectedAffiliation(format!(
+                            "expected union emp_id: {}",
                             emp_id
-                        ))),
-                        |a| Ok(a.get_member_id()),
-                    )?;
-                self.dao()
-                    .remove_union_member(member_id)
-                    .run(ctx)
-                    .map_err(UsecaseError::RemoveUnionMemberFailed)
+                        )))?;
+                    self.dao()
+                        .remove_union_member(member_id)
+                        .run(ctx)
+                        .map_err(UsecaseError::RemoveUnionMemberFailed)?;
+                }
+                Ok(())
             },
-            Rc::new(RefCell::new(AffiliationImpl::Unaffiliated)),
         )
     }
 }
@@ -1,22 +1,22 @@
 use std::{cell::RefCell, rc::Rc};
 use tx_rs::Tx;
 
-use abstract_tx::{ChangeAffiliationTx, UsecaseError};
+use abstract_tx::{AddAffiliationTx, UsecaseError};
 use dao::{HavePayrollDao, PayrollDao};
-use payroll_domain::{EmployeeId, MemberId};
+use payroll_domain::{EmployeeId, MemberId, Money};
 use payroll_impl::AffiliationImpl;
 
-pub trait ChangeUnionMemberTx<Ctx>: ChangeAffiliationTx<Ctx> {
+pub trait ChangeUnionMemberTx<Ctx>: AddAffiliationTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
         member_id: MemberId,
-        dues: f32,
+        dues: Money,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
-        ChangeAffiliationTx::execute(
+        AddAffiliationTx::execute(
             self,
             emp_id,
             move |ctx, _| {
@@ -0,0 +1,72 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::MemberId;
+use payroll_impl::AffiliationImpl;
+
+pub trait DeleteServiceChargeTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        member_id: MemberId,
+        date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let emp_id = self
+                .dao()
+                .find_union_member(member_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            let emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            if self
+                .dao()
+                .is_paid_on(emp_id, date)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+            {
+                return Err(UsecaseError::AlreadyPaid(format!(
+                    "emp_id: {}, date: {}",
+                    emp_id, date
+                )));
+            }
+            let removed = emp
+                .get_affiliations()
+                .iter()
+                .find(|a| {
+                    a.borrow()
+                        .as_any()
+                        .downcast_ref::<AffiliationImpl>()
+                        .is_some_and(|a| a.get_member_id() == Some(member_id))
+                })
+                .ok_or(UsecaseError::UnexpectedAffiliation(format!(
+                    "expected union emp_id: {}",
+                    emp_id
+                )))?
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<AffiliationImpl>()
+                .unwrap()
+                .remove_service_charges(date);
+            if removed.is_empty() {
+                return Err(UsecaseError::EntryNotFound(format!(
+                    "member_id: {}, date: {}",
+                    member_id, date
+                )));
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> DeleteServiceChargeTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,28 @@
+use abstract_tx::{RemoveAffiliationTx, UsecaseError};
+use dao::HavePayrollDao;
+use payroll_domain::EmployeeId;
+use payroll_impl::AffiliationImpl;
+
+pub trait RemoveSavingsPlanTx<Ctx>: RemoveAffiliationTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        RemoveAffiliationTx::execute(
+            self,
+            emp_id,
+            |a| {
+                matches!(
+                    a.as_any().downcast_ref::<AffiliationImpl>(),
+                    Some(AffiliationImpl::SavingsPlan { .. })
+                )
+            },
+            |_, _| Ok(()),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> RemoveSavingsPlanTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
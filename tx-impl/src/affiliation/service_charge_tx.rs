@@ -1,17 +1,17 @@
 use chrono::NaiveDate;
 use tx_rs::Tx;
 
-use abstract_tx::UsecaseError;
-use dao::{HavePayrollDao, PayrollDao};
-use payroll_domain::MemberId;
+use abstract_tx::{AddEntryTx, UsecaseError};
+use dao::PayrollDao;
+use payroll_domain::{MemberId, Money};
 use payroll_impl::{AffiliationImpl, ServiceCharge};
 
-pub trait ServiceChargeTx<Ctx>: HavePayrollDao<Ctx> {
+pub trait ServiceChargeTx<Ctx>: AddEntryTx<Ctx> {
     fn execute<'a>(
         &'a self,
         member_id: MemberId,
         date: NaiveDate,
-        amount: f32,
+        amount: Money,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
@@ -22,26 +22,89 @@ pub trait ServiceChargeTx<Ctx>: HavePayrollDao<Ctx> {
                 .find_union_member(member_id)
                 .run(ctx)
                 .map_err(UsecaseError::NotFound)?;
-            let emp = self
-                .dao()
-                .fetch(emp_id)
-                .run(ctx)
-                .map_err(UsecaseError::NotFound)?;
-            emp.get_affiliation()
-                .borrow_mut()
-                .as_any_mut()
-                .downcast_mut::<AffiliationImpl>()
-                .ok_or(UsecaseError::UnexpectedAffiliation(format!(
-                    "expected union emp_id: {}",
-                    emp_id
-                )))?
-                .add_service_charge(ServiceCharge::new(date, amount));
-            self.dao()
-                .update(emp)
-                .run(ctx)
-                .map_err(UsecaseError::UpdateEmployeeFailed)
+            AddEntryTx::execute(self, emp_id, date, "late service charge", move |_, emp| {
+                emp.get_affiliations()
+                    .iter()
+                    .find(|a| {
+                        a.borrow()
+                            .as_any()
+                            .downcast_ref::<AffiliationImpl>()
+                            .is_some_and(|a| a.get_member_id() == Some(member_id))
+                    })
+                    .ok_or(UsecaseError::UnexpectedAffiliation(format!(
+                        "expected union emp_id: {}",
+                        emp_id
+                    )))?
+                    .borrow_mut()
+                    .as_any_mut()
+                    .downcast_mut::<AffiliationImpl>()
+                    .unwrap()
+                    .add_service_charge(ServiceCharge::new(date, amount));
+                Ok(())
+            })
+            .run(ctx)
         })
     }
 }
 // blanket implementation
-impl<T, Ctx> ServiceChargeTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+impl<T, Ctx> ServiceChargeTx<Ctx> for T where T: AddEntryTx<Ctx> {}
+
+#[cfg(test)]
+mod test_service_charge {
+    use super::*;
+
+    use payroll_domain::{Earning, LateEntryPolicy};
+
+    use crate::affiliation::ChangeUnionMemberTx;
+    use crate::general::{AddSalaryEmployeeTx, PaydayTx};
+    use crate::payroll_run::ChangeLateEntryPolicyTx;
+    use crate::test_util::{tester, ymd, Tester};
+
+    // a union member paid for August 2024
+    fn paid(t: &Tester) {
+        AddSalaryEmployeeTx::execute(t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        ChangeUnionMemberTx::execute(t, 1, 7235, Money::from_cents(900))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+    }
+
+    #[test]
+    fn test_reject_late_service_charge() {
+        let t = tester();
+        paid(&t);
+
+        let result = ServiceChargeTx::execute(&t, 7235, ymd(2024, 8, 8), Money::from_cents(350))
+            .run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::PeriodLocked(_))));
+        let emp = t.db.fetch(1).run(&mut ()).unwrap();
+        assert!(emp.get_adjustments().is_empty());
+    }
+
+    #[test]
+    fn test_carry_late_service_charge_forward() {
+        let t = tester();
+        paid(&t);
+        ChangeLateEntryPolicyTx::execute(&t, LateEntryPolicy::CarryForward)
+            .run(&mut ())
+            .unwrap();
+
+        ServiceChargeTx::execute(&t, 7235, ymd(2024, 8, 8), Money::from_cents(350))
+            .run(&mut ())
+            .unwrap();
+        // the charge comes off the next paycheck
+        let emp = t.db.fetch(1).run(&mut ()).unwrap();
+        assert_eq!(
+            emp.get_adjustments(),
+            &[Earning::Adjustment {
+                date: ymd(2024, 8, 8),
+                description: "late service charge".to_string(),
+                amount: -Money::from_cents(350),
+            }]
+        );
+    }
+}
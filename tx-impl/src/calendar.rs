@@ -0,0 +1,5 @@
+mod change_japanese_calendar_tx;
+mod load_holiday_calendar_tx;
+
+pub use change_japanese_calendar_tx::ChangeJapaneseCalendarTx;
+pub use load_holiday_calendar_tx::LoadHolidayCalendarTx;
@@ -0,0 +1,15 @@
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{ChangeHolidayCalendarTx, UsecaseError};
+use payroll_impl::HolidayCalendarImpl;
+
+pub trait ChangeJapaneseCalendarTx<Ctx>: ChangeHolidayCalendarTx<Ctx> {
+    fn execute<'a>(&'a self) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeHolidayCalendarTx::execute(self, Rc::new(RefCell::new(HolidayCalendarImpl::Japanese)))
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeJapaneseCalendarTx<Ctx> for T where T: ChangeHolidayCalendarTx<Ctx> {}
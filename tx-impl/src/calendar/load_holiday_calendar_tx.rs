@@ -0,0 +1,20 @@
+use std::{cell::RefCell, rc::Rc};
+use tx_rs::Tx;
+
+use abstract_tx::{ChangeHolidayCalendarTx, UsecaseError};
+use payroll_impl::HolidayCalendarImpl;
+
+pub trait LoadHolidayCalendarTx<Ctx>: ChangeHolidayCalendarTx<Ctx> {
+    fn execute<'a>(&'a self, path: &'a str) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let calendar = HolidayCalendarImpl::load(path)
+                .map_err(|e| UsecaseError::LoadHolidayCalendarFailed(e.to_string()))?;
+            ChangeHolidayCalendarTx::execute(self, Rc::new(RefCell::new(calendar))).run(ctx)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> LoadHolidayCalendarTx<Ctx> for T where T: ChangeHolidayCalendarTx<Ctx> {}
@@ -1,7 +1,11 @@
 mod change_employee_commissioned_tx;
 mod change_employee_hourly_tx;
+mod change_employee_overtime_tx;
 mod change_employee_salaried_tx;
+mod change_employee_shift_differential_tx;
 
 pub use change_employee_commissioned_tx::ChangeEmployeeCommissionedTx;
 pub use change_employee_hourly_tx::ChangeEmployeeHourlyTx;
+pub use change_employee_overtime_tx::ChangeEmployeeOvertimeTx;
 pub use change_employee_salaried_tx::ChangeEmployeeSalariedTx;
+pub use change_employee_shift_differential_tx::ChangeEmployeeShiftDifferentialTx;
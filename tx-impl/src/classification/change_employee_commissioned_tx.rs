@@ -1,33 +1,63 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 
-use abstract_tx::{ChangeEmployeePaymentClassificationTx, UsecaseError};
-use payroll_domain::EmployeeId;
+use abstract_tx::{ChangeEmployeeRateTx, UsecaseError};
+use payroll_domain::{EmployeeId, Money, Rate};
 use payroll_impl::{PaymentClassificationImpl, PaymentScheduleImpl};
 
-pub trait ChangeEmployeeCommissionedTx<Ctx>: ChangeEmployeePaymentClassificationTx<Ctx> {
+pub trait ChangeEmployeeCommissionedTx<Ctx>: ChangeEmployeeRateTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        effective: Option<NaiveDate>,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
-        ChangeEmployeePaymentClassificationTx::execute(
-            self,
-            emp_id,
-            Rc::new(RefCell::new(PaymentClassificationImpl::Commissioned {
-                salary,
-                commission_rate,
-                sales_receipts: vec![],
-            })),
-            Rc::new(RefCell::new(PaymentScheduleImpl::Biweekly)),
-        )
+        ChangeEmployeeRateTx::execute(self, emp_id, effective, move |emp, from| {
+            // a commissioned employee keeps the sales receipts, only the rates change
+            if let Some(PaymentClassificationImpl::Commissioned {
+                salary: salary_history,
+                commission_rate: rate_history,
+                ..
+            }) = emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                match from {
+                    Some(date) => {
+                        salary_history.change(date, salary);
+                        rate_history.change(date, commission_rate);
+                    }
+                    None => {
+                        salary_history.reset(salary);
+                        rate_history.reset(commission_rate);
+                    }
+                }
+                return Ok(());
+            }
+            if effective.is_some() {
+                return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected commissioned emp_id: {}",
+                    emp_id
+                )));
+            }
+            emp.set_classification(Rc::new(RefCell::new(
+                PaymentClassificationImpl::Commissioned {
+                    salary: salary.into(),
+                    commission_rate: commission_rate.into(),
+                    sales_receipts: vec![],
+                    plan: None,
+                },
+            )));
+            emp.set_schedule(Rc::new(RefCell::new(PaymentScheduleImpl::biweekly())));
+            Ok(())
+        })
     }
 }
 // blanket implementation
-impl<T, Ctx> ChangeEmployeeCommissionedTx<Ctx> for T where
-    T: ChangeEmployeePaymentClassificationTx<Ctx>
-{
-}
+impl<T, Ctx> ChangeEmployeeCommissionedTx<Ctx> for T where T: ChangeEmployeeRateTx<Ctx> {}
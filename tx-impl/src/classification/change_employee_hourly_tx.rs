@@ -1,28 +1,53 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 
-use abstract_tx::{ChangeEmployeePaymentClassificationTx, UsecaseError};
-use payroll_domain::EmployeeId;
+use abstract_tx::{ChangeEmployeeRateTx, UsecaseError};
+use payroll_domain::{EmployeeId, Money, OvertimePolicy, ShiftDifferential};
 use payroll_impl::{PaymentClassificationImpl, PaymentScheduleImpl};
 
-pub trait ChangeEmployeeHourlyTx<Ctx>: ChangeEmployeePaymentClassificationTx<Ctx> {
+pub trait ChangeEmployeeHourlyTx<Ctx>: ChangeEmployeeRateTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        effective: Option<NaiveDate>,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
-        ChangeEmployeePaymentClassificationTx::execute(
-            self,
-            emp_id,
-            Rc::new(RefCell::new(PaymentClassificationImpl::Hourly {
-                hourly_rate,
+        ChangeEmployeeRateTx::execute(self, emp_id, effective, move |emp, from| {
+            // an hourly employee keeps the timecards, only the rate changes
+            if let Some(PaymentClassificationImpl::Hourly {
+                hourly_rate: history,
+                ..
+            }) = emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                match from {
+                    Some(date) => history.change(date, hourly_rate),
+                    None => history.reset(hourly_rate),
+                }
+                return Ok(());
+            }
+            if effective.is_some() {
+                return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected hourly emp_id: {}",
+                    emp_id
+                )));
+            }
+            emp.set_classification(Rc::new(RefCell::new(PaymentClassificationImpl::Hourly {
+                hourly_rate: hourly_rate.into(),
                 timecards: vec![],
-            })),
-            Rc::new(RefCell::new(PaymentScheduleImpl::Weekly)),
-        )
+                overtime: OvertimePolicy::default(),
+                differential: ShiftDifferential::default(),
+            })));
+            emp.set_schedule(Rc::new(RefCell::new(PaymentScheduleImpl::Weekly)));
+            Ok(())
+        })
     }
 }
 // blanket implementation
-impl<T, Ctx> ChangeEmployeeHourlyTx<Ctx> for T where T: ChangeEmployeePaymentClassificationTx<Ctx> {}
+impl<T, Ctx> ChangeEmployeeHourlyTx<Ctx> for T where T: ChangeEmployeeRateTx<Ctx> {}
@@ -1,25 +1,81 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 
-use abstract_tx::{ChangeEmployeePaymentClassificationTx, UsecaseError};
-use payroll_domain::EmployeeId;
+use abstract_tx::{ChangeEmployeeRateTx, UsecaseError};
+use payroll_domain::{EmployeeId, Money};
 use payroll_impl::{PaymentClassificationImpl, PaymentScheduleImpl};
 
-pub trait ChangeEmployeeSalariedTx<Ctx>: ChangeEmployeePaymentClassificationTx<Ctx> {
+pub trait ChangeEmployeeSalariedTx<Ctx>: ChangeEmployeeRateTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
-        salary: f32,
+        salary: Money,
+        effective: Option<NaiveDate>,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
-        ChangeEmployeePaymentClassificationTx::execute(
-            self,
-            emp_id,
-            Rc::new(RefCell::new(PaymentClassificationImpl::Salaried { salary })),
-            Rc::new(RefCell::new(PaymentScheduleImpl::Monthly)),
-        )
+        ChangeEmployeeRateTx::execute(self, emp_id, effective, move |emp, from| {
+            if let Some(PaymentClassificationImpl::Salaried { salary: history }) = emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                match from {
+                    Some(date) => history.change(date, salary),
+                    None => history.reset(salary),
+                }
+                return Ok(());
+            }
+            if effective.is_some() {
+                return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected salaried emp_id: {}",
+                    emp_id
+                )));
+            }
+            emp.set_classification(Rc::new(RefCell::new(PaymentClassificationImpl::Salaried {
+                salary: salary.into(),
+            })));
+            emp.set_schedule(Rc::new(RefCell::new(PaymentScheduleImpl::Monthly)));
+            Ok(())
+        })
     }
 }
 // blanket implementation
-impl<T, Ctx> ChangeEmployeeSalariedTx<Ctx> for T where T: ChangeEmployeePaymentClassificationTx<Ctx> {}
+impl<T, Ctx> ChangeEmployeeSalariedTx<Ctx> for T where T: ChangeEmployeeRateTx<Ctx> {}
+
+#[cfg(test)]
+mod test_change_employee_salaried {
+    use super::*;
+    use tx_rs::Tx;
+
+    use crate::general::{AddSalaryEmployeeTx, PaydayTx};
+    use crate::payroll_run::RetroPayTx;
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_undated_change_keeps_paid_periods() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 7, 31), false)
+            .run(&mut ())
+            .unwrap();
+        ChangeEmployeeSalariedTx::execute(&t, 1, Money::from_cents(330000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(330000));
+        // July was paid at the salary in force then
+        let adjustments = RetroPayTx::execute(&t, 1, ymd(2024, 7, 1)..=ymd(2024, 8, 31))
+            .run(&mut ())
+            .unwrap();
+        assert!(adjustments.is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::{EmployeeId, ShiftDifferential};
+use payroll_impl::PaymentClassificationImpl;
+
+pub trait ChangeEmployeeShiftDifferentialTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        shift_differential: ShiftDifferential,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            match emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                Some(PaymentClassificationImpl::Hourly { differential, .. }) => {
+                    *differential = shift_differential;
+                    Ok(())
+                }
+                _ => Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected hourly emp_id: {}",
+                    emp_id
+                ))),
+            }
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeShiftDifferentialTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
@@ -0,0 +1,7 @@
+mod assign_commission_plan_tx;
+mod define_commission_plan_tx;
+mod unassign_commission_plan_tx;
+
+pub use assign_commission_plan_tx::AssignCommissionPlanTx;
+pub use define_commission_plan_tx::DefineCommissionPlanTx;
+pub use unassign_commission_plan_tx::UnassignCommissionPlanTx;
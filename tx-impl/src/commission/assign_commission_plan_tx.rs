@@ -0,0 +1,111 @@
+use tx_rs::Tx;
+
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use dao::PayrollDao;
+use payroll_domain::EmployeeId;
+use payroll_impl::PaymentClassificationImpl;
+
+pub trait AssignCommissionPlanTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    // the employee keeps a copy, so redefining the plan later doesn't change it
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        name: &'a str,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |ctx, emp| {
+            let commission_plan = self
+                .dao()
+                .fetch_commission_plan(name)
+                .run(ctx)
+                .map_err(UsecaseError::CommissionPlanNotFound)?;
+            match emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                Some(PaymentClassificationImpl::Commissioned { plan, .. }) => {
+                    *plan = Some(commission_plan);
+                    Ok(())
+                }
+                _ => Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected commissioned emp_id: {}",
+                    emp_id
+                ))),
+            }
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AssignCommissionPlanTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
+
+#[cfg(test)]
+mod test_assign_commission_plan {
+    use super::*;
+
+    use payroll_domain::{CommissionPlan, CommissionTier, Money, Rate};
+
+    use crate::commission::DefineCommissionPlanTx;
+    use crate::general::{AddCommissionedEmployeeTx, PaydayTx, SalesReceiptTx};
+    use crate::test_util::{paychecks, tester, ymd};
+
+    fn flat(rate: Rate) -> CommissionPlan {
+        CommissionPlan::new(
+            "Flat",
+            vec![CommissionTier::new(Money::ZERO, rate)],
+            vec![],
+            None,
+        )
+    }
+
+    #[test]
+    fn test_redefined_plan_is_not_reassigned() {
+        let t = tester();
+        AddCommissionedEmployeeTx::execute(
+            &t,
+            1,
+            "Bob",
+            "Home",
+            Money::ZERO,
+            Rate::from_basis_points(1000),
+            None,
+        )
+        .run(&mut ())
+        .unwrap();
+        DefineCommissionPlanTx::execute(&t, flat(Rate::from_basis_points(500)))
+            .run(&mut ())
+            .unwrap();
+        AssignCommissionPlanTx::execute(&t, 1, "Flat")
+            .run(&mut ())
+            .unwrap();
+        DefineCommissionPlanTx::execute(&t, flat(Rate::from_basis_points(800)))
+            .run(&mut ())
+            .unwrap();
+        SalesReceiptTx::execute(&t, 1, ymd(2024, 8, 5), Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+
+        // the copy taken at assignment still pays 5%
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(5000));
+
+        // assigning again picks up the new definition
+        AssignCommissionPlanTx::execute(&t, 1, "Flat")
+            .run(&mut ())
+            .unwrap();
+        SalesReceiptTx::execute(&t, 1, ymd(2024, 8, 12), Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 23), false)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(8000));
+    }
+}
@@ -0,0 +1,22 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::CommissionPlan;
+
+pub trait DefineCommissionPlanTx<Ctx>: HavePayrollDao<Ctx> {
+    // redefining a plan by name affects only the employees assigned to it afterwards
+    fn execute<'a>(
+        &'a self,
+        plan: CommissionPlan,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .save_commission_plan(plan)
+            .map_err(UsecaseError::SaveCommissionPlanFailed)
+    }
+}
+// blanket implementation
+impl<T, Ctx> DefineCommissionPlanTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
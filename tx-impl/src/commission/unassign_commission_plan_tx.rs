@@ -0,0 +1,34 @@
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::EmployeeId;
+use payroll_impl::PaymentClassificationImpl;
+
+pub trait UnassignCommissionPlanTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    // back to the flat commission rate
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            match emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                Some(PaymentClassificationImpl::Commissioned { plan, .. }) => {
+                    *plan = None;
+                    Ok(())
+                }
+                _ => Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected commissioned emp_id: {}",
+                    emp_id
+                ))),
+            }
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> UnassignCommissionPlanTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
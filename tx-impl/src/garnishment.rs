@@ -0,0 +1,5 @@
+mod add_garnishment_tx;
+mod remove_garnishment_tx;
+
+pub use add_garnishment_tx::AddGarnishmentTx;
+pub use remove_garnishment_tx::RemoveGarnishmentTx;
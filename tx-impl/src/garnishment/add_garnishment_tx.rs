@@ -0,0 +1,36 @@
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::{EmployeeId, Garnishment, GarnishmentAmount, Money};
+
+pub trait AddGarnishmentTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        case: &str,
+        amount: GarnishmentAmount,
+        max_per_period: Option<Money>,
+        balance: Money,
+        ahead_of_union_dues: bool,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            if emp.get_garnishments().iter().any(|g| g.get_case() == case) {
+                return Err(UsecaseError::UnexpectedGarnishment(format!(
+                    "already garnished for case {} emp_id: {}",
+                    case, emp_id
+                )));
+            }
+            emp.add_garnishment(Garnishment::new(
+                case,
+                amount,
+                max_per_period,
+                balance,
+                ahead_of_union_dues,
+            ));
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AddGarnishmentTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
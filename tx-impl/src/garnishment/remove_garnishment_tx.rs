@@ -0,0 +1,24 @@
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::EmployeeId;
+
+pub trait RemoveGarnishmentTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        case: &str,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            emp.remove_garnishment(case)
+                .map(|_| ())
+                .ok_or(UsecaseError::UnexpectedGarnishment(format!(
+                    "no garnishment for case {} emp_id: {}",
+                    case, emp_id
+                )))
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> RemoveGarnishmentTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
@@ -1,19 +1,37 @@
+mod add_allowance_tx;
+mod add_bonus_tx;
 mod add_commissioned_employee_tx;
 mod add_hourly_employee_tx;
 mod add_salary_employee_tx;
+mod amend_sales_receipt_tx;
+mod amend_timecard_tx;
 mod change_employee_address_tx;
 mod change_employee_name_tx;
+mod clock_timecard_tx;
 mod delete_employee_tx;
+mod delete_sales_receipt_tx;
+mod delete_timecard_tx;
 mod payday_tx;
+mod reimbursement_tx;
 mod sales_receipt_tx;
+mod terminate_employee_tx;
 mod timecard_tx;
 
+pub use add_allowance_tx::AddAllowanceTx;
+pub use add_bonus_tx::AddBonusTx;
 pub use add_commissioned_employee_tx::AddCommissionedEmployeeTx;
 pub use add_hourly_employee_tx::AddHourlyEmployeeTx;
 pub use add_salary_employee_tx::AddSalaryEmployeeTx;
+pub use amend_sales_receipt_tx::AmendSalesReceiptTx;
+pub use amend_timecard_tx::AmendTimeCardTx;
 pub use change_employee_address_tx::ChangeEmployeeAddressTx;
 pub use change_employee_name_tx::ChangeEmployeeNameTx;
+pub use clock_timecard_tx::ClockTimeCardTx;
 pub use delete_employee_tx::DeleteEmployeeTx;
+pub use delete_sales_receipt_tx::DeleteSalesReceiptTx;
+pub use delete_timecard_tx::DeleteTimeCardTx;
 pub use payday_tx::PaydayTx;
+pub use reimbursement_tx::ReimbursementTx;
 pub use sales_receipt_tx::SalesReceiptTx;
+pub use terminate_employee_tx::TerminateEmployeeTx;
 pub use timecard_tx::TimeCardTx;
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+
+use abstract_tx::{AddEntryTx, UsecaseError};
+use payroll_domain::{Earning, EmployeeId, Money};
+
+pub trait AddAllowanceTx<Ctx>: AddEntryTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: &str,
+        amount: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        AddEntryTx::execute(self, emp_id, date, "late allowance", move |_, emp| {
+            emp.add_additional_earning(Earning::Allowance {
+                date,
+                description: description.to_string(),
+                amount,
+            });
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AddAllowanceTx<Ctx> for T where T: AddEntryTx<Ctx> {}
@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+
+use abstract_tx::{AddEntryTx, UsecaseError};
+use payroll_domain::{Earning, EmployeeId, Money};
+
+pub trait AddBonusTx<Ctx>: AddEntryTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: &str,
+        amount: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        AddEntryTx::execute(self, emp_id, date, "late bonus", move |_, emp| {
+            emp.add_additional_earning(Earning::Bonus {
+                date,
+                description: description.to_string(),
+                amount,
+            });
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AddBonusTx<Ctx> for T where T: AddEntryTx<Ctx> {}
+
+#[cfg(test)]
+mod test_add_bonus {
+    use super::*;
+    use tx_rs::Tx;
+
+    use payroll_domain::LateEntryPolicy;
+
+    use crate::general::{AddSalaryEmployeeTx, PaydayTx};
+    use crate::payroll_run::ChangeLateEntryPolicyTx;
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_late_bonus() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        let result = AddBonusTx::execute(&t, 1, ymd(2024, 8, 20), "Q2", Money::from_cents(50000))
+            .run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::PeriodLocked(_))));
+
+        ChangeLateEntryPolicyTx::execute(&t, LateEntryPolicy::CarryForward)
+            .run(&mut ())
+            .unwrap();
+        AddBonusTx::execute(&t, 1, ymd(2024, 8, 20), "Q2", Money::from_cents(50000))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 9, 30), false)
+            .run(&mut ())
+            .unwrap();
+        // paid once, on the next paycheck
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(350000));
+    }
+}
@@ -1,7 +1,8 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 
 use abstract_tx::{AddEmployeeTx, UsecaseError};
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money, Rate};
 use payroll_impl::{PaymentClassificationImpl, PaymentScheduleImpl};
 
 pub trait AddCommissionedEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
@@ -10,8 +11,9 @@ pub trait AddCommissionedEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
         emp_id: EmployeeId,
         name: &str,
         address: &str,
-        salary: f32,
-        commission_rate: f32,
+        salary: Money,
+        commission_rate: Rate,
+        hire_date: Option<NaiveDate>,
     ) -> impl tx_rs::Tx<Ctx, Item = EmployeeId, Err = UsecaseError>
     where
         Ctx: 'a,
@@ -21,12 +23,14 @@ pub trait AddCommissionedEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
             emp_id,
             name,
             address,
+            hire_date,
             Rc::new(RefCell::new(PaymentClassificationImpl::Commissioned {
-                salary,
-                commission_rate,
+                salary: salary.into(),
+                commission_rate: commission_rate.into(),
                 sales_receipts: vec![],
+                plan: None,
             })),
-            Rc::new(RefCell::new(PaymentScheduleImpl::Biweekly)),
+            Rc::new(RefCell::new(PaymentScheduleImpl::biweekly())),
         )
     }
 }
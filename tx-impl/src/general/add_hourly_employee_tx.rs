@@ -1,7 +1,8 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 
 use abstract_tx::{AddEmployeeTx, UsecaseError};
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money, OvertimePolicy, ShiftDifferential};
 use payroll_impl::{PaymentClassificationImpl, PaymentScheduleImpl};
 
 pub trait AddHourlyEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
@@ -10,7 +11,8 @@ pub trait AddHourlyEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
         emp_id: EmployeeId,
         name: &str,
         address: &str,
-        hourly_rate: f32,
+        hourly_rate: Money,
+        hire_date: Option<NaiveDate>,
     ) -> impl tx_rs::Tx<Ctx, Item = EmployeeId, Err = UsecaseError>
     where
         Ctx: 'a,
@@ -20,9 +22,12 @@ pub trait AddHourlyEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
             emp_id,
             name,
             address,
+            hire_date,
             Rc::new(RefCell::new(PaymentClassificationImpl::Hourly {
-                hourly_rate,
+                hourly_rate: hourly_rate.into(),
                 timecards: vec![],
+                overtime: OvertimePolicy::default(),
+                differential: ShiftDifferential::default(),
             })),
             Rc::new(RefCell::new(PaymentScheduleImpl::Weekly)),
         )
@@ -1,7 +1,8 @@
+use chrono::NaiveDate;
 use std::{cell::RefCell, rc::Rc};
 
 use abstract_tx::{AddEmployeeTx, UsecaseError};
-use payroll_domain::EmployeeId;
+use payroll_domain::{EmployeeId, Money};
 use payroll_impl::{PaymentClassificationImpl, PaymentScheduleImpl};
 
 pub trait AddSalaryEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
@@ -10,7 +11,8 @@ pub trait AddSalaryEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
         emp_id: EmployeeId,
         name: &str,
         address: &str,
-        salary: f32,
+        salary: Money,
+        hire_date: Option<NaiveDate>,
     ) -> impl tx_rs::Tx<Ctx, Item = EmployeeId, Err = UsecaseError>
     where
         Ctx: 'a,
@@ -20,7 +22,10 @@ pub trait AddSalaryEmployeeTx<Ctx>: AddEmployeeTx<Ctx> {
             emp_id,
             name,
             address,
-            Rc::new(RefCell::new(PaymentClassificationImpl::Salaried { salary })),
+            hire_date,
+            Rc::new(RefCell::new(PaymentClassificationImpl::Salaried {
+                salary: salary.into(),
+            })),
             Rc::new(RefCell::new(PaymentScheduleImpl::Monthly)),
         )
     }
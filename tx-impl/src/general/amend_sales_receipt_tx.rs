@@ -0,0 +1,75 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{EmployeeId, Money};
+use payroll_impl::PaymentClassificationImpl;
+
+pub trait AmendSalesReceiptTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        amount: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            if self
+                .dao()
+                .is_paid_on(emp_id, date)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+            {
+                return Err(UsecaseError::AlreadyPaid(format!(
+                    "emp_id: {}, date: {}",
+                    emp_id, date
+                )));
+            }
+            let matched = match emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                Some(c @ PaymentClassificationImpl::Commissioned { .. }) => {
+                    c.amend_sales_receipt(date, amount)
+                }
+                _ => {
+                    return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                        "expected commissioned emp_id: {}",
+                        emp_id
+                    )))
+                }
+            };
+            match matched {
+                0 => {
+                    return Err(UsecaseError::EntryNotFound(format!(
+                        "emp_id: {}, date: {}",
+                        emp_id, date
+                    )))
+                }
+                1 => {}
+                n => {
+                    return Err(UsecaseError::AmbiguousEntry(format!(
+                        "emp_id: {}, date: {}, {} entries",
+                        emp_id, date, n
+                    )))
+                }
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AmendSalesReceiptTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,131 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{EmployeeId, Hours};
+use payroll_impl::PaymentClassificationImpl;
+
+pub trait AmendTimeCardTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        hours: Hours,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            if self
+                .dao()
+                .is_paid_on(emp_id, date)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+            {
+                return Err(UsecaseError::AlreadyPaid(format!(
+                    "emp_id: {}, date: {}",
+                    emp_id, date
+                )));
+            }
+            let matched = match emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                // a clocked timecard is deleted and entered again with the new times
+                Some(c @ PaymentClassificationImpl::Hourly { .. }) if c.is_clocked(date) => {
+                    return Err(UsecaseError::ClockedEntry(format!(
+                        "emp_id: {}, date: {}",
+                        emp_id, date
+                    )))
+                }
+                Some(c @ PaymentClassificationImpl::Hourly { .. }) => c.amend_timecard(date, hours),
+                _ => {
+                    return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                        "expected hourly emp_id: {}",
+                        emp_id
+                    )))
+                }
+            };
+            match matched {
+                0 => {
+                    return Err(UsecaseError::EntryNotFound(format!(
+                        "emp_id: {}, date: {}",
+                        emp_id, date
+                    )))
+                }
+                1 => {}
+                n => {
+                    return Err(UsecaseError::AmbiguousEntry(format!(
+                        "emp_id: {}, date: {}, {} entries",
+                        emp_id, date, n
+                    )))
+                }
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AmendTimeCardTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+
+#[cfg(test)]
+mod test_amend_timecard {
+    use super::*;
+    use chrono::NaiveTime;
+    use payroll_domain::Money;
+
+    use crate::general::{AddHourlyEmployeeTx, ClockTimeCardTx, PaydayTx, TimeCardTx};
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_unique_timecard_only() {
+        let t = tester();
+        AddHourlyEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        for hours in [4, 3] {
+            TimeCardTx::execute(&t, 1, ymd(2024, 8, 5), Hours::from_hours(hours))
+                .run(&mut ())
+                .unwrap();
+        }
+
+        let result =
+            AmendTimeCardTx::execute(&t, 1, ymd(2024, 8, 5), Hours::from_hours(8)).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::AmbiguousEntry(_))));
+        let result =
+            AmendTimeCardTx::execute(&t, 1, ymd(2024, 8, 6), Hours::from_hours(8)).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::EntryNotFound(_))));
+    }
+
+    #[test]
+    fn test_clocked_timecard() {
+        let t = tester();
+        AddHourlyEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        let time = |h| NaiveTime::from_hms_opt(h, 0, 0).unwrap();
+        ClockTimeCardTx::execute(&t, 1, ymd(2024, 8, 5), time(9), time(17))
+            .run(&mut ())
+            .unwrap();
+
+        let result =
+            AmendTimeCardTx::execute(&t, 1, ymd(2024, 8, 5), Hours::from_hours(4)).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::ClockedEntry(_))));
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(12000));
+    }
+}
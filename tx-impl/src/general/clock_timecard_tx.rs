@@ -0,0 +1,66 @@
+use chrono::{NaiveDate, NaiveTime};
+
+use abstract_tx::{AddEntryTx, UsecaseError};
+use payroll_domain::EmployeeId;
+use payroll_impl::{PaymentClassificationImpl, TimeCard};
+
+pub trait ClockTimeCardTx<Ctx>: AddEntryTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        clock_in: NaiveTime,
+        clock_out: NaiveTime,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        AddEntryTx::execute(self, emp_id, date, "late timecard", move |_, emp| {
+            let tc = TimeCard::clocked(date, clock_in, clock_out).ok_or(
+                UsecaseError::InvalidShift(format!(
+                    "emp_id: {}, date: {}, {} - {}",
+                    emp_id, date, clock_in, clock_out
+                )),
+            )?;
+            emp.get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+                .ok_or(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected hourly emp_id: {}",
+                    emp_id
+                )))?
+                .add_timecard(tc);
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ClockTimeCardTx<Ctx> for T where T: AddEntryTx<Ctx> {}
+
+#[cfg(test)]
+mod test_clock_timecard {
+    use super::*;
+    use payroll_domain::Money;
+    use tx_rs::Tx;
+
+    use crate::general::{AddHourlyEmployeeTx, PaydayTx};
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_no_time_between() {
+        let t = tester();
+        AddHourlyEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        let time = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+
+        let result = ClockTimeCardTx::execute(&t, 1, ymd(2024, 8, 5), time, time).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::InvalidShift(_))));
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::ZERO);
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::EmployeeId;
+use payroll_impl::PaymentClassificationImpl;
+
+pub trait DeleteTimeCardTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            if self
+                .dao()
+                .is_paid_on(emp_id, date)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+            {
+                return Err(UsecaseError::AlreadyPaid(format!(
+                    "emp_id: {}, date: {}",
+                    emp_id, date
+                )));
+            }
+            let removed = match emp
+                .get_classification()
+                .borrow_mut()
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            {
+                Some(c @ PaymentClassificationImpl::Hourly { .. }) => c.remove_timecards(date),
+                _ => {
+                    return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                        "expected hourly emp_id: {}",
+                        emp_id
+                    )))
+                }
+            };
+            if removed.is_empty() {
+                return Err(UsecaseError::EntryNotFound(format!(
+                    "emp_id: {}, date: {}",
+                    emp_id, date
+                )));
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> DeleteTimeCardTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -2,37 +2,79 @@ use chrono::NaiveDate;
 use tx_rs::Tx;
 
 use abstract_tx::UsecaseError;
-use dao::{HavePayrollDao, PayrollDao};
-use payroll_domain::Paycheck;
 
-pub trait PaydayTx<Ctx>: HavePayrollDao<Ctx> {
+use crate::payroll_run::{IssuePaychecksTx, PreparePayrollTx};
+use crate::scheduled::ApplyScheduledChangesTx;
+
+pub trait PaydayTx<Ctx>:
+    PreparePayrollTx<Ctx> + ApplyScheduledChangesTx<Ctx> + IssuePaychecksTx<Ctx>
+{
+    // a rerun pays the periods that were already paid once more, for corrections
     fn execute<'a>(
         &'a self,
         pay_date: NaiveDate,
+        rerun: bool,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
         tx_rs::with_tx(move |ctx| {
-            let emps = self
-                .dao()
-                .fetch_all()
-                .run(ctx)
-                .map_err(UsecaseError::GetAllFailed)?;
-            for emp in emps {
-                if emp.is_pay_date(pay_date) {
-                    let period = emp.get_pay_period(pay_date);
-                    let mut pc = Paycheck::new(period);
-                    emp.payday(&mut pc);
-                    self.dao()
-                        .record_paycheck(emp.get_emp_id(), pc)
-                        .run(ctx)
-                        .map_err(UsecaseError::UpdateEmployeeFailed)?;
-                }
-            }
-            Ok(())
+            let run = PreparePayrollTx::execute(self, pay_date, rerun).run(ctx)?;
+            ApplyScheduledChangesTx::execute(self, pay_date).run(ctx)?;
+            IssuePaychecksTx::execute(self, run).run(ctx)
         })
     }
 }
 // blanket implementation
-impl<T, Ctx> PaydayTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+impl<T, Ctx> PaydayTx<Ctx> for T where
+    T: PreparePayrollTx<Ctx> + ApplyScheduledChangesTx<Ctx> + IssuePaychecksTx<Ctx>
+{
+}
+
+#[cfg(test)]
+mod test_payday {
+    use super::*;
+
+    use payroll_domain::Money;
+
+    use crate::general::AddSalaryEmployeeTx;
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_already_paid() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+        AddSalaryEmployeeTx::execute(&t, 2, "Alice", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+
+        let result = PaydayTx::execute(&t, ymd(2024, 8, 31), false).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::AlreadyPaid(_))));
+        // nobody is paid when the run is refused
+        assert_eq!(paychecks(&t, 1).len(), 1);
+        assert!(paychecks(&t, 2).is_empty());
+    }
+
+    #[test]
+    fn test_rerun() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        PaydayTx::execute(&t, ymd(2024, 8, 31), true)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs.len(), 2);
+        assert_eq!(pcs[1].get_period(), pcs[0].get_period());
+    }
+}
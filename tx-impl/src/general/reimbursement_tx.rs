@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::{EmployeeId, Money, Reimbursement};
+
+pub trait ReimbursementTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    // a late claim is repaid on the next paycheck, so a paid period doesn't lock it
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        description: &str,
+        amount: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            emp.add_reimbursement(Reimbursement::new(date, description, amount));
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ReimbursementTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
+
+#[cfg(test)]
+mod test_reimbursement {
+    use super::*;
+    use tx_rs::Tx;
+
+    use crate::general::{AddSalaryEmployeeTx, PaydayTx};
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_late_claim() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 7, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        ReimbursementTx::execute(&t, 1, ymd(2024, 7, 30), "Taxi", Money::from_cents(2000))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[1].get_total_reimbursements(), Money::from_cents(2000));
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(300000));
+    }
+}
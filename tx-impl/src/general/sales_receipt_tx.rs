@@ -1,27 +1,21 @@
 use chrono::NaiveDate;
-use tx_rs::Tx;
 
-use abstract_tx::UsecaseError;
-use dao::{HavePayrollDao, PayrollDao};
-use payroll_domain::EmployeeId;
+use abstract_tx::{AddEntryTx, UsecaseError};
+use payroll_domain::{EmployeeId, Money};
 use payroll_impl::{PaymentClassificationImpl, SalesReceipt};
 
-pub trait SalesReceiptTx<Ctx>: HavePayrollDao<Ctx> {
+pub trait SalesReceiptTx<Ctx>: AddEntryTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
         date: NaiveDate,
-        amount: f32,
+        amount: Money,
+        product: Option<&str>,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
-        tx_rs::with_tx(move |ctx| {
-            let emp = self
-                .dao()
-                .fetch(emp_id)
-                .run(ctx)
-                .map_err(UsecaseError::NotFound)?;
+        AddEntryTx::execute(self, emp_id, date, "late sales receipt", move |_, emp| {
             emp.get_classification()
                 .borrow_mut()
                 .as_any_mut()
@@ -30,13 +24,13 @@ pub trait SalesReceiptTx<Ctx>: HavePayrollDao<Ctx> {
                     "expected commissioned emp_id: {}",
                     emp_id
                 )))?
-                .add_sales_receipt(SalesReceipt::new(date, amount));
-            self.dao()
-                .update(emp)
-                .run(ctx)
-                .map_err(UsecaseError::UpdateEmployeeFailed)
+                .add_sales_receipt(match product {
+                    Some(product) => SalesReceipt::of_product(date, amount, product),
+                    None => SalesReceipt::new(date, amount),
+                });
+            Ok(())
         })
     }
 }
 // blanket implementation
-impl<T, Ctx> SalesReceiptTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+impl<T, Ctx> SalesReceiptTx<Ctx> for T where T: AddEntryTx<Ctx> {}
@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::EmployeeId;
+
+pub trait TerminateEmployeeTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        last_day: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            if emp.get_hire_date().is_some_and(|hired| last_day < hired) {
+                return Err(UsecaseError::InvalidEmploymentDate(format!(
+                    "terminated before hired emp_id: {}",
+                    emp_id
+                )));
+            }
+            emp.set_termination_date(last_day);
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> TerminateEmployeeTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
@@ -1,27 +1,20 @@
 use chrono::NaiveDate;
-use tx_rs::Tx;
 
-use abstract_tx::UsecaseError;
-use dao::{HavePayrollDao, PayrollDao};
-use payroll_domain::EmployeeId;
+use abstract_tx::{AddEntryTx, UsecaseError};
+use payroll_domain::{EmployeeId, Hours};
 use payroll_impl::{PaymentClassificationImpl, TimeCard};
 
-pub trait TimeCardTx<Ctx>: HavePayrollDao<Ctx> {
+pub trait TimeCardTx<Ctx>: AddEntryTx<Ctx> {
     fn execute<'a>(
         &'a self,
         emp_id: EmployeeId,
         date: NaiveDate,
-        hours: f32,
+        hours: Hours,
     ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
     where
         Ctx: 'a,
     {
-        tx_rs::with_tx(move |ctx| {
-            let emp = self
-                .dao()
-                .fetch(emp_id)
-                .run(ctx)
-                .map_err(UsecaseError::NotFound)?;
+        AddEntryTx::execute(self, emp_id, date, "late timecard", move |_, emp| {
             emp.get_classification()
                 .borrow_mut()
                 .as_any_mut()
@@ -31,12 +24,9 @@ pub trait TimeCardTx<Ctx>: HavePayrollDao<Ctx> {
                     emp_id
                 )))?
                 .add_timecard(TimeCard::new(date, hours));
-            self.dao()
-                .update(emp)
-                .run(ctx)
-                .map_err(UsecaseError::UpdateEmployeeFailed)
+            Ok(())
         })
     }
 }
 // blanket implementation
-impl<T, Ctx> TimeCardTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+impl<T, Ctx> TimeCardTx<Ctx> for T where T: AddEntryTx<Ctx> {}
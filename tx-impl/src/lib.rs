@@ -1,4 +1,16 @@
 pub mod affiliation;
+pub mod calendar;
 pub mod classification;
+pub mod commission;
+pub mod garnishment;
 pub mod general;
+pub mod loan;
 pub mod method;
+pub mod payroll_run;
+pub mod pto;
+pub mod schedule;
+pub mod scheduled;
+pub mod withholding;
+
+#[cfg(test)]
+mod test_util;
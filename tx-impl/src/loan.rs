@@ -0,0 +1,5 @@
+mod add_loan_tx;
+mod list_loans_tx;
+
+pub use add_loan_tx::AddLoanTx;
+pub use list_loans_tx::ListLoansTx;
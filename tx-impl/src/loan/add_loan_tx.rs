@@ -0,0 +1,99 @@
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::{EmployeeId, Loan, LoanRepayment, Money};
+
+pub trait AddLoanTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        name: &str,
+        principal: Money,
+        repayment: LoanRepayment,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            if principal <= Money::ZERO {
+                return Err(UsecaseError::UnexpectedLoan(format!(
+                    "loan {} lends nothing emp_id: {}",
+                    name, emp_id
+                )));
+            }
+            if matches!(
+                repayment,
+                LoanRepayment::Installment { amount } if amount <= Money::ZERO
+            ) || matches!(repayment, LoanRepayment::Count { count: 0 })
+            {
+                return Err(UsecaseError::UnexpectedLoan(format!(
+                    "loan {} is never repaid emp_id: {}",
+                    name, emp_id
+                )));
+            }
+            if emp.get_loans().iter().any(|l| l.get_name() == name) {
+                return Err(UsecaseError::UnexpectedLoan(format!(
+                    "already lent {} emp_id: {}",
+                    name, emp_id
+                )));
+            }
+            emp.add_loan(Loan::new(name, principal, repayment));
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> AddLoanTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
+
+#[cfg(test)]
+mod test_add_loan {
+    use super::*;
+    use tx_rs::Tx;
+
+    use crate::general::AddSalaryEmployeeTx;
+    use crate::loan::ListLoansTx;
+    use crate::test_util::tester;
+
+    #[test]
+    fn test_never_repaid() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+
+        let result = AddLoanTx::execute(
+            &t,
+            1,
+            "Advance",
+            Money::from_cents(10000),
+            LoanRepayment::Count { count: 0 },
+        )
+        .run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::UnexpectedLoan(_))));
+        assert!(ListLoansTx::execute(&t, 1).run(&mut ()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_not_positive() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+
+        for (principal, repayment) in [
+            (Money::ZERO, Money::from_cents(1000)),
+            (Money::from_cents(-10000), Money::from_cents(1000)),
+            (Money::from_cents(10000), Money::ZERO),
+            (Money::from_cents(10000), Money::from_cents(-1000)),
+        ] {
+            let result = AddLoanTx::execute(
+                &t,
+                1,
+                "Advance",
+                principal,
+                LoanRepayment::Installment { amount: repayment },
+            )
+            .run(&mut ());
+            assert!(matches!(result, Err(UsecaseError::UnexpectedLoan(_))));
+        }
+        assert!(ListLoansTx::execute(&t, 1).run(&mut ()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,23 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{EmployeeId, Loan};
+
+pub trait ListLoansTx<Ctx>: HavePayrollDao<Ctx> {
+    // the loans of the employee with their remaining balances
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+    ) -> impl tx_rs::Tx<Ctx, Item = Vec<Loan>, Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .fetch(emp_id)
+            .map(|emp| emp.get_loans().to_vec())
+            .map_err(UsecaseError::NotFound)
+    }
+}
+// blanket implementation
+impl<T, Ctx> ListLoansTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
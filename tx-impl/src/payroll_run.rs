@@ -0,0 +1,23 @@
+mod approve_payroll_tx;
+mod calculate_payroll_tx;
+mod change_late_entry_policy_tx;
+mod discard_payroll_tx;
+mod issue_paychecks_tx;
+mod off_cycle_paycheck_tx;
+mod prepare_payroll_tx;
+mod retro_pay_tx;
+mod review_payroll_tx;
+mod void_paycheck_tx;
+mod void_payroll_run_tx;
+
+pub use approve_payroll_tx::ApprovePayrollTx;
+pub use calculate_payroll_tx::CalculatePayrollTx;
+pub use change_late_entry_policy_tx::ChangeLateEntryPolicyTx;
+pub use discard_payroll_tx::DiscardPayrollTx;
+pub use issue_paychecks_tx::IssuePaychecksTx;
+pub use off_cycle_paycheck_tx::OffCyclePaycheckTx;
+pub use prepare_payroll_tx::PreparePayrollTx;
+pub use retro_pay_tx::RetroPayTx;
+pub use review_payroll_tx::ReviewPayrollTx;
+pub use void_paycheck_tx::VoidPaycheckTx;
+pub use void_payroll_run_tx::VoidPayrollRunTx;
@@ -0,0 +1,216 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::PayrollDao;
+
+use super::IssuePaychecksTx;
+use crate::scheduled::ApplyScheduledChangesTx;
+
+pub trait ApprovePayrollTx<Ctx>: ApplyScheduledChangesTx<Ctx> + IssuePaychecksTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let run = self
+                .dao()
+                .fetch_payroll_run(pay_date)
+                .run(ctx)
+                .map_err(UsecaseError::PayrollRunNotFound)?;
+            // another run may have paid these periods since the draft was calculated
+            if !run.is_rerun() {
+                let mut paid = vec![];
+                for (emp_id, pc) in run.get_paychecks() {
+                    if self
+                        .dao()
+                        .is_paid(*emp_id, pc.get_period())
+                        .run(ctx)
+                        .map_err(UsecaseError::GetPaychecksFailed)?
+                    {
+                        paid.push(*emp_id);
+                    }
+                }
+                if !paid.is_empty() {
+                    paid.sort();
+                    return Err(UsecaseError::AlreadyPaid(format!(
+                        "pay_date: {}, emp_ids: {:?}",
+                        pay_date, paid
+                    )));
+                }
+            }
+            // an employee may have been deleted since; refuse before anything is written
+            for (emp_id, _) in run.get_paychecks() {
+                self.dao()
+                    .fetch(*emp_id)
+                    .run(ctx)
+                    .map_err(UsecaseError::NotFound)?;
+            }
+            ApplyScheduledChangesTx::execute(self, pay_date).run(ctx)?;
+            IssuePaychecksTx::execute(self, run).run(ctx)?;
+            self.dao()
+                .remove_payroll_run(pay_date)
+                .run(ctx)
+                .map_err(UsecaseError::DiscardPayrollRunFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ApprovePayrollTx<Ctx> for T where
+    T: ApplyScheduledChangesTx<Ctx> + IssuePaychecksTx<Ctx>
+{
+}
+
+#[cfg(test)]
+mod test_approve_payroll {
+    use super::*;
+    use payroll_domain::{Deduction, Hours, Money, Paycheck};
+
+    use crate::general::{
+        AddHourlyEmployeeTx, AddSalaryEmployeeTx, DeleteEmployeeTx, PaydayTx, TimeCardTx,
+    };
+    use crate::payroll_run::{CalculatePayrollTx, DiscardPayrollTx, ReviewPayrollTx};
+    use crate::scheduled::{ScheduleEmployeeAddressTx, ScheduleUnionMemberTx};
+    use crate::test_util::{paychecks, tester, ymd, Tester};
+
+    fn scheduled(t: &Tester) -> usize {
+        t.db.fetch_scheduled_changes().run(&mut ()).unwrap().len()
+    }
+
+    #[test]
+    fn test_calculate_discard_approve() {
+        let t = tester();
+        let pay_date = ymd(2024, 8, 31);
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+        ScheduleUnionMemberTx::execute(&t, 1, pay_date, 7, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+        let has_dues = |pc: &Paycheck| {
+            pc.get_deductions()
+                .iter()
+                .any(|d| matches!(d, Deduction::UnionDues { .. }))
+        };
+
+        // the draft reflects the scheduled change without applying it
+        CalculatePayrollTx::execute(&t, pay_date, false)
+            .run(&mut ())
+            .unwrap();
+        let run = t.db.fetch_payroll_run(pay_date).run(&mut ()).unwrap();
+        assert!(has_dues(&run.get_paychecks()[0].1));
+        assert_eq!(scheduled(&t), 1);
+        assert!(t.db.find_union_member(7).run(&mut ()).is_err());
+
+        DiscardPayrollTx::execute(&t, pay_date)
+            .run(&mut ())
+            .unwrap();
+        assert!(t.db.fetch_payroll_run(pay_date).run(&mut ()).is_err());
+        assert_eq!(scheduled(&t), 1);
+        assert!(paychecks(&t, 1).is_empty());
+
+        CalculatePayrollTx::execute(&t, pay_date, false)
+            .run(&mut ())
+            .unwrap();
+        ApprovePayrollTx::execute(&t, pay_date)
+            .run(&mut ())
+            .unwrap();
+        assert!(t.db.fetch_payroll_run(pay_date).run(&mut ()).is_err());
+        assert_eq!(scheduled(&t), 0);
+        assert_eq!(t.db.find_union_member(7).run(&mut ()), Ok(1));
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs.len(), 1);
+        assert!(has_dues(&pcs[0]));
+    }
+
+    #[test]
+    fn test_refused_payday_keeps_scheduled_changes() {
+        let t = tester();
+        let pay_date = ymd(2024, 8, 31);
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+        CalculatePayrollTx::execute(&t, pay_date, false)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, pay_date, false).run(&mut ()).unwrap();
+        ScheduleEmployeeAddressTx::execute(&t, 1, pay_date, "123 Wall St.")
+            .run(&mut ())
+            .unwrap();
+
+        let result = PaydayTx::execute(&t, pay_date, false).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::AlreadyPaid(_))));
+        assert_eq!(scheduled(&t), 1);
+        // the draft was paid by the payday since it was calculated
+        let result = ApprovePayrollTx::execute(&t, pay_date).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::AlreadyPaid(_))));
+        assert_eq!(scheduled(&t), 1);
+        assert_eq!(paychecks(&t, 1).len(), 1);
+    }
+
+    #[test]
+    fn test_approve_pays_the_reviewed_draft() {
+        let t = tester();
+        let pay_date = ymd(2024, 8, 9);
+        AddHourlyEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        TimeCardTx::execute(&t, 1, ymd(2024, 8, 5), Hours::from_hours(8))
+            .run(&mut ())
+            .unwrap();
+        CalculatePayrollTx::execute(&t, pay_date, false)
+            .run(&mut ())
+            .unwrap();
+        let draft = ReviewPayrollTx::execute(&t, pay_date).run(&mut ()).unwrap();
+        assert_eq!(
+            draft.get_paychecks()[0].1.get_gross_pay(),
+            Money::from_cents(12000)
+        );
+
+        // entered after the review, so left for a correction
+        TimeCardTx::execute(&t, 1, ymd(2024, 8, 6), Hours::from_hours(8))
+            .run(&mut ())
+            .unwrap();
+        ApprovePayrollTx::execute(&t, pay_date)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(12000));
+    }
+
+    #[test]
+    fn test_employee_deleted_after_review() {
+        let t = tester();
+        let pay_date = ymd(2024, 8, 31);
+        for emp_id in [1, 2] {
+            AddSalaryEmployeeTx::execute(
+                &t,
+                emp_id,
+                "Bob",
+                "Home",
+                Money::from_cents(100000),
+                None,
+            )
+            .run(&mut ())
+            .unwrap();
+        }
+        ScheduleEmployeeAddressTx::execute(&t, 1, pay_date, "123 Wall St.")
+            .run(&mut ())
+            .unwrap();
+        CalculatePayrollTx::execute(&t, pay_date, false)
+            .run(&mut ())
+            .unwrap();
+        ReviewPayrollTx::execute(&t, pay_date).run(&mut ()).unwrap();
+        DeleteEmployeeTx::execute(&t, 2).run(&mut ()).unwrap();
+
+        // nothing of the draft is paid or applied
+        let result = ApprovePayrollTx::execute(&t, pay_date).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::NotFound(_))));
+        assert!(paychecks(&t, 1).is_empty());
+        assert_eq!(scheduled(&t), 1);
+        assert!(t.db.fetch_payroll_run(pay_date).run(&mut ()).is_ok());
+    }
+}
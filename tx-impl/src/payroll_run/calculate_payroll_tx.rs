@@ -0,0 +1,28 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::PayrollDao;
+
+use super::PreparePayrollTx;
+
+pub trait CalculatePayrollTx<Ctx>: PreparePayrollTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        pay_date: NaiveDate,
+        rerun: bool,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let run = PreparePayrollTx::execute(self, pay_date, rerun).run(ctx)?;
+            self.dao()
+                .save_payroll_run(run)
+                .run(ctx)
+                .map_err(UsecaseError::SavePayrollRunFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> CalculatePayrollTx<Ctx> for T where T: PreparePayrollTx<Ctx> {}
@@ -0,0 +1,21 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::LateEntryPolicy;
+
+pub trait ChangeLateEntryPolicyTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        policy: LateEntryPolicy,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .set_late_entry_policy(policy)
+            .map_err(UsecaseError::SetLateEntryPolicyFailed)
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeLateEntryPolicyTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
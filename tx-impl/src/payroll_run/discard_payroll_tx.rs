@@ -0,0 +1,21 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+
+pub trait DiscardPayrollTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .remove_payroll_run(pay_date)
+            .map_err(UsecaseError::DiscardPayrollRunFailed)
+    }
+}
+// blanket implementation
+impl<T, Ctx> DiscardPayrollTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
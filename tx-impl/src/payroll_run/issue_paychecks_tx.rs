@@ -0,0 +1,36 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::PayrollRun;
+
+pub trait IssuePaychecksTx<Ctx>: HavePayrollDao<Ctx> {
+    // pay each paycheck of the run by the employee's payment method and record it
+    fn execute<'a>(&'a self, run: PayrollRun) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            for (emp_id, pc) in run.into_paychecks() {
+                let mut emp = self
+                    .dao()
+                    .fetch(emp_id)
+                    .run(ctx)
+                    .map_err(UsecaseError::NotFound)?;
+                emp.pay(&pc);
+                emp.settle(&pc);
+                self.dao()
+                    .update(emp)
+                    .run(ctx)
+                    .map_err(UsecaseError::UpdateEmployeeFailed)?;
+                self.dao()
+                    .record_paycheck(emp_id, pc)
+                    .run(ctx)
+                    .map_err(UsecaseError::UpdateEmployeeFailed)?;
+            }
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> IssuePaychecksTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,314 @@
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{Earning, EmployeeId, Paycheck};
+
+pub trait OffCyclePaycheckTx<Ctx>: HavePayrollDao<Ctx> {
+    // pay one employee for an explicit period outside the schedule, e.g. a final check;
+    // the extras and whatever no other paycheck paid for within the period are on it
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        period: RangeInclusive<NaiveDate>,
+        extras: Vec<Earning>,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            if period.is_empty() {
+                return Err(UsecaseError::InvalidPayPeriod(format!(
+                    "{} is after {}",
+                    period.start(),
+                    period.end()
+                )));
+            }
+            let mut emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            let mut pc = Paycheck::new(period);
+            pc.set_pay_date(pay_date);
+            pc.set_off_cycle();
+            for paid in self
+                .dao()
+                .fetch_paychecks(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+                .iter()
+                .filter(|pc| !pc.is_voided())
+            {
+                pc.leave_out(paid);
+            }
+            // extras go first so that withholding sees them in the gross pay
+            pc.add_earnings(extras);
+            emp.payday(&mut pc);
+            emp.settle(&pc);
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)?;
+            self.dao()
+                .record_paycheck(emp_id, pc)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> OffCyclePaycheckTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+
+#[cfg(test)]
+mod test_off_cycle_paycheck {
+    use super::*;
+    use payroll_domain::{
+        CommissionPlan, CommissionTier, Hours, Money, OvertimePolicy, OvertimeRule, Rate,
+    };
+
+    use crate::classification::{ChangeEmployeeHourlyTx, ChangeEmployeeOvertimeTx};
+    use crate::commission::{AssignCommissionPlanTx, DefineCommissionPlanTx};
+    use crate::general::{
+        AddCommissionedEmployeeTx, AddHourlyEmployeeTx, AddSalaryEmployeeTx, PaydayTx,
+        SalesReceiptTx, TerminateEmployeeTx, TimeCardTx,
+    };
+    use crate::test_util::{paychecks, tester, ymd, Tester};
+
+    fn hourly(t: &Tester) {
+        AddHourlyEmployeeTx::execute(t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        for d in [5, 6] {
+            TimeCardTx::execute(t, 1, ymd(2024, 8, d), Hours::from_hours(8))
+                .run(&mut ())
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_regular_payday_after_off_cycle() {
+        let t = tester();
+        hourly(&t);
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 7),
+            ymd(2024, 8, 3)..=ymd(2024, 8, 9),
+            vec![],
+        )
+        .run(&mut ())
+        .unwrap();
+        // the off-cycle paycheck neither locks the dates nor the regular period
+        TimeCardTx::execute(&t, 1, ymd(2024, 8, 8), Hours::from_hours(4))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(24000));
+        // only the timecard the off-cycle paycheck didn't pay
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(6000));
+    }
+
+    #[test]
+    fn test_paid_period() {
+        let t = tester();
+        hourly(&t);
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+        let extra = Earning::Extra {
+            description: "Missed overtime".to_string(),
+            amount: Money::from_cents(2500),
+        };
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 12),
+            ymd(2024, 8, 3)..=ymd(2024, 8, 9),
+            vec![extra.clone()],
+        )
+        .run(&mut ())
+        .unwrap();
+
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(24000));
+        assert_eq!(pcs[1].get_earnings(), &[extra]);
+    }
+
+    #[test]
+    fn test_prorated_base_pay() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 15),
+            ymd(2024, 8, 1)..=ymd(2024, 8, 15),
+            vec![],
+        )
+        .run(&mut ())
+        .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        // 15 of the 31 days, then the rest of the month
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(145161));
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(154839));
+    }
+
+    #[test]
+    fn test_final_check() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        TerminateEmployeeTx::execute(&t, 1, ymd(2024, 8, 15))
+            .run(&mut ())
+            .unwrap();
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 15),
+            ymd(2024, 8, 1)..=ymd(2024, 8, 15),
+            vec![],
+        )
+        .run(&mut ())
+        .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        // the final check paid everything up to the last day
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(145161));
+        assert!(pcs[1..].iter().all(|pc| pc.get_gross_pay() == Money::ZERO));
+    }
+
+    #[test]
+    fn test_weekly_overtime() {
+        let t = tester();
+        AddHourlyEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(1000), None)
+            .run(&mut ())
+            .unwrap();
+        let policy = OvertimePolicy::new(vec![OvertimeRule::Weekly {
+            threshold: Hours::from_hours(40),
+            multiplier: Rate::from_basis_points(15000),
+        }]);
+        ChangeEmployeeOvertimeTx::execute(&t, 1, policy)
+            .run(&mut ())
+            .unwrap();
+        for d in 5..=9 {
+            TimeCardTx::execute(&t, 1, ymd(2024, 8, d), Hours::from_hours(9))
+                .run(&mut ())
+                .unwrap();
+        }
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 9),
+            ymd(2024, 8, 7)..=ymd(2024, 8, 9),
+            vec![],
+        )
+        .run(&mut ())
+        .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+
+        // 40h straight and 5h at 1.5 over the two paychecks
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(27000));
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(20500));
+    }
+
+    #[test]
+    fn test_rate_change_after_off_cycle() {
+        let t = tester();
+        hourly(&t);
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 7),
+            ymd(2024, 8, 5)..=ymd(2024, 8, 5),
+            vec![],
+        )
+        .run(&mut ())
+        .unwrap();
+        ChangeEmployeeHourlyTx::execute(&t, 1, Money::from_cents(1650), Some(ymd(2024, 8, 1)))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+
+        // the paid timecard isn't paid again at the new rate
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(12000));
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(13200));
+    }
+
+    #[test]
+    fn test_tiered_commission() {
+        let t = tester();
+        AddCommissionedEmployeeTx::execute(
+            &t,
+            1,
+            "Bob",
+            "Home",
+            Money::ZERO,
+            Rate::from_basis_points(500),
+            None,
+        )
+        .run(&mut ())
+        .unwrap();
+        let plan = CommissionPlan::new(
+            "Tiered",
+            vec![
+                CommissionTier::new(Money::ZERO, Rate::from_basis_points(500)),
+                CommissionTier::new(Money::from_cents(200000), Rate::from_basis_points(800)),
+            ],
+            vec![],
+            None,
+        );
+        DefineCommissionPlanTx::execute(&t, plan)
+            .run(&mut ())
+            .unwrap();
+        AssignCommissionPlanTx::execute(&t, 1, "Tiered")
+            .run(&mut ())
+            .unwrap();
+        SalesReceiptTx::execute(&t, 1, ymd(2024, 8, 5), Money::from_cents(150000), None)
+            .run(&mut ())
+            .unwrap();
+        SalesReceiptTx::execute(&t, 1, ymd(2024, 8, 6), Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 7),
+            ymd(2024, 8, 6)..=ymd(2024, 8, 6),
+            vec![],
+        )
+        .run(&mut ())
+        .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+
+        // 2000.00 at 5% and 500.00 at 8% over the two paychecks
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[0].get_gross_pay(), Money::from_cents(5000));
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(9000));
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{HolidayCalendar, Paycheck, PayrollRun};
+use payroll_impl::HolidayCalendarImpl;
+
+pub trait PreparePayrollTx<Ctx>: HavePayrollDao<Ctx> {
+    // calculate the paychecks due on `pay_date` without paying them, nor applying
+    // the changes scheduled by then, though the paychecks reflect them
+    fn execute<'a>(
+        &'a self,
+        pay_date: NaiveDate,
+        rerun: bool,
+    ) -> impl tx_rs::Tx<Ctx, Item = PayrollRun, Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let calendar: Rc<RefCell<dyn HolidayCalendar>> = self
+                .dao()
+                .fetch_holiday_calendar()
+                .run(ctx)
+                .map_err(UsecaseError::GetHolidayCalendarFailed)?
+                .unwrap_or(Rc::new(RefCell::new(HolidayCalendarImpl::NoHolidays)));
+            let emps = self
+                .dao()
+                .fetch_all()
+                .run(ctx)
+                .map_err(UsecaseError::GetAllFailed)?;
+            let scheduled = self
+                .dao()
+                .fetch_scheduled_changes()
+                .run(ctx)
+                .map_err(UsecaseError::GetScheduledChangesFailed)?
+                .into_iter()
+                .filter(|c| c.get_effective() <= pay_date)
+                .collect::<Vec<_>>();
+
+            let mut run = PayrollRun::new(pay_date, rerun);
+            let mut paid = vec![];
+            for mut emp in emps {
+                let emp_id = emp.get_emp_id();
+                for change in scheduled.iter().filter(|c| c.get_emp_id() == emp_id) {
+                    change.get_change().clone().apply(&mut emp);
+                }
+                if let Some(payday) = emp.get_nominal_pay_date(pay_date, &*calendar.borrow()) {
+                    let period = emp.get_pay_period(payday);
+                    if emp.get_employed_period(&period).is_none() {
+                        continue;
+                    }
+                    if self
+                        .dao()
+                        .is_paid(emp.get_emp_id(), period.clone())
+                        .run(ctx)
+                        .map_err(UsecaseError::GetPaychecksFailed)?
+                    {
+                        paid.push(emp.get_emp_id());
+                    }
+                    let mut pc = Paycheck::new(period);
+                    pc.set_pay_date(pay_date);
+                    for paid in self
+                        .dao()
+                        .fetch_paychecks(emp.get_emp_id())
+                        .run(ctx)
+                        .map_err(UsecaseError::GetPaychecksFailed)?
+                        .iter()
+                        .filter(|pc| !pc.is_voided() && pc.is_off_cycle())
+                    {
+                        pc.leave_out(paid);
+                    }
+                    emp.calculate_pay(&mut pc);
+                    run.add_paycheck(emp.get_emp_id(), pc);
+                }
+            }
+            // refuse the whole run rather than pay some employees twice
+            if !rerun && !paid.is_empty() {
+                paid.sort();
+                return Err(UsecaseError::AlreadyPaid(format!(
+                    "pay_date: {}, emp_ids: {:?}",
+                    pay_date, paid
+                )));
+            }
+            Ok(run)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> PreparePayrollTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,174 @@
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{Earning, EmployeeId, Money, Paycheck};
+
+pub trait RetroPayTx<Ctx>: HavePayrollDao<Ctx> {
+    // recompute the paychecks recorded for periods overlapping `range` under the current terms
+    // and carry what is still owed into the next paycheck
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        range: RangeInclusive<NaiveDate>,
+    ) -> impl tx_rs::Tx<Ctx, Item = Vec<Earning>, Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            if range.is_empty() {
+                return Err(UsecaseError::InvalidPayPeriod(format!(
+                    "{} is after {}",
+                    range.start(),
+                    range.end()
+                )));
+            }
+            let mut emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            let paychecks = self
+                .dao()
+                .fetch_paychecks(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+                .into_iter()
+                .filter(|pc| !pc.is_voided())
+                .collect::<Vec<_>>();
+            // adjustments already paid or pending, including those of an earlier retro pay
+            let carried = paychecks
+                .iter()
+                .flat_map(|pc| pc.get_earnings())
+                .chain(emp.get_adjustments())
+                .filter(|e| matches!(e, Earning::Adjustment { .. }))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let mut adjustments = vec![];
+            for pc in paychecks.iter().filter(|pc| {
+                let period = pc.get_period();
+                !pc.is_off_cycle()
+                    && *period.start() <= *range.end()
+                    && *range.start() <= *period.end()
+            }) {
+                let period = pc.get_period();
+                let mut recomputed = Paycheck::new(period.clone());
+                recomputed.set_employed_period(pc.get_employed_period());
+                // what an off-cycle paycheck paid for is left out as on the paycheck
+                for off_cycle in paychecks.iter().filter(|pc| pc.is_off_cycle()) {
+                    recomputed.leave_out(off_cycle);
+                }
+                let due: Money = emp
+                    .get_classification()
+                    .borrow()
+                    .calculate_pay(&recomputed)
+                    .iter()
+                    .map(Earning::get_amount)
+                    .sum();
+                let paid: Money = pc
+                    .get_earnings()
+                    .iter()
+                    .filter(|e| e.is_wage())
+                    .map(Earning::get_amount)
+                    .sum();
+                let adjusted: Money = carried
+                    .iter()
+                    .filter(|e| e.get_date().is_some_and(|d| period.contains(&d)))
+                    .map(Earning::get_amount)
+                    .sum();
+                let amount = due - paid - adjusted;
+                if !amount.is_zero() {
+                    adjustments.push(Earning::Adjustment {
+                        date: *period.end(),
+                        description: format!(
+                            "retro pay for {} to {}",
+                            period.start(),
+                            period.end()
+                        ),
+                        amount,
+                    });
+                }
+            }
+            for adjustment in &adjustments {
+                emp.add_adjustment(adjustment.clone());
+            }
+            self.dao()
+                .update(emp)
+                .run(ctx)
+                .map_err(UsecaseError::UpdateEmployeeFailed)?;
+            Ok(adjustments)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> RetroPayTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+
+#[cfg(test)]
+mod test_retro_pay {
+    use super::*;
+
+    use payroll_domain::Hours;
+
+    use crate::classification::ChangeEmployeeHourlyTx;
+    use crate::general::{AddHourlyEmployeeTx, PaydayTx, TimeCardTx};
+    use crate::test_util::{paychecks, tester, ymd, Tester};
+
+    // 8 hours at 15.00 paid for the week ending 2024-08-09, then raised to 16.50 from August
+    fn raised(t: &Tester) {
+        AddHourlyEmployeeTx::execute(t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        TimeCardTx::execute(t, 1, ymd(2024, 8, 5), Hours::from_hours(8))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+        ChangeEmployeeHourlyTx::execute(t, 1, Money::from_cents(1650), Some(ymd(2024, 8, 1)))
+            .run(&mut ())
+            .unwrap();
+    }
+    fn retro_pay(t: &Tester) -> Vec<Earning> {
+        RetroPayTx::execute(t, 1, ymd(2024, 8, 1)..=ymd(2024, 8, 31))
+            .run(&mut ())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_backdated_raise() {
+        let t = tester();
+        raised(&t);
+
+        assert_eq!(
+            retro_pay(&t),
+            [Earning::Adjustment {
+                date: ymd(2024, 8, 9),
+                description: "retro pay for 2024-08-03 to 2024-08-09".to_string(),
+                amount: Money::from_cents(1200),
+            }]
+        );
+        PaydayTx::execute(&t, ymd(2024, 8, 16), false)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(1200));
+    }
+
+    #[test]
+    fn test_earlier_adjustments() {
+        let t = tester();
+        raised(&t);
+        retro_pay(&t);
+
+        // pending on the employee
+        assert!(retro_pay(&t).is_empty());
+        // and once paid
+        PaydayTx::execute(&t, ymd(2024, 8, 16), false)
+            .run(&mut ())
+            .unwrap();
+        assert!(retro_pay(&t).is_empty());
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::PayrollRun;
+
+pub trait ReviewPayrollTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = PayrollRun, Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .fetch_payroll_run(pay_date)
+            .map_err(UsecaseError::PayrollRunNotFound)
+    }
+}
+// blanket implementation
+impl<T, Ctx> ReviewPayrollTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
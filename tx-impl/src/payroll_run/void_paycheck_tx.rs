@@ -0,0 +1,190 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::{EmployeeId, Reversal};
+
+pub trait VoidPaycheckTx<Ctx>: HavePayrollDao<Ctx> {
+    // the voided paycheck is kept, and its period can be paid again;
+    // `off_cycle` tells an off-cycle paycheck from the regular one issued on the same day
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        pay_date: NaiveDate,
+        off_cycle: bool,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let voided = self
+                .dao()
+                .void_paycheck(emp_id, pay_date, off_cycle)
+                .run(ctx)
+                .map_err(UsecaseError::VoidPaycheckFailed)?;
+            // a deleted employee has no balances left to give back
+            if let Ok(mut emp) = self.dao().fetch(emp_id).run(ctx) {
+                emp.unsettle(&voided);
+                self.dao()
+                    .update(emp)
+                    .run(ctx)
+                    .map_err(UsecaseError::UpdateEmployeeFailed)?;
+            }
+            self.dao()
+                .record_reversal(Reversal::new(emp_id, pay_date, &voided))
+                .run(ctx)
+                .map_err(UsecaseError::RecordReversalFailed)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> VoidPaycheckTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+
+#[cfg(test)]
+mod test_void_paycheck {
+    use super::*;
+
+    use payroll_domain::{
+        Earning, GarnishmentAmount, Hours, LateEntryPolicy, LoanRepayment, Money, PtoAccrual,
+    };
+
+    use crate::garnishment::AddGarnishmentTx;
+    use crate::general::{AddBonusTx, AddSalaryEmployeeTx, PaydayTx};
+    use crate::loan::AddLoanTx;
+    use crate::payroll_run::{ChangeLateEntryPolicyTx, OffCyclePaycheckTx};
+    use crate::pto::ChangeEmployeePtoTx;
+    use crate::test_util::{paychecks, tester, ymd};
+
+    #[test]
+    fn test_payday_after_void() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        VoidPaycheckTx::execute(&t, 1, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+        let august = ymd(2024, 8, 1)..=ymd(2024, 8, 31);
+        assert!(!t.db.is_paid(1, august).run(&mut ()).unwrap());
+        // no rerun needed to pay the period again
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+
+        let pcs = paychecks(&t, 1);
+        assert!(pcs[0].is_voided());
+        assert!(!pcs[1].is_voided());
+        assert_eq!(pcs[1].get_gross_pay(), pcs[0].get_gross_pay());
+        let reversals = t.db.fetch_reversals().run(&mut ()).unwrap();
+        assert_eq!(reversals.len(), 1);
+        assert_eq!(reversals[0].get_period(), pcs[0].get_period());
+        assert_eq!(reversals[0].get_net_pay(), -pcs[0].get_net_pay());
+    }
+
+    #[test]
+    fn test_void_off_cycle() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+        let extra = Earning::Extra {
+            description: "Relocation".to_string(),
+            amount: Money::from_cents(50000),
+        };
+        OffCyclePaycheckTx::execute(
+            &t,
+            1,
+            ymd(2024, 8, 31),
+            ymd(2024, 8, 1)..=ymd(2024, 8, 31),
+            vec![extra],
+        )
+        .run(&mut ())
+        .unwrap();
+
+        // the regular paycheck issued the same day stays
+        VoidPaycheckTx::execute(&t, 1, ymd(2024, 8, 31), true)
+            .run(&mut ())
+            .unwrap();
+        let pcs = paychecks(&t, 1);
+        assert!(!pcs[0].is_voided());
+        assert!(pcs[1].is_voided());
+        let reversals = t.db.fetch_reversals().run(&mut ()).unwrap();
+        assert_eq!(reversals.len(), 1);
+        assert_eq!(reversals[0].get_net_pay(), -pcs[1].get_net_pay());
+
+        let result = VoidPaycheckTx::execute(&t, 1, ymd(2024, 8, 31), true).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::VoidPaycheckFailed(_))));
+    }
+
+    #[test]
+    fn test_void_gives_balances_back() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(300000), None)
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(&t, ymd(2024, 7, 31), false)
+            .run(&mut ())
+            .unwrap();
+        // a late bonus carried forward as an adjustment to the August paycheck
+        ChangeLateEntryPolicyTx::execute(&t, LateEntryPolicy::CarryForward)
+            .run(&mut ())
+            .unwrap();
+        AddBonusTx::execute(&t, 1, ymd(2024, 7, 20), "Q2", Money::from_cents(50000))
+            .run(&mut ())
+            .unwrap();
+        let repayment = LoanRepayment::Installment {
+            amount: Money::from_cents(10000),
+        };
+        AddLoanTx::execute(&t, 1, "Car", Money::from_cents(100000), repayment)
+            .run(&mut ())
+            .unwrap();
+        let amount = GarnishmentAmount::Fixed {
+            amount: Money::from_cents(10000),
+        };
+        AddGarnishmentTx::execute(
+            &t,
+            1,
+            "Support",
+            amount,
+            None,
+            Money::from_cents(50000),
+            false,
+        )
+        .run(&mut ())
+        .unwrap();
+        let accrual = PtoAccrual::PerPeriod {
+            hours: Hours::from_hours(8),
+        };
+        ChangeEmployeePtoTx::execute(&t, 1, accrual, Some(Hours::ZERO))
+            .run(&mut ())
+            .unwrap();
+        let before = t.db.fetch(1).run(&mut ()).unwrap();
+        assert_eq!(before.get_adjustments().len(), 1);
+
+        PaydayTx::execute(&t, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+        let paid = t.db.fetch(1).run(&mut ()).unwrap();
+        assert!(paid.get_adjustments().is_empty());
+        assert_ne!(paid.get_loans(), before.get_loans());
+        assert_ne!(paid.get_garnishments(), before.get_garnishments());
+        assert_ne!(paid.get_pto(), before.get_pto());
+
+        VoidPaycheckTx::execute(&t, 1, ymd(2024, 8, 31), false)
+            .run(&mut ())
+            .unwrap();
+        let voided = t.db.fetch(1).run(&mut ()).unwrap();
+        assert_eq!(voided.get_adjustments(), before.get_adjustments());
+        assert_eq!(voided.get_loans(), before.get_loans());
+        assert_eq!(voided.get_garnishments(), before.get_garnishments());
+        assert_eq!(voided.get_pto(), before.get_pto());
+    }
+}
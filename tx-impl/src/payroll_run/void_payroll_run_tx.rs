@@ -0,0 +1,45 @@
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::PayrollDao;
+
+use super::VoidPaycheckTx;
+
+pub trait VoidPayrollRunTx<Ctx>: VoidPaycheckTx<Ctx> {
+    // void every regular paycheck issued on `pay_date`
+    fn execute<'a>(
+        &'a self,
+        pay_date: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let emp_ids: BTreeSet<_> = self
+                .dao()
+                .fetch_all_paychecks()
+                .run(ctx)
+                .map_err(UsecaseError::GetPaychecksFailed)?
+                .into_iter()
+                .filter(|(_, pc)| {
+                    !pc.is_voided() && !pc.is_off_cycle() && pc.get_pay_date() == Some(pay_date)
+                })
+                .map(|(emp_id, _)| emp_id)
+                .collect();
+            if emp_ids.is_empty() {
+                return Err(UsecaseError::PaycheckNotFound(format!(
+                    "pay_date: {}",
+                    pay_date
+                )));
+            }
+            for emp_id in emp_ids {
+                VoidPaycheckTx::execute(self, emp_id, pay_date, false).run(ctx)?;
+            }
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> VoidPayrollRunTx<Ctx> for T where T: VoidPaycheckTx<Ctx> {}
@@ -0,0 +1,5 @@
+mod change_employee_pto_tx;
+mod leave_tx;
+
+pub use change_employee_pto_tx::ChangeEmployeePtoTx;
+pub use leave_tx::LeaveTx;
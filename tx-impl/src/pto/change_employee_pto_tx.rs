@@ -0,0 +1,25 @@
+use abstract_tx::{ChangeEmployeeTx, UsecaseError};
+use payroll_domain::{EmployeeId, Hours, PaidTimeOff, PtoAccrual};
+
+pub trait ChangeEmployeePtoTx<Ctx>: ChangeEmployeeTx<Ctx> {
+    // a new accrual keeps the balance unless one is given
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        accrual: PtoAccrual,
+        balance: Option<Hours>,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeeTx::execute(self, emp_id, move |_, emp| {
+            let balance = balance
+                .or(emp.get_pto().map(PaidTimeOff::get_balance))
+                .unwrap_or(Hours::ZERO);
+            emp.set_pto(PaidTimeOff::new(accrual, balance));
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeePtoTx<Ctx> for T where T: ChangeEmployeeTx<Ctx> {}
@@ -0,0 +1,116 @@
+use chrono::NaiveDate;
+
+use abstract_tx::{AddEntryTx, UsecaseError};
+use payroll_domain::{EmployeeId, Hours};
+use payroll_impl::{PaymentClassificationImpl, TimeCard};
+
+pub trait LeaveTx<Ctx>: AddEntryTx<Ctx> {
+    // take paid time off out of the balance
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        date: NaiveDate,
+        hours: Hours,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        AddEntryTx::execute(self, emp_id, date, "late leave", move |_, emp| {
+            let classification = emp.get_classification();
+            let mut classification = classification.borrow_mut();
+            let Some(hourly @ PaymentClassificationImpl::Hourly { .. }) = classification
+                .as_any_mut()
+                .downcast_mut::<PaymentClassificationImpl>()
+            else {
+                return Err(UsecaseError::UnexpectedPaymentClassification(format!(
+                    "expected hourly emp_id: {}",
+                    emp_id
+                )));
+            };
+            let Some(pto) = emp.get_pto_mut() else {
+                return Err(UsecaseError::InsufficientLeaveBalance(format!(
+                    "no paid time off emp_id: {}",
+                    emp_id
+                )));
+            };
+            let balance = pto.get_balance();
+            if hours <= Hours::ZERO || !pto.take(hours) {
+                return Err(UsecaseError::InsufficientLeaveBalance(format!(
+                    "{} requested, {} left emp_id: {}",
+                    hours, balance, emp_id
+                )));
+            }
+            hourly.add_timecard(TimeCard::leave(date, hours));
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> LeaveTx<Ctx> for T where T: AddEntryTx<Ctx> {}
+
+#[cfg(test)]
+mod test_leave {
+    use super::*;
+    use tx_rs::Tx;
+
+    use dao::PayrollDao;
+    use payroll_domain::{LateEntryPolicy, Money, PtoAccrual};
+
+    use crate::general::{AddHourlyEmployeeTx, PaydayTx};
+    use crate::payroll_run::ChangeLateEntryPolicyTx;
+    use crate::pto::ChangeEmployeePtoTx;
+    use crate::test_util::{paychecks, tester, ymd, Tester};
+
+    // an hourly employee paid for the week ending 2024-08-09
+    fn paid(t: &Tester) -> Hours {
+        AddHourlyEmployeeTx::execute(t, 1, "Bob", "Home", Money::from_cents(1500), None)
+            .run(&mut ())
+            .unwrap();
+        let accrual = PtoAccrual::PerPeriod {
+            hours: Hours::from_hours(8),
+        };
+        ChangeEmployeePtoTx::execute(t, 1, accrual, Some(Hours::from_hours(16)))
+            .run(&mut ())
+            .unwrap();
+        PaydayTx::execute(t, ymd(2024, 8, 9), false)
+            .run(&mut ())
+            .unwrap();
+        balance(t)
+    }
+    fn balance(t: &Tester) -> Hours {
+        let emp = t.db.fetch(1).run(&mut ()).unwrap();
+        emp.get_pto().unwrap().get_balance()
+    }
+
+    #[test]
+    fn test_reject_late_leave() {
+        let t = tester();
+        let before = paid(&t);
+
+        let result = LeaveTx::execute(&t, 1, ymd(2024, 8, 7), Hours::from_hours(8)).run(&mut ());
+        assert!(matches!(result, Err(UsecaseError::PeriodLocked(_))));
+        // nothing is taken out of the balance
+        assert_eq!(balance(&t), before);
+    }
+
+    #[test]
+    fn test_carry_late_leave_forward() {
+        let t = tester();
+        let before = paid(&t);
+        ChangeLateEntryPolicyTx::execute(&t, LateEntryPolicy::CarryForward)
+            .run(&mut ())
+            .unwrap();
+
+        LeaveTx::execute(&t, 1, ymd(2024, 8, 7), Hours::from_hours(8))
+            .run(&mut ())
+            .unwrap();
+        assert_eq!(balance(&t), before - Hours::from_hours(8));
+        PaydayTx::execute(&t, ymd(2024, 8, 16), false)
+            .run(&mut ())
+            .unwrap();
+
+        // the leave is paid on the next paycheck
+        let pcs = paychecks(&t, 1);
+        assert_eq!(pcs[1].get_gross_pay(), Money::from_cents(12000));
+    }
+}
@@ -0,0 +1,7 @@
+mod change_employee_biweekly_tx;
+mod change_employee_every_n_weeks_tx;
+mod change_employee_semi_monthly_tx;
+
+pub use change_employee_biweekly_tx::ChangeEmployeeBiweeklyTx;
+pub use change_employee_every_n_weeks_tx::ChangeEmployeeEveryNWeeksTx;
+pub use change_employee_semi_monthly_tx::ChangeEmployeeSemiMonthlyTx;
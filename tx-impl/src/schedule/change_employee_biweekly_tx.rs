@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{ChangeEmployeePaymentScheduleTx, UsecaseError};
+use payroll_domain::EmployeeId;
+use payroll_impl::PaymentScheduleImpl;
+
+pub trait ChangeEmployeeBiweeklyTx<Ctx>: ChangeEmployeePaymentScheduleTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        anchor: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeePaymentScheduleTx::execute(
+            self,
+            emp_id,
+            Rc::new(RefCell::new(PaymentScheduleImpl::Biweekly { anchor })),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeBiweeklyTx<Ctx> for T where T: ChangeEmployeePaymentScheduleTx<Ctx> {}
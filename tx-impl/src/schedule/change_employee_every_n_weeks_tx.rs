@@ -0,0 +1,39 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+use tx_rs::Tx;
+
+use abstract_tx::{ChangeEmployeePaymentScheduleTx, UsecaseError};
+use payroll_domain::EmployeeId;
+use payroll_impl::PaymentScheduleImpl;
+
+pub trait ChangeEmployeeEveryNWeeksTx<Ctx>: ChangeEmployeePaymentScheduleTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        weeks: u32,
+        anchor: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            if weeks == 0 {
+                return Err(UsecaseError::InvalidPaymentSchedule(format!(
+                    "weeks must be positive emp_id: {}",
+                    emp_id
+                )));
+            }
+            ChangeEmployeePaymentScheduleTx::execute(
+                self,
+                emp_id,
+                Rc::new(RefCell::new(PaymentScheduleImpl::EveryNWeeks {
+                    weeks,
+                    anchor,
+                })),
+            )
+            .run(ctx)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeEveryNWeeksTx<Ctx> for T where T: ChangeEmployeePaymentScheduleTx<Ctx> {}
@@ -0,0 +1,23 @@
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{ChangeEmployeePaymentScheduleTx, UsecaseError};
+use payroll_domain::EmployeeId;
+use payroll_impl::PaymentScheduleImpl;
+
+pub trait ChangeEmployeeSemiMonthlyTx<Ctx>: ChangeEmployeePaymentScheduleTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ChangeEmployeePaymentScheduleTx::execute(
+            self,
+            emp_id,
+            Rc::new(RefCell::new(PaymentScheduleImpl::SemiMonthly)),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeSemiMonthlyTx<Ctx> for T where T: ChangeEmployeePaymentScheduleTx<Ctx> {}
@@ -0,0 +1,19 @@
+mod apply_scheduled_changes_tx;
+mod cancel_scheduled_changes_tx;
+mod list_scheduled_changes_tx;
+mod schedule_employee_address_tx;
+mod schedule_employee_direct_tx;
+mod schedule_employee_hold_tx;
+mod schedule_employee_mail_tx;
+mod schedule_employee_name_tx;
+mod schedule_union_member_tx;
+
+pub use apply_scheduled_changes_tx::ApplyScheduledChangesTx;
+pub use cancel_scheduled_changes_tx::CancelScheduledChangesTx;
+pub use list_scheduled_changes_tx::ListScheduledChangesTx;
+pub use schedule_employee_address_tx::ScheduleEmployeeAddressTx;
+pub use schedule_employee_direct_tx::ScheduleEmployeeDirectTx;
+pub use schedule_employee_hold_tx::ScheduleEmployeeHoldTx;
+pub use schedule_employee_mail_tx::ScheduleEmployeeMailTx;
+pub use schedule_employee_name_tx::ScheduleEmployeeNameTx;
+pub use schedule_union_member_tx::ScheduleUnionMemberTx;
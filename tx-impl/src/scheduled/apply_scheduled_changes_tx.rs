@@ -0,0 +1,168 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::EmployeeChange;
+use payroll_impl::AffiliationImpl;
+
+pub trait ApplyScheduledChangesTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(&'a self, date: NaiveDate) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let due = self
+                .dao()
+                .fetch_scheduled_changes()
+                .run(ctx)
+                .map_err(UsecaseError::GetScheduledChangesFailed)?
+                .into_iter()
+                .filter(|c| c.get_effective() <= date)
+                .collect::<Vec<_>>();
+            let mut keys = vec![];
+            for change in &due {
+                let key = (change.get_emp_id(), change.get_effective());
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+            // the changes of an employee for a day are removed only once they are saved,
+            // so a failure leaves them and everything after them in the queue
+            for (emp_id, effective) in keys {
+                // the employee may have been deleted since the change was scheduled
+                if let Ok(mut emp) = self.dao().fetch(emp_id).run(ctx) {
+                    for change in due
+                        .iter()
+                        .filter(|c| c.get_emp_id() == emp_id && c.get_effective() == effective)
+                    {
+                        if let EmployeeChange::Affiliation {
+                            member_id: Some(member_id),
+                            ..
+                        } = change.get_change()
+                        {
+                            // the membership may have changed since the change was scheduled;
+                            // such a change is dropped rather than failing the payday
+                            if AffiliationImpl::find_member_id(emp.get_affiliations()).is_some()
+                                || self.dao().find_union_member(*member_id).run(ctx).is_ok()
+                            {
+                                continue;
+                            }
+                            self.dao()
+                                .add_union_member(*member_id, emp_id)
+                                .run(ctx)
+                                .map_err(UsecaseError::AddUnionMemberFailed)?;
+                        }
+                        change.get_change().clone().apply(&mut emp);
+                    }
+                    self.dao()
+                        .update(emp)
+                        .run(ctx)
+                        .map_err(UsecaseError::UpdateEmployeeFailed)?;
+                }
+                self.dao()
+                    .remove_scheduled_changes(emp_id, effective)
+                    .run(ctx)
+                    .map_err(UsecaseError::CancelScheduledChangeFailed)?;
+            }
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ApplyScheduledChangesTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+
+#[cfg(test)]
+mod test_apply_scheduled_changes {
+    use super::*;
+    use payroll_domain::Money;
+
+    use crate::affiliation::ChangeUnionMemberTx;
+    use crate::general::AddSalaryEmployeeTx;
+    use crate::scheduled::{
+        ScheduleEmployeeAddressTx, ScheduleEmployeeNameTx, ScheduleUnionMemberTx,
+    };
+    use crate::test_util::{tester, ymd, Tester};
+
+    fn salaried(t: &Tester, emp_id: u32) {
+        AddSalaryEmployeeTx::execute(t, emp_id, "Bob", "Home", Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+    }
+    fn queue(t: &Tester) -> Vec<(u32, NaiveDate)> {
+        t.db.fetch_scheduled_changes()
+            .run(&mut ())
+            .unwrap()
+            .iter()
+            .map(|c| (c.get_emp_id(), c.get_effective()))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply() {
+        let t = tester();
+        salaried(&t, 1);
+        ScheduleUnionMemberTx::execute(&t, 1, ymd(2024, 9, 1), 7, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+        ScheduleEmployeeAddressTx::execute(&t, 1, ymd(2024, 9, 1), "123 Wall St.")
+            .run(&mut ())
+            .unwrap();
+
+        ApplyScheduledChangesTx::execute(&t, ymd(2024, 8, 31))
+            .run(&mut ())
+            .unwrap();
+        assert_eq!(queue(&t).len(), 2);
+        assert!(t.db.find_union_member(7).run(&mut ()).is_err());
+
+        ApplyScheduledChangesTx::execute(&t, ymd(2024, 9, 1))
+            .run(&mut ())
+            .unwrap();
+        assert!(queue(&t).is_empty());
+        assert_eq!(t.db.find_union_member(7).run(&mut ()), Ok(1));
+        let emp = t.db.fetch(1).run(&mut ()).unwrap();
+        assert_eq!(emp.get_affiliations().len(), 1);
+    }
+
+    #[test]
+    fn test_membership_changed() {
+        let t = tester();
+        salaried(&t, 1);
+        salaried(&t, 2);
+        ScheduleUnionMemberTx::execute(&t, 1, ymd(2024, 9, 1), 7, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+        ScheduleEmployeeAddressTx::execute(&t, 1, ymd(2024, 9, 1), "123 Wall St.")
+            .run(&mut ())
+            .unwrap();
+        ScheduleUnionMemberTx::execute(&t, 2, ymd(2024, 9, 1), 8, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+        ScheduleEmployeeNameTx::execute(&t, 2, ymd(2024, 9, 1), "Alice")
+            .run(&mut ())
+            .unwrap();
+        // the employee joins and the member id is taken after the changes were scheduled
+        ChangeUnionMemberTx::execute(&t, 1, 9, Money::from_cents(875))
+            .run(&mut ())
+            .unwrap();
+        salaried(&t, 3);
+        ChangeUnionMemberTx::execute(&t, 3, 8, Money::from_cents(875))
+            .run(&mut ())
+            .unwrap();
+
+        // only the memberships are dropped
+        ApplyScheduledChangesTx::execute(&t, ymd(2024, 9, 1))
+            .run(&mut ())
+            .unwrap();
+        assert!(queue(&t).is_empty());
+        assert!(t.db.find_union_member(7).run(&mut ()).is_err());
+        assert_eq!(t.db.find_union_member(8).run(&mut ()), Ok(3));
+        let emp = t.db.fetch(1).run(&mut ()).unwrap();
+        assert_eq!(
+            AffiliationImpl::find_member_id(emp.get_affiliations()),
+            Some(9)
+        );
+        let emp = t.db.fetch(2).run(&mut ()).unwrap();
+        assert!(emp.get_affiliations().is_empty());
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::EmployeeId;
+
+pub trait CancelScheduledChangesTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let removed = self
+                .dao()
+                .remove_scheduled_changes(emp_id, effective)
+                .run(ctx)
+                .map_err(UsecaseError::CancelScheduledChangeFailed)?;
+            if removed.is_empty() {
+                return Err(UsecaseError::ScheduledChangeNotFound(format!(
+                    "emp_id: {}, effective: {}",
+                    emp_id, effective
+                )));
+            }
+            Ok(())
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> CancelScheduledChangesTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
+
+#[cfg(test)]
+mod test_cancel_scheduled_changes {
+    use super::*;
+    use payroll_domain::Money;
+
+    use crate::general::AddSalaryEmployeeTx;
+    use crate::scheduled::{ScheduleEmployeeAddressTx, ScheduleEmployeeNameTx};
+    use crate::test_util::{tester, ymd};
+
+    #[test]
+    fn test_cancel() {
+        let t = tester();
+        AddSalaryEmployeeTx::execute(&t, 1, "Bob", "Home", Money::from_cents(100000), None)
+            .run(&mut ())
+            .unwrap();
+        ScheduleEmployeeNameTx::execute(&t, 1, ymd(2024, 9, 1), "Alice")
+            .run(&mut ())
+            .unwrap();
+        ScheduleEmployeeAddressTx::execute(&t, 1, ymd(2024, 9, 1), "123 Wall St.")
+            .run(&mut ())
+            .unwrap();
+        ScheduleEmployeeAddressTx::execute(&t, 1, ymd(2024, 10, 1), "456 Main St.")
+            .run(&mut ())
+            .unwrap();
+
+        CancelScheduledChangesTx::execute(&t, 1, ymd(2024, 9, 1))
+            .run(&mut ())
+            .unwrap();
+        let left = t.db.fetch_scheduled_changes().run(&mut ()).unwrap();
+        assert_eq!(left.len(), 1);
+        assert_eq!(left[0].get_effective(), ymd(2024, 10, 1));
+
+        let result = CancelScheduledChangesTx::execute(&t, 1, ymd(2024, 9, 1)).run(&mut ());
+        assert!(matches!(
+            result,
+            Err(UsecaseError::ScheduledChangeNotFound(_))
+        ));
+    }
+}
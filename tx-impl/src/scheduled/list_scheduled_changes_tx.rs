@@ -0,0 +1,18 @@
+use tx_rs::Tx;
+
+use abstract_tx::UsecaseError;
+use dao::{HavePayrollDao, PayrollDao};
+use payroll_domain::ScheduledChange;
+
+pub trait ListScheduledChangesTx<Ctx>: HavePayrollDao<Ctx> {
+    fn execute<'a>(&'a self) -> impl tx_rs::Tx<Ctx, Item = Vec<ScheduledChange>, Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        self.dao()
+            .fetch_scheduled_changes()
+            .map_err(UsecaseError::GetScheduledChangesFailed)
+    }
+}
+// blanket implementation
+impl<T, Ctx> ListScheduledChangesTx<Ctx> for T where T: HavePayrollDao<Ctx> {}
@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+
+use abstract_tx::{ScheduleChangeTx, UsecaseError};
+use payroll_domain::{EmployeeChange, EmployeeId};
+
+pub trait ScheduleEmployeeAddressTx<Ctx>: ScheduleChangeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        address: &str,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ScheduleChangeTx::execute(
+            self,
+            emp_id,
+            effective,
+            EmployeeChange::Address(address.to_string()),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> ScheduleEmployeeAddressTx<Ctx> for T where T: ScheduleChangeTx<Ctx> {}
@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{ScheduleChangeTx, UsecaseError};
+use payroll_domain::{EmployeeChange, EmployeeId};
+use payroll_impl::PaymentMethodImpl;
+
+pub trait ScheduleEmployeeDirectTx<Ctx>: ScheduleChangeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        bank: &str,
+        account: &str,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ScheduleChangeTx::execute(
+            self,
+            emp_id,
+            effective,
+            EmployeeChange::Method(Rc::new(RefCell::new(PaymentMethodImpl::Direct {
+                bank: bank.to_string(),
+                account: account.to_string(),
+            }))),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> ScheduleEmployeeDirectTx<Ctx> for T where T: ScheduleChangeTx<Ctx> {}
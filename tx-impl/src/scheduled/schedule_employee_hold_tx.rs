@@ -0,0 +1,26 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{ScheduleChangeTx, UsecaseError};
+use payroll_domain::{EmployeeChange, EmployeeId};
+use payroll_impl::PaymentMethodImpl;
+
+pub trait ScheduleEmployeeHoldTx<Ctx>: ScheduleChangeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ScheduleChangeTx::execute(
+            self,
+            emp_id,
+            effective,
+            EmployeeChange::Method(Rc::new(RefCell::new(PaymentMethodImpl::Hold))),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> ScheduleEmployeeHoldTx<Ctx> for T where T: ScheduleChangeTx<Ctx> {}
@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+
+use abstract_tx::{ScheduleChangeTx, UsecaseError};
+use payroll_domain::{EmployeeChange, EmployeeId};
+use payroll_impl::PaymentMethodImpl;
+
+pub trait ScheduleEmployeeMailTx<Ctx>: ScheduleChangeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        address: &str,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        ScheduleChangeTx::execute(
+            self,
+            emp_id,
+            effective,
+            EmployeeChange::Method(Rc::new(RefCell::new(PaymentMethodImpl::Mail {
+                address: address.to_string(),
+            }))),
+        )
+    }
+}
+// blanket implementation
+impl<T, Ctx> ScheduleEmployeeMailTx<Ctx> for T where T: ScheduleChangeTx<Ctx> {}
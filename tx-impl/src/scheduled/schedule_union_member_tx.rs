@@ -0,0 +1,172 @@
+use chrono::NaiveDate;
+use std::{cell::RefCell, rc::Rc};
+use tx_rs::Tx;
+
+use abstract_tx::{ScheduleChangeTx, UsecaseError};
+use dao::PayrollDao;
+use payroll_domain::{EmployeeChange, EmployeeId, MemberId, Money};
+use payroll_impl::AffiliationImpl;
+
+pub trait ScheduleUnionMemberTx<Ctx>: ScheduleChangeTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        effective: NaiveDate,
+        member_id: MemberId,
+        dues: Money,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            // refuse now rather than on the payday the change is applied
+            let emp = self
+                .dao()
+                .fetch(emp_id)
+                .run(ctx)
+                .map_err(UsecaseError::NotFound)?;
+            let scheduled = self
+                .dao()
+                .fetch_scheduled_changes()
+                .run(ctx)
+                .map_err(UsecaseError::GetScheduledChangesFailed)?
+                .into_iter()
+                .filter_map(|c| match c.get_change() {
+                    EmployeeChange::Affiliation {
+                        member_id: Some(m), ..
+                    } => Some((c.get_emp_id(), *m)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if AffiliationImpl::find_member_id(emp.get_affiliations()).is_some()
+                || scheduled.iter().any(|(e, _)| *e == emp_id)
+            {
+                return Err(UsecaseError::UnexpectedAffiliation(format!(
+                    "emp_id: {} is already a union member",
+                    emp_id
+                )));
+            }
+            if scheduled.iter().any(|(_, m)| *m == member_id)
+                || self.dao().find_union_member(member_id).run(ctx).is_ok()
+            {
+                return Err(UsecaseError::UnexpectedAffiliation(format!(
+                    "member_id: {} already exists",
+                    member_id
+                )));
+            }
+            ScheduleChangeTx::execute(
+                self,
+                emp_id,
+                effective,
+                EmployeeChange::Affiliation {
+                    member_id: Some(member_id),
+                    affiliation: Rc::new(RefCell::new(AffiliationImpl::Union {
+                        member_id,
+                        dues,
+                        service_charges: vec![],
+                    })),
+                },
+            )
+            .run(ctx)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ScheduleUnionMemberTx<Ctx> for T where T: ScheduleChangeTx<Ctx> {}
+
+#[cfg(test)]
+mod test_schedule_union_member {
+    use super::*;
+
+    use crate::affiliation::ChangeUnionMemberTx;
+    use crate::general::AddSalaryEmployeeTx;
+    use crate::test_util::{tester, ymd};
+
+    #[test]
+    fn test_member_id_taken() {
+        let t = tester();
+        for emp_id in [1, 2, 3] {
+            AddSalaryEmployeeTx::execute(
+                &t,
+                emp_id,
+                "Bob",
+                "Home",
+                Money::from_cents(100000),
+                None,
+            )
+            .run(&mut ())
+            .unwrap();
+        }
+        ChangeUnionMemberTx::execute(&t, 1, 7, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+        ScheduleUnionMemberTx::execute(&t, 2, ymd(2024, 9, 1), 8, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+
+        // by a member
+        let result =
+            ScheduleUnionMemberTx::execute(&t, 3, ymd(2024, 9, 1), 7, Money::from_cents(945))
+                .run(&mut ());
+        assert!(matches!(
+            result,
+            Err(UsecaseError::UnexpectedAffiliation(_))
+        ));
+        // by a scheduled change
+        let result =
+            ScheduleUnionMemberTx::execute(&t, 3, ymd(2024, 10, 1), 8, Money::from_cents(945))
+                .run(&mut ());
+        assert!(matches!(
+            result,
+            Err(UsecaseError::UnexpectedAffiliation(_))
+        ));
+        assert_eq!(
+            t.db.fetch_scheduled_changes().run(&mut ()).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_already_a_member() {
+        let t = tester();
+        for emp_id in [1, 2] {
+            AddSalaryEmployeeTx::execute(
+                &t,
+                emp_id,
+                "Bob",
+                "Home",
+                Money::from_cents(100000),
+                None,
+            )
+            .run(&mut ())
+            .unwrap();
+        }
+        ChangeUnionMemberTx::execute(&t, 1, 7, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+        ScheduleUnionMemberTx::execute(&t, 2, ymd(2024, 9, 1), 8, Money::from_cents(945))
+            .run(&mut ())
+            .unwrap();
+
+        // by a membership
+        let result =
+            ScheduleUnionMemberTx::execute(&t, 1, ymd(2024, 9, 1), 9, Money::from_cents(945))
+                .run(&mut ());
+        assert!(matches!(
+            result,
+            Err(UsecaseError::UnexpectedAffiliation(_))
+        ));
+        // by a scheduled change
+        let result =
+            ScheduleUnionMemberTx::execute(&t, 2, ymd(2024, 10, 1), 9, Money::from_cents(945))
+                .run(&mut ());
+        assert!(matches!(
+            result,
+            Err(UsecaseError::UnexpectedAffiliation(_))
+        ));
+        assert_eq!(
+            t.db.fetch_scheduled_changes().run(&mut ()).unwrap().len(),
+            1
+        );
+    }
+}
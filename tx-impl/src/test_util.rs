@@ -0,0 +1,27 @@
+use chrono::NaiveDate;
+use tx_rs::Tx;
+
+use dao::{HavePayrollDao, PayrollDao};
+use mock_db::MockDb;
+use payroll_domain::{EmployeeId, Paycheck};
+
+// runs the use cases against an in-memory database
+pub struct Tester {
+    pub db: MockDb,
+}
+impl HavePayrollDao<()> for Tester {
+    fn dao(&self) -> &impl PayrollDao<()> {
+        &self.db
+    }
+}
+pub fn tester() -> Tester {
+    Tester { db: MockDb::new() }
+}
+
+pub fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+// the paychecks recorded for the employee, in the order they were issued
+pub fn paychecks(t: &Tester, emp_id: EmployeeId) -> Vec<Paycheck> {
+    t.db.fetch_paychecks(emp_id).run(&mut ()).unwrap()
+}
@@ -0,0 +1,3 @@
+mod change_employee_tax_table_tx;
+
+pub use change_employee_tax_table_tx::ChangeEmployeeTaxTableTx;
@@ -0,0 +1,30 @@
+use std::{cell::RefCell, rc::Rc};
+use tx_rs::Tx;
+
+use abstract_tx::{ChangeEmployeeWithholdingTx, UsecaseError};
+use payroll_domain::EmployeeId;
+use payroll_impl::{TaxTable, WithholdingImpl};
+
+pub trait ChangeEmployeeTaxTableTx<Ctx>: ChangeEmployeeWithholdingTx<Ctx> {
+    fn execute<'a>(
+        &'a self,
+        emp_id: EmployeeId,
+        path: &'a str,
+    ) -> impl tx_rs::Tx<Ctx, Item = (), Err = UsecaseError>
+    where
+        Ctx: 'a,
+    {
+        tx_rs::with_tx(move |ctx| {
+            let table = TaxTable::load(path)
+                .map_err(|e| UsecaseError::LoadTaxTableFailed(e.to_string()))?;
+            ChangeEmployeeWithholdingTx::execute(
+                self,
+                emp_id,
+                Rc::new(RefCell::new(WithholdingImpl::Table { table })),
+            )
+            .run(ctx)
+        })
+    }
+}
+// blanket implementation
+impl<T, Ctx> ChangeEmployeeTaxTableTx<Ctx> for T where T: ChangeEmployeeWithholdingTx<Ctx> {}